@@ -1,6 +1,8 @@
 #![warn(missing_docs)]
 extern crate peroxide;
 use peroxide::prelude::*;
+use peroxide::numerical::root::RootError;
+use std::fmt;
 
 // This library was developed for use in my PhD thesis under supervision 
 // of Professor Per F. Peterson. It is part of a thermal hydraulics
@@ -99,6 +101,9 @@ fn churchillInnerTerm(Re: f64, roughnessRatio: f64) -> f64 {
 #[allow(non_snake_case)]
 fn fanning(ReynoldsNumber: f64, roughnessRatio: f64) -> f64{
 
+    debug_assert!(ReynoldsNumber.is_finite() && roughnessRatio.is_finite(),
+                  "Re or roughnessRatio is NaN or infinite");
+
     if ReynoldsNumber == 0.0 {
         panic!("Re = 0.0");
     }
@@ -117,10 +122,119 @@ fn fanning(ReynoldsNumber: f64, roughnessRatio: f64) -> f64{
     return fanningFrictionFactor;
 }
 
+/// errors which may be returned when calculating friction factors
+/// instead of panicking
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrictionFactorError {
+    /// Re = 0, the friction factor is undefined here 
+    /// (it tends to infinity as Re tends to zero)
+    ZeroReynolds,
+    /// Re < 0, friction factor correlations only take
+    /// the magnitude of Re, reverse flow should be handled
+    /// by the caller
+    NegativeReynolds,
+    /// roughness ratio < 0, which is nonphysical
+    NegativeRoughness,
+    /// Re or roughness ratio is NaN or infinite, usually from an
+    /// earlier division by zero upstream
+    NonFinite,
+}
+
+impl fmt::Display for FrictionFactorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrictionFactorError::ZeroReynolds => 
+                write!(f, "Re = 0.0, friction factor is undefined"),
+            FrictionFactorError::NegativeReynolds => 
+                write!(f, "Re < 0.0, use the magnitude of Re instead"),
+            FrictionFactorError::NegativeRoughness => 
+                write!(f, "roughnessRatio < 0.0, nonphysical"),
+            FrictionFactorError::NonFinite => 
+                write!(f, "Re or roughnessRatio is NaN or infinite"),
+        }
+    }
+}
+
+impl std::error::Error for FrictionFactorError {}
+
+/// calculates darcy friction factor using churchill correlation
+/// but returns an error instead of panicking for
+/// Re = 0, Re < 0, roughness ratio < 0, or NaN and 
+/// infinite inputs
+///
+/// this is useful for handling stagnation points in 
+/// network solvers gracefully
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let darcy_result = try_darcy(0.0, 0.0015);
+///
+/// assert_eq!(darcy_result, Err(FrictionFactorError::ZeroReynolds));
+///
+/// let darcy_result = try_darcy(1800.0, 0.0015);
+///
+/// assert_eq!(darcy_result, Ok(darcy(1800.0, 0.0015)));
+///
+/// let darcy_result = try_darcy(f64::NAN, 0.0015);
+///
+/// assert_eq!(darcy_result, Err(FrictionFactorError::NonFinite));
+/// ```
+pub fn try_darcy(reynolds_number: f64, 
+                 roughness_ratio: f64) -> Result<f64, FrictionFactorError> {
+
+    // NaN fails every comparison below, so it has to be
+    // caught first
+    if !reynolds_number.is_finite() || !roughness_ratio.is_finite() {
+        return Err(FrictionFactorError::NonFinite);
+    }
+
+    if reynolds_number == 0.0 {
+        return Err(FrictionFactorError::ZeroReynolds);
+    }
+
+    if reynolds_number < 0.0 {
+        return Err(FrictionFactorError::NegativeReynolds);
+    }
+
+    if roughness_ratio < 0.0 {
+        return Err(FrictionFactorError::NegativeRoughness);
+    }
+
+    return Ok(4.0*fanning(reynolds_number, roughness_ratio));
+}
+
+/// calculates darcy friction factor using churchill correlation
+/// without panicking, for use in custom darcy functions
+///
+/// unlike try_darcy, reverse flow (Re < 0) is fine here, 
+/// the friction factor is calculated using the magnitude of Re, 
+/// so the caller only needs to flip the sign for reverse flow.
+/// Re = 0 and negative roughness ratios return an error
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// assert_eq!(darcy_checked(1800.0, -0.0015), 
+///     Err(FrictionFactorError::NegativeRoughness));
+///
+/// assert_eq!(darcy_checked(-1800.0, 0.0015), Ok(darcy(1800.0, 0.0015)));
+/// ```
+pub fn darcy_checked(reynolds_number: f64, 
+                     roughness_ratio: f64) -> Result<f64, FrictionFactorError> {
+
+    return try_darcy(reynolds_number.abs(), roughness_ratio);
+}
+
 #[allow(non_snake_case)]
 /// calculates darcy friction factor using churchill correlation
+///
+/// this will panic for Re <= 0, roughness ratio < 0 or 
+/// NaN and infinite inputs,
+/// use try_darcy if you want to handle these cases yourself
 pub fn darcy(ReynoldsNumber: f64, roughnessRatio: f64) -> f64 {
-    return 4.0*fanning(ReynoldsNumber, roughnessRatio);
+    return try_darcy(ReynoldsNumber, roughnessRatio).
+        unwrap_or_else(|error| panic!("{}", error));
 }
 
 #[allow(non_snake_case)]
@@ -130,6 +244,934 @@ pub fn moody(ReynoldsNumber: f64, roughnessRatio: f64) -> f64 {
     return 4.0*fanning(ReynoldsNumber, roughnessRatio);
 }
 
+/// calculates darcy friction factor using churchill correlation
+/// for a whole slice of Reynolds numbers at once
+///
+/// the results are written into out, which must be the same 
+/// length as reynolds_numbers (it panics otherwise)
+///
+/// This is meant for Monte Carlo or sweep type studies where 
+/// darcy is called in a tight loop. The roughness term and the 
+/// constant in B are computed once, and the loop body has no 
+/// branches so the compiler has a chance to autovectorise it.
+///
+/// Like darcy, it panics for Re <= 0 or roughness ratio < 0,
+/// the Reynolds numbers are checked before anything is 
+/// written to out
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let reynolds_numbers = [1800.0, 4000.0, 1.0e5];
+/// let mut friction_factors = [0.0; 3];
+///
+/// darcy_batch(&reynolds_numbers, 0.0015, &mut friction_factors);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(friction_factors[2], darcy(1.0e5, 0.0015),
+/// max_relative = 1e-12);
+/// ```
+pub fn darcy_batch(reynolds_numbers: &[f64], 
+                   roughness_ratio: f64,
+                   out: &mut [f64]) {
+
+    assert_eq!(reynolds_numbers.len(), out.len(),
+    "darcy_batch: reynolds_numbers and out must be the same length");
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    for &reynolds_number in reynolds_numbers.iter() {
+        if reynolds_number == 0.0 {
+            panic!("Re = 0.0");
+        }
+
+        if reynolds_number < 0.0 {
+            panic!("Re<0.0");
+        }
+    }
+
+    // these do not depend on Re, so we compute them once
+    let roughness_term = 0.27 * roughness_ratio;
+    let b_numerator = 37530.0_f64.powf(16.0);
+
+    for (friction_factor, &reynolds_number) in 
+        out.iter_mut().zip(reynolds_numbers.iter()) {
+
+        let laminar_term = (8.0/reynolds_number).powf(12.0);
+
+        let reynolds_term = (7.0/reynolds_number).powf(0.9);
+        let log_fraction = 1.0/(reynolds_term + roughness_term);
+        let a_term = (2.457*log_fraction.ln()).powf(16.0);
+
+        let b_term = b_numerator/reynolds_number.powf(16.0);
+
+        let turbulent_term = (1.0/(a_term + b_term)).powf(3.0/2.0);
+
+        // darcy = 4 * fanning = 4 * 2 * (inner term)^(1/12)
+        *friction_factor = 8.0 * (laminar_term + turbulent_term)
+            .powf(1.0/12.0);
+    }
+}
+
+
+/// calculates darcy friction factor using churchill correlation
+/// in single precision, eg. for embedded controllers where f64
+/// math is expensive
+///
+/// it is the same blend as darcy, but the terms are rearranged 
+/// so that nothing overflows or underflows in f32.
+/// In darcy, 37530^16 is about 1e73, which is way beyond the 
+/// f32 maximum of about 3e38. So instead of raising A and B to the 
+/// 16th power and then taking the 12th root of the sum, 
+/// i factor out the largest term before raising to any power:
+///
+/// (A + B)^(-1/8) = m^(-2) (1 + (n/m)^16)^(-1/8)
+///
+/// where A = a^16, B = b^16, m = max(a,b) and n = min(a,b)
+///
+/// Like darcy, it panics for Re <= 0 or roughness ratio < 0
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let friction_factor_f32 = darcy_f32(1.0e5, 0.0015);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(friction_factor_f32, 
+/// darcy(1.0e5, 0.0015) as f32,
+/// max_relative = 1e-4);
+/// ```
+pub fn darcy_f32(reynolds_number: f32, roughness_ratio: f32) -> f32 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    // A = a^16 and B = b^16
+    let reynolds_term = (7.0_f32/reynolds_number).powf(0.9);
+    let log_fraction = 1.0/(reynolds_term + 0.27 * roughness_ratio);
+    let a = 2.457 * log_fraction.ln();
+    let b = 37530.0_f32/reynolds_number;
+
+    let larger_term = a.max(b);
+    let smaller_term = a.min(b);
+
+    // (A+B)^(-3/2) raised to the 1/12 power
+    let turbulent_term_root = larger_term.powi(-2) * 
+        (1.0 + (smaller_term/larger_term).powi(16)).powf(-1.0/8.0);
+
+    // (8/Re)^12 raised to the 1/12 power
+    let laminar_term_root = 8.0/reynolds_number;
+
+    // (laminar + turbulent)^(1/12), again factoring out the
+    // largest term
+    let larger_root = laminar_term_root.max(turbulent_term_root);
+
+    let inner_term_root = larger_root * 
+        ((laminar_term_root/larger_root).powi(12) + 
+         (turbulent_term_root/larger_root).powi(12)).powf(1.0/12.0);
+
+    // darcy = 4 * fanning = 4 * 2 * (inner term)^(1/12)
+    return 8.0 * inner_term_root;
+}
+
+/// calculates darcy friction factor using churchill correlation
+/// at a fixed Re for a list of roughness ratios
+///
+/// this is meant for uncertainty quantification, where you 
+/// want to see how much f changes over the plausible range of 
+/// pipe roughness
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let roughness_ratios = [0.0, 1.0e-4, 1.0e-3];
+/// let friction_factors = darcy_roughness_sweep(1.0e5, &roughness_ratios);
+///
+/// assert_eq!(friction_factors.len(), 3);
+/// assert!(friction_factors[2] > friction_factors[0]);
+/// ```
+pub fn darcy_roughness_sweep(reynolds_number: f64, 
+                             roughness_ratios: &[f64]) -> Vec<f64> {
+
+    return roughness_ratios.iter().
+        map(|roughness_ratio| darcy(reynolds_number, *roughness_ratio)).
+        collect();
+}
+
+/// calculates the sensitivity of the churchill darcy friction
+/// factor to roughness ratio, ie. df/d(roughness_ratio) at fixed Re
+///
+/// i use central differences with a step of 1e-3 of the roughness 
+/// ratio (or 1e-8 for very smooth pipes). 
+/// Roughness ratio can't be negative,
+/// so if the step would go below zero, i use a forward difference
+///
+/// in the laminar region f = 64/Re, so this is close to zero,
+/// in the turbulent region it is positive
+pub fn darcy_roughness_sensitivity(reynolds_number: f64, 
+                                   roughness_ratio: f64) -> f64 {
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    let step = (1.0e-3 * roughness_ratio).max(1.0e-8);
+
+    if roughness_ratio < step {
+        return (darcy(reynolds_number, roughness_ratio + step) 
+                - darcy(reynolds_number, roughness_ratio))/step;
+    }
+
+    return (darcy(reynolds_number, roughness_ratio + step) 
+            - darcy(reynolds_number, roughness_ratio - step))/(2.0 * step);
+}
+
+/// calculates the derivative of the churchill darcy friction 
+/// factor with respect to Reynolds number, ie. df/dRe at fixed 
+/// roughness ratio
+///
+/// this is meant for Newton type network solvers, where an 
+/// analytic derivative converges better than numerical 
+/// differentiation. I differentiate the correlation term by term,
+///
+/// f = 8 S^(1/12), S = (8/Re)^12 + (A + B)^(-3/2)
+///
+/// df/dRe = f/(12 S) dS/dRe
+///
+/// dS/dRe = -12/Re (8/Re)^12 - 3/2 (A + B)^(-5/2) (dA/dRe + dB/dRe)
+///
+/// dB/dRe = -16 B/Re
+///
+/// dA/dRe = 16 A/g dg/dRe, 
+/// g = 2.457 ln (1/((7/Re)^0.9 + 0.27 roughness_ratio)),
+/// dg/dRe = 2.457 * 0.9 (7/Re)^0.9 / (Re ((7/Re)^0.9 + 0.27 roughness_ratio))
+///
+/// Like darcy, it panics for Re <= 0 or roughness ratio < 0
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// // in the laminar region f = 64/Re, so df/dRe = -64/Re^2
+/// extern crate approx;
+/// approx::assert_relative_eq!(darcy_dRe(100.0, 0.0), 
+///     -64.0/(100.0 * 100.0), max_relative = 1e-3);
+/// ```
+#[allow(non_snake_case)]
+pub fn darcy_dRe(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    let darcy_friction_factor = darcy(reynolds_number, roughness_ratio);
+
+    let laminar_term = (8.0/reynolds_number).powf(12.0);
+    let d_laminar_term = -12.0 * laminar_term / reynolds_number;
+
+    let b_term = B(reynolds_number);
+    let d_b_term = -16.0 * b_term / reynolds_number;
+
+    let reynolds_term = (7.0/reynolds_number).powf(0.9);
+    let log_argument = reynolds_term + 0.27 * roughness_ratio;
+    let g = 2.457 * (1.0/log_argument).ln();
+    let d_g = 2.457 * 0.9 * reynolds_term / (reynolds_number * log_argument);
+
+    let a_term = A(reynolds_number, roughness_ratio);
+    let d_a_term = 16.0 * a_term / g * d_g;
+
+    let a_plus_b = a_term + b_term;
+    let turbulent_term = a_plus_b.powf(-1.5);
+    let d_turbulent_term = -1.5 * turbulent_term / a_plus_b 
+        * (d_a_term + d_b_term);
+
+    let inner_term = laminar_term + turbulent_term;
+    let d_inner_term = d_laminar_term + d_turbulent_term;
+
+    return darcy_friction_factor / (12.0 * inner_term) * d_inner_term;
+}
+
+
+/// calculates darcy friction factor using the haaland
+/// explicit approximation of the colebrook equation
+///
+/// 1/sqrt(f) = -1.8 log10 [ (roughness_ratio/3.7)^1.11 + 6.9/Re ]
+///
+/// For Re < 2300, f = 64/Re. There is no blending in the 
+/// transition region, so the friction factor jumps at Re = 2300.
+///
+/// This is much cheaper than the churchill correlation, 
+/// which is useful if you call it millions of times.
+///
+/// However, Re = 0 will not work!
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let haaland_friction_factor = 
+///     churchill_friction_factor::haaland_darcy(1.0e5, 1.0e-4);
+///
+/// println!("{}", haaland_friction_factor);
+/// ```
+pub fn haaland_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    if reynolds_number < 2300.0 {
+        return 64.0/reynolds_number;
+    }
+
+    let log_term = ((roughness_ratio/3.7).powf(1.11) 
+                    + 6.9/reynolds_number).log10();
+
+    let inverse_sqrt_f = -1.8 * log_term;
+
+    return 1.0/inverse_sqrt_f.powf(2.0);
+}
+
+/// calculates darcy friction factor using the swamee jain
+/// explicit approximation of the colebrook equation
+///
+/// f = 0.25 / [ log10 (roughness_ratio/3.7 + 5.74/Re^0.9) ]^2
+///
+/// For Re < 2300, f = 64/Re. There is no blending in the 
+/// transition region, so the friction factor jumps at Re = 2300.
+///
+/// This is much cheaper than the churchill correlation, 
+/// which is useful if you call it millions of times.
+///
+/// However, Re = 0 will not work!
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let swamee_jain_friction_factor = 
+///     churchill_friction_factor::swamee_jain_darcy(1.0e5, 1.0e-4);
+///
+/// println!("{}", swamee_jain_friction_factor);
+/// ```
+pub fn swamee_jain_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    if reynolds_number < 2300.0 {
+        return 64.0/reynolds_number;
+    }
+
+    let log_term = (roughness_ratio/3.7 
+                    + 5.74/reynolds_number.powf(0.9)).log10();
+
+    return 0.25/log_term.powf(2.0);
+}
+
+/// calculates darcy friction factor for smooth pipes using the 
+/// blasius correlation
+///
+/// f = 0.316 Re^(-0.25)
+///
+/// which is valid for smooth turbulent pipe flow with
+/// 4000 < Re < 1e5, roughness is ignored.
+///
+/// For Re < 2300, f = 64/Re. There is no blending in the 
+/// transition region, blasius is used from Re = 2300 onwards.
+///
+/// Blasius overpredicts friction factor at higher Re,
+/// so this panics for Re > 1e5, use darcy instead.
+/// Re = 0 will not work either!
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let blasius_friction_factor = 
+///     churchill_friction_factor::blasius_darcy(5.0e4);
+///
+/// println!("{}", blasius_friction_factor);
+/// ```
+pub fn blasius_darcy(reynolds_number: f64) -> f64 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if reynolds_number > 1.0e5 {
+        panic!("Re > 1e5, blasius correlation not valid, use darcy");
+    }
+
+    if reynolds_number < 2300.0 {
+        return 64.0/reynolds_number;
+    }
+
+    return 0.316 * reynolds_number.powf(-0.25);
+}
+
+/// maximum number of newton iterations used to solve
+/// the colebrook equation
+pub const COLEBROOK_MAX_ITERATIONS: usize = 50;
+
+/// relative tolerance for the colebrook newton iterations,
+/// iterations stop when the change in 1/sqrt(f) is
+/// smaller than this fraction of 1/sqrt(f)
+pub const COLEBROOK_RELATIVE_TOLERANCE: f64 = 1e-12;
+
+// solves the colebrook white equation for darcy friction factor
+//
+// 1/sqrt(f) = -2 log10 (roughness_ratio/3.7 + 2.51/(Re sqrt(f)))
+//
+// with x = 1/sqrt(f) , we solve 
+//
+// g(x) = x + 2 log10 (roughness_ratio/3.7 + 2.51 x /Re) = 0
+//
+// using newton's method. The initial guess is from the 
+// swamee jain explicit approximation, so that we start close 
+// to the root even for very low roughness ratios (1e-7)
+// where the log term gets stiff
+fn colebrook_turbulent_darcy(reynolds_number: f64, 
+                             roughness_ratio: f64) -> f64 {
+
+    let roughness_term = roughness_ratio/3.7;
+    let reynolds_term = 2.51/reynolds_number;
+
+    // swamee jain initial guess
+    let initial_friction_factor = 
+        swamee_jain_darcy(reynolds_number, roughness_ratio);
+
+    let mut x = 1.0/initial_friction_factor.sqrt();
+
+    for _iteration in 0..COLEBROOK_MAX_ITERATIONS {
+
+        let log_argument = roughness_term + reynolds_term * x;
+        let g = x + 2.0 * log_argument.log10();
+        let g_prime = 1.0 + 2.0/std::f64::consts::LN_10 * 
+            reynolds_term/log_argument;
+
+        let x_new = x - g/g_prime;
+        let change = (x_new - x).abs();
+        x = x_new;
+
+        if change < COLEBROOK_RELATIVE_TOLERANCE * x.abs() {
+            break;
+        }
+    }
+
+    return 1.0/x.powf(2.0);
+}
+
+/// calculates darcy friction factor using the colebrook white
+/// equation
+///
+/// The colebrook equation is implicit in f, so it is solved
+/// using newton iterations on 1/sqrt(f), starting from the 
+/// swamee jain approximation. Iterations are capped at
+/// COLEBROOK_MAX_ITERATIONS (50) and stop once the relative change in
+/// 1/sqrt(f) is less than COLEBROOK_RELATIVE_TOLERANCE (1e-12). 
+/// If the cap is reached, the last iterate is returned. In practice,
+/// convergence takes less than 10 iterations even for 
+/// roughness ratios of 1e-7.
+///
+/// For Re < 2300, f = 64/Re.
+///
+/// For Re >= 2300, the colebrook result replaces the turbulent (A)
+/// term in the churchill correlation, so that the transition
+/// region is blended the same way as in churchill's correlation.
+/// At high Re, this tends to the colebrook result.
+///
+/// However, Re = 0 will not work!
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let colebrook_friction_factor = 
+///     churchill_friction_factor::colebrook_darcy(1.0e5, 1.0e-7);
+///
+/// let churchill_friction_factor = 
+///     churchill_friction_factor::darcy(1.0e5, 1.0e-7);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(colebrook_friction_factor, 
+///     churchill_friction_factor, max_relative = 0.03);
+/// ```
+pub fn colebrook_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    if reynolds_number < 2300.0 {
+        return 64.0/reynolds_number;
+    }
+
+    let colebrook_friction_factor = 
+        colebrook_turbulent_darcy(reynolds_number, roughness_ratio);
+
+    // churchill's turbulent term A is related to the turbulent
+    // darcy friction factor by f = 8 * A^(-1/8)
+    // so A = (8/f)^8
+    //
+    // we then blend using the churchill inner term
+    let colebrook_a_term = (8.0/colebrook_friction_factor).powf(8.0);
+    let b_term = B(reynolds_number);
+
+    let laminar_term = (8.0/reynolds_number).powf(12.0);
+    let turbulent_term = (1.0/(colebrook_a_term + b_term)).powf(3.0/2.0);
+
+    let fanning_friction_factor = 2.0 * 
+        (laminar_term + turbulent_term).powf(1.0/12.0);
+
+    return 4.0 * fanning_friction_factor;
+}
+
+/// a darcy friction factor correlation, so that pipe 
+/// calculations can pick their friction model 
+/// (see CalcPressureLoss::from_mass_rate_with_correlation)
+///
+/// Churchill is the default used everywhere else in the crate,
+/// Haaland, Colebrook and Blasius wrap haaland_darcy, 
+/// colebrook_darcy and blasius_darcy. Like those functions,
+/// implementors take Re > 0, reverse flow is handled by the caller
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let correlations: Vec<&dyn FrictionFactorCorrelation> = 
+///     vec![&Churchill, &Haaland, &Colebrook, &Blasius];
+///
+/// extern crate approx;
+/// for correlation in correlations {
+///     // all about the same in laminar flow
+///     approx::assert_relative_eq!(correlation.darcy(1000.0, 1.0e-4), 
+///         64.0/1000.0, max_relative = 1e-3);
+/// }
+///
+/// assert_eq!(Churchill.darcy(1.0e5, 1.0e-4), darcy(1.0e5, 1.0e-4));
+/// ```
+pub trait FrictionFactorCorrelation {
+    /// calculates the darcy friction factor
+    /// given Re and roughness ratio
+    fn darcy(&self, reynolds_number: f64, roughness_ratio: f64) -> f64;
+}
+
+/// churchill correlation, see darcy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Churchill;
+
+impl FrictionFactorCorrelation for Churchill {
+    fn darcy(&self, reynolds_number: f64, roughness_ratio: f64) -> f64 {
+        return darcy(reynolds_number, roughness_ratio);
+    }
+}
+
+/// haaland explicit approximation, see haaland_darcy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Haaland;
+
+impl FrictionFactorCorrelation for Haaland {
+    fn darcy(&self, reynolds_number: f64, roughness_ratio: f64) -> f64 {
+        return haaland_darcy(reynolds_number, roughness_ratio);
+    }
+}
+
+/// colebrook equation blended with churchill's laminar term,
+/// see colebrook_darcy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Colebrook;
+
+impl FrictionFactorCorrelation for Colebrook {
+    fn darcy(&self, reynolds_number: f64, roughness_ratio: f64) -> f64 {
+        return colebrook_darcy(reynolds_number, roughness_ratio);
+    }
+}
+
+/// blasius smooth pipe correlation, see blasius_darcy,
+/// the roughness ratio is ignored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Blasius;
+
+impl FrictionFactorCorrelation for Blasius {
+    fn darcy(&self, reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+        return blasius_darcy(reynolds_number);
+    }
+}
+
+/// lower bound of the transition band used by the smoothed
+/// darcy friction factor, below this Re we use 64/Re
+pub const SMOOTHING_LOWER_REYNOLDS: f64 = 2300.0;
+
+/// upper bound of the transition band used by the smoothed
+/// darcy friction factor, above this Re we use the churchill 
+/// correlation as is
+pub const SMOOTHING_UPPER_REYNOLDS: f64 = 4000.0;
+
+/// calculates darcy friction factor with a C1 continuous
+/// blend across the transition band (Re = 2300 to 4000)
+///
+/// Below Re = 2300, f = 64/Re
+/// Above Re = 4000, f is the churchill darcy friction factor
+///
+/// in between, i use a smoothstep weight
+///
+/// w = 3t^2 - 2t^3
+/// t = (Re - 2300)/(4000 - 2300)
+///
+/// f = (1-w) * 64/Re + w * f_churchill
+///
+/// the weight has zero slope at both ends of the band, so
+/// f and df/dRe are continuous everywhere. This helps
+/// root finders (eg. Brent) which may oscillate when the
+/// friction factor changes slope abruptly in the transition
+/// region.
+///
+/// Note: this slightly alters friction factor values within
+/// the transition region compared to the churchill correlation.
+/// Outside the transition region, values are the same
+/// as the churchill correlation (above Re = 4000) or 64/Re
+/// (below Re = 2300).
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let smoothed_friction_factor = 
+///     churchill_friction_factor::smoothed_darcy(3000.0, 0.0015);
+///
+/// println!("{}", smoothed_friction_factor);
+/// ```
+pub fn smoothed_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    if reynolds_number <= SMOOTHING_LOWER_REYNOLDS {
+        // still want the usual checks for Re <= 0 
+        // and roughness ratio
+        if reynolds_number <= 0.0 || roughness_ratio < 0.0 {
+            return darcy(reynolds_number, roughness_ratio);
+        }
+        return 64.0/reynolds_number;
+    }
+
+    if reynolds_number >= SMOOTHING_UPPER_REYNOLDS {
+        return darcy(reynolds_number, roughness_ratio);
+    }
+
+    let laminar_friction_factor = 64.0/reynolds_number;
+    let churchill_friction_factor = darcy(reynolds_number, roughness_ratio);
+
+    let t = (reynolds_number - SMOOTHING_LOWER_REYNOLDS)/
+        (SMOOTHING_UPPER_REYNOLDS - SMOOTHING_LOWER_REYNOLDS);
+
+    // smoothstep weight, zero gradient at t = 0 and t = 1
+    let weight = 3.0 * t.powf(2.0) - 2.0 * t.powf(3.0);
+
+    return (1.0 - weight) * laminar_friction_factor 
+        + weight * churchill_friction_factor;
+}
+
+/// flow regime for pipe flow, based on Reynolds number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowRegime {
+    /// Re < 2300
+    Laminar,
+    /// 2300 <= Re <= 4000, where friction factor correlations
+    /// are least reliable
+    Transitional,
+    /// Re > 4000
+    Turbulent,
+}
+
+/// classifies the flow regime based on Reynolds number
+///
+/// laminar below Re = 2300, turbulent above Re = 4000 and 
+/// transitional in between (the same band as smoothed_darcy)
+///
+/// negative Re (reverse flow) is classified by its magnitude
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// assert_eq!(flow_regime(1800.0), FlowRegime::Laminar);
+/// assert_eq!(flow_regime(3000.0), FlowRegime::Transitional);
+/// assert_eq!(flow_regime(-1.0e5), FlowRegime::Turbulent);
+/// ```
+pub fn flow_regime(reynolds_number: f64) -> FlowRegime {
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number < SMOOTHING_LOWER_REYNOLDS {
+        return FlowRegime::Laminar;
+    }
+
+    if reynolds_number > SMOOTHING_UPPER_REYNOLDS {
+        return FlowRegime::Turbulent;
+    }
+
+    return FlowRegime::Transitional;
+}
+
+/// which part of the churchill correlation to use in darcy_with_mode
+///
+/// useful for validation, eg. to compare against laminar only 
+/// or turbulent only correlations within the transition band
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrictionMode {
+    /// usual churchill correlation, blending laminar and 
+    /// turbulent flow, same as darcy
+    Auto,
+    /// laminar only, f = 64/Re at any Re
+    ForceLaminar,
+    /// turbulent only, the churchill correlation without its 
+    /// laminar term
+    ForceTurbulent,
+}
+
+/// calculates darcy friction factor using churchill correlation,
+/// optionally forcing laminar only or turbulent only evaluation
+///
+/// FrictionMode::Auto is the same as darcy.
+///
+/// For FrictionMode::ForceTurbulent, i drop the laminar (8/Re)^12
+/// term in the churchill correlation, so that
+///
+/// f_fanning = 2 (A+B)^(-1/8)
+///
+/// this will panic for Re <= 0 or roughness ratio < 0, like darcy
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// // laminar friction factor, even in the transition band
+/// let laminar_darcy = darcy_with_mode(3000.0, 0.0, 
+///     FrictionMode::ForceLaminar);
+///
+/// assert_eq!(laminar_darcy, 64.0/3000.0);
+///
+/// // auto is just the usual churchill correlation
+/// assert_eq!(darcy_with_mode(3000.0, 0.0, FrictionMode::Auto),
+///     darcy(3000.0, 0.0));
+/// ```
+#[allow(non_snake_case)]
+pub fn darcy_with_mode(reynolds_number: f64, 
+                       roughness_ratio: f64,
+                       mode: FrictionMode) -> f64 {
+
+    if let Err(error) = try_darcy(reynolds_number, roughness_ratio) {
+        panic!("{}", error);
+    }
+
+    match mode {
+        FrictionMode::Auto => {
+            return darcy(reynolds_number, roughness_ratio);
+        },
+        FrictionMode::ForceLaminar => {
+            return 64.0/reynolds_number;
+        },
+        FrictionMode::ForceTurbulent => {
+            let Aterm = A(reynolds_number, roughness_ratio);
+            let Bterm = B(reynolds_number);
+
+            let turbulentTerm = (1.0/(Aterm + Bterm)).powf(3.0/2.0);
+            let fanningFrictionFactor = 2.0 * turbulentTerm.powf(1.0/12.0);
+
+            return 4.0 * fanningFrictionFactor;
+        },
+    }
+}
+
+
+/// lower end of the band scanned by transition_uncertainty_peak
+pub const TRANSITION_SCAN_LOWER_REYNOLDS: f64 = 2000.0;
+
+/// upper end of the band scanned by transition_uncertainty_peak
+pub const TRANSITION_SCAN_UPPER_REYNOLDS: f64 = 4000.0;
+
+/// finds the Reynolds number in the transition band where the 
+/// churchill friction factor is furthest from both the laminar
+/// and turbulent branches, for flagging high uncertainty 
+/// operating points
+///
+/// i scan Re = 2000 to 4000 in steps of 10, and at each Re compare
+/// the churchill friction factor (which blends the two branches)
+/// to the laminar extrapolation (64/Re) and the turbulent 
+/// extrapolation (FrictionMode::ForceTurbulent), taking the 
+/// nearer of the two:
+///
+/// relative_difference = 
+/// min(|f_churchill - f_laminar|, |f_churchill - f_turbulent|) 
+/// / f_churchill
+///
+/// returns (reynolds_number, relative_difference) at the peak.
+///
+/// Far from the crossing, the blend follows one branch closely, 
+/// so the relative difference is small. The peak is near where 
+/// the two branches cross (about Re = 2240, a 5% difference 
+/// for most roughness ratios), where neither branch is a 
+/// good estimate and the friction factor (and pressure loss) 
+/// is least certain.
+///
+/// panics for roughness ratio < 0
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let (peak_reynolds, peak_relative_difference) = 
+///     transition_uncertainty_peak(1.0e-4);
+///
+/// assert!(peak_reynolds > 2000.0 && peak_reynolds < 4000.0);
+/// assert!(peak_relative_difference > 0.0);
+/// ```
+pub fn transition_uncertainty_peak(roughness_ratio: f64) -> (f64, f64) {
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    let reynolds_step = 10.0;
+    let number_of_steps = ((TRANSITION_SCAN_UPPER_REYNOLDS 
+                            - TRANSITION_SCAN_LOWER_REYNOLDS)
+                           / reynolds_step) as usize;
+
+    let mut peak_reynolds = TRANSITION_SCAN_LOWER_REYNOLDS;
+    let mut peak_relative_difference = 0.0;
+
+    for step in 0..=number_of_steps {
+
+        let reynolds_number = TRANSITION_SCAN_LOWER_REYNOLDS 
+            + reynolds_step * step as f64;
+
+        let laminar_darcy = darcy_with_mode(reynolds_number, 
+                                            roughness_ratio,
+                                            FrictionMode::ForceLaminar);
+
+        let turbulent_darcy = darcy_with_mode(reynolds_number, 
+                                              roughness_ratio,
+                                              FrictionMode::ForceTurbulent);
+
+        let blended_darcy = darcy(reynolds_number, roughness_ratio);
+
+        // distance from the blend to the nearer branch
+        let relative_difference = 
+            (blended_darcy - laminar_darcy).abs()
+            .min((blended_darcy - turbulent_darcy).abs())
+            / blended_darcy;
+
+        if relative_difference > peak_relative_difference {
+            peak_reynolds = reynolds_number;
+            peak_relative_difference = relative_difference;
+        }
+    }
+
+    return (peak_reynolds, peak_relative_difference);
+}
+
+/// calculates laminar darcy friction factor with a user
+/// supplied laminar constant C
+///
+/// f_darcy = C/Re
+///
+/// C = 64 for circular pipes, 96 for parallel plates,
+/// and about 56.9 for square ducts
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// assert_eq!(darcy_laminar_constant(1000.0, 96.0), 0.096);
+/// ```
+pub fn darcy_laminar_constant(reynolds_number: f64, 
+                              laminar_constant: f64) -> f64 {
+
+    if reynolds_number == 0.0 {
+        panic!("Re = 0.0");
+    }
+
+    if reynolds_number < 0.0 {
+        panic!("Re<0.0");
+    }
+
+    if laminar_constant <= 0.0 {
+        panic!("laminar constant <= 0.0, nonphysical");
+    }
+
+    return laminar_constant/reynolds_number;
+}
+
+/// calculates darcy friction factor using churchill correlation
+/// for non circular ducts, where the laminar friction factor
+/// is C/Re rather than 64/Re
+///
+/// the laminar term of the churchill correlation is changed 
+/// from (8/Re)^12 to ((C/8)/Re)^12 so that it tends to C/Re 
+/// at low Re, the turbulent terms are unchanged (use the 
+/// hydraulic diameter for Re and roughness ratio as usual)
+///
+/// C = 64 gives the same result as darcy
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// assert_eq!(darcy_noncircular(1000.0, 0.0, 64.0), darcy(1000.0, 0.0));
+/// ```
+#[allow(non_snake_case)]
+pub fn darcy_noncircular(reynolds_number: f64, 
+                         roughness_ratio: f64,
+                         laminar_constant: f64) -> f64 {
+
+    if let Err(error) = try_darcy(reynolds_number, roughness_ratio) {
+        panic!("{}", error);
+    }
+
+    if laminar_constant <= 0.0 {
+        panic!("laminar constant <= 0.0, nonphysical");
+    }
+
+    // in fanning terms the laminar friction factor is 
+    // 2 * (C/8)/Re, ie. 16/Re for C = 64
+    let laminar_over_Re = (laminar_constant/8.0)/reynolds_number;
+    let laminarTerm = laminar_over_Re.powf(12.0);
+
+    let Aterm = A(reynolds_number, roughness_ratio);
+    let Bterm = B(reynolds_number);
+
+    let APlusBInverse = 1.0/(Aterm+Bterm);
+    let turbulentTerm = APlusBInverse.powf(3.0/2.0);
+
+    let fanningFrictionFactor = 2.0 * 
+        (laminarTerm + turbulentTerm).powf(1.0/12.0);
+
+    return 4.0 * fanningFrictionFactor;
+}
 
 #[allow(non_snake_case)]
 
@@ -169,11 +1211,93 @@ pub fn fLDK(ReynoldsNumber: f64,
 #[allow(non_snake_case)]
 /// calculates a nondimensional pressure loss (Be_D)
 /// from the nondimensionalised flowrate (Re_D)
-pub fn getBe(mut ReynoldsNumber: f64,
+pub fn getBe(ReynoldsNumber: f64,
              roughnessRatio: f64,
              lengthToDiameterRatio: f64,
              K: f64) -> f64{
 
+    return get_bejan_number_with_smoothing(ReynoldsNumber,
+                                           roughnessRatio,
+                                           lengthToDiameterRatio,
+                                           K,
+                                           false);
+}
+
+/// calculates a nondimensional pressure loss (Be_D)
+/// from the nondimensionalised flowrate (Re_D), same as getBe,
+/// but also returns the darcy friction factor used
+///
+/// returns (Be_D, darcy), so you don't have to call darcy()
+/// separately when debugging. For reverse flow, Be_D is negative
+/// but the friction factor is that of |Re|. At Re = 0, Be_D is 0 
+/// and the (laminar) friction factor is infinite
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let (bejan_number, darcy_friction_factor) = 
+///     get_bejan_d_with_friction(5000.0, 0.001, 10.0, 2.0);
+///
+/// assert_eq!(bejan_number, getBe(5000.0, 0.001, 10.0, 2.0));
+/// assert_eq!(darcy_friction_factor, darcy(5000.0, 0.001));
+/// ```
+pub fn get_bejan_d_with_friction(reynolds_number: f64,
+                                 roughness_ratio: f64,
+                                 length_to_diameter_ratio: f64,
+                                 form_loss_k: f64) -> (f64, f64) {
+
+    debug_assert!(reynolds_number.is_finite() 
+                  && length_to_diameter_ratio.is_finite()
+                  && form_loss_k.is_finite(),
+                  "Re, L/D or K is NaN or infinite");
+
+    if reynolds_number == 0.0 {
+        return (0.0, f64::INFINITY);
+    }
+
+    if roughness_ratio < 0.0 {
+        panic!("roughnessRatio<0.0");
+    }
+
+    if length_to_diameter_ratio <= 0.0 {
+        panic!("lengthToDiameterRatio<=0.0");
+    }
+
+    if form_loss_k < 0.0 {
+        panic!("Form loss coefficient K < 0.0");
+    }
+
+    let darcy_friction_factor = darcy(reynolds_number.abs(), 
+                                      roughness_ratio);
+
+    let fldk = darcy_friction_factor * length_to_diameter_ratio 
+        + form_loss_k;
+
+    let bejan_number = 0.5 * fldk * reynolds_number.powf(2.0) 
+        * reynolds_number.signum();
+
+    return (bejan_number, darcy_friction_factor);
+}
+
+#[allow(non_snake_case)]
+/// calculates a nondimensional pressure loss (Be_D)
+/// from the nondimensionalised flowrate (Re_D)
+///
+/// if smoothed is true, the smoothed_darcy friction factor
+/// is used instead of the churchill correlation, 
+/// this slightly alters values in the transition region
+/// (Re = 2300 to 4000) but improves solver robustness
+pub fn get_bejan_number_with_smoothing(mut ReynoldsNumber: f64,
+             roughnessRatio: f64,
+             lengthToDiameterRatio: f64,
+             K: f64,
+             smoothed: bool) -> f64{
+
+    debug_assert!(ReynoldsNumber.is_finite() 
+                  && lengthToDiameterRatio.is_finite()
+                  && K.is_finite(),
+                  "Re, L/D or K is NaN or infinite");
+
     if ReynoldsNumber == 0.0 {
         return 0.0;
     }
@@ -197,7 +1321,11 @@ pub fn getBe(mut ReynoldsNumber: f64,
         panic!("Form loss coefficient K < 0.0");
     }
 
-    let f = darcy(ReynoldsNumber, roughnessRatio);
+    let f = if smoothed {
+        smoothed_darcy(ReynoldsNumber, roughnessRatio)
+    } else {
+        darcy(ReynoldsNumber, roughnessRatio)
+    };
 
     let fLDK = f*lengthToDiameterRatio + K;
 
@@ -216,21 +1344,132 @@ pub fn getBe(mut ReynoldsNumber: f64,
 ///
 /// it is basically calculating nondimensionalised
 /// flowrate from nondimensionalised pressure loss
-pub fn getRe(mut Be_D: f64,
+pub fn getRe(Be_D: f64,
              roughnessRatio: f64,
              lengthToDiameter: f64,
              formLossK: f64) -> f64 {
 
+    return get_reynolds_number_with_smoothing(Be_D,
+                                              roughnessRatio,
+                                              lengthToDiameter,
+                                              formLossK,
+                                              false);
+}
+
+/// errors which may be returned when solving for Reynolds number
+/// from Bejan number instead of panicking
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReynoldsSolveError {
+    /// Bejan number or one of the other inputs is NaN or 
+    /// infinite, or the root finder returned NaN
+    NotANumber,
+    /// length to diameter ratio <= 0, roughness ratio < 0 
+    /// or form loss K < 0
+    InvalidInput(&'static str),
+    /// the Bejan number is larger than that at the 
+    /// upper end of the Re bracket (Re = 1e12), so the 
+    /// root cannot be bracketed
+    OutOfBracket {
+        /// magnitude of the Bejan number supplied
+        bejan_number: f64,
+        /// Bejan number at the upper end of the bracket
+        max_bejan_number: f64,
+    },
+    /// the root finder did not converge within its 
+    /// iteration limit
+    NonConvergence,
+}
+
+impl fmt::Display for ReynoldsSolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReynoldsSolveError::NotANumber => 
+                write!(f, "Re solve: NaN or infinity in inputs or solution"),
+            ReynoldsSolveError::InvalidInput(msg) => 
+                write!(f, "{}", msg),
+            ReynoldsSolveError::OutOfBracket { bejan_number, max_bejan_number } => 
+                write!(f, "Be too large: Be = {} exceeds max Be = {} (Re = 1e12)",
+                       bejan_number, max_bejan_number),
+            ReynoldsSolveError::NonConvergence => 
+                write!(f, "Re solve: root finder did not converge"),
+        }
+    }
+}
+
+impl std::error::Error for ReynoldsSolveError {}
+
+#[allow(non_snake_case)]
+/// calculates Re given a Be_D 
+///
+/// if smoothed is true, the smoothed_darcy friction factor
+/// is used instead of the churchill correlation
+///
+/// this panics if the solve fails, use
+/// try_get_reynolds_number_with_smoothing to handle 
+/// the errors yourself
+pub fn get_reynolds_number_with_smoothing(Be_D: f64,
+             roughnessRatio: f64,
+             lengthToDiameter: f64,
+             formLossK: f64,
+             smoothed: bool) -> f64 {
+
+    match try_get_reynolds_number_with_smoothing(Be_D,
+                                                 roughnessRatio,
+                                                 lengthToDiameter,
+                                                 formLossK,
+                                                 smoothed) {
+        Ok(reynolds_number) => return reynolds_number,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+#[allow(non_snake_case)]
+/// calculates Re given a Be_D, but returns an error
+/// instead of panicking if the inputs are invalid or the 
+/// root finder fails
+///
+/// This way you can tell "Re really is tiny" apart from
+/// "the solver gave up"
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::*;
+///
+/// let reynolds_number_result = 
+///     try_get_reynolds_number_with_smoothing(1.0e30, 0.00014, 10.0, 5.0, false);
+///
+/// assert!(matches!(reynolds_number_result, 
+///     Err(ReynoldsSolveError::OutOfBracket { .. })));
+/// ```
+pub fn try_get_reynolds_number_with_smoothing(mut Be_D: f64,
+             roughnessRatio: f64,
+             lengthToDiameter: f64,
+             formLossK: f64,
+             smoothed: bool) -> Result<f64, ReynoldsSolveError> {
+
+    if !Be_D.is_finite() || !roughnessRatio.is_finite() 
+        || !lengthToDiameter.is_finite() || !formLossK.is_finite() {
+        return Err(ReynoldsSolveError::NotANumber);
+    }
+
     if lengthToDiameter <= 0.0 {
-        panic!("lengthToDiameterRatio<=0.0");
+        return Err(ReynoldsSolveError::InvalidInput(
+                "lengthToDiameterRatio<=0.0"));
     }
 
     if roughnessRatio < 0.0 {
-        panic!("roughnessRatio<0.0");
+        return Err(ReynoldsSolveError::InvalidInput(
+                "roughnessRatio<0.0"));
     }
 
     if formLossK < 0.0 {
-        panic!("formLossK<0.0");
+        return Err(ReynoldsSolveError::InvalidInput(
+                "formLossK<0.0"));
+    }
+
+    // zero pressure loss means zero flow, the root is 
+    // at the end of the bracket so i return it directly
+    if Be_D == 0.0 {
+        return Ok(0.0);
     }
 
     // this part deals with negative Be_L values
@@ -245,11 +1484,13 @@ pub fn getRe(mut Be_D: f64,
 
     // i calculate the Be_D corresponding to 
     // Re = 1e12
-    let maxBe_D = getBe(maxRe,roughnessRatio, 
-                        lengthToDiameter,formLossK);
+    let maxBe_D = get_bejan_number_with_smoothing(maxRe,roughnessRatio, 
+                        lengthToDiameter,formLossK,smoothed);
 
     if Be_D >= maxBe_D {
-        panic!("Be too large");
+        return Err(ReynoldsSolveError::OutOfBracket { 
+            bejan_number: Be_D, 
+            max_bejan_number: maxBe_D });
     }
     // the above checks for all the relevant exceptions
     // including formLossK < 0
@@ -278,9 +1519,11 @@ pub fn getRe(mut Be_D: f64,
         // https://docs.rs/peroxide/latest/peroxide/structure/ad/index.html
 
         let reynoldsDouble = Re.x();
-        let fLDKterm = getBe(reynoldsDouble, roughnessRatio,
-                             lengthToDiameter,
-                             formLossK);
+        let fLDKterm = get_bejan_number_with_smoothing(
+            reynoldsDouble, roughnessRatio,
+            lengthToDiameter,
+            formLossK,
+            smoothed);
 
         return AD0(Be_D - fLDKterm);
 
@@ -291,17 +1534,25 @@ pub fn getRe(mut Be_D: f64,
                                          100,
                                          1e-8);
 
+    // instead of unwrapping, i pass the root finder
+    // errors on to the user
+    let mut ReynoldsNumber = match ReynoldsNumberResult {
+        Ok(ReynoldsNumber) => ReynoldsNumber,
+        Err(RootError::NaNRoot) => 
+            return Err(ReynoldsSolveError::NotANumber),
+        Err(_) => 
+            return Err(ReynoldsSolveError::NonConvergence),
+    };
 
-
-    // the unwrap turns the result into f64
-    let mut ReynoldsNumber = ReynoldsNumberResult.unwrap();
-
+    if ReynoldsNumber.is_nan() {
+        return Err(ReynoldsSolveError::NotANumber);
+    }
 
     if isNegative
     {
         ReynoldsNumber = ReynoldsNumber * -1.0;
-        return ReynoldsNumber;
+        return Ok(ReynoldsNumber);
     }
 
-    return ReynoldsNumber;
+    return Ok(ReynoldsNumber);
 }
@@ -0,0 +1,122 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are conversions between the friction factor and 
+// dimensionless pressure loss conventions used in this crate
+// and those used in some papers
+//
+// the definitions used in this crate are:
+//
+// darcy friction factor: f_darcy = 4 f_fanning
+// (laminar flow in a circular pipe: f_darcy = 64/Re, 
+// f_fanning = 16/Re)
+//
+// Reynolds number: Re = rho U D / mu
+//
+// Bejan number: Be_D = dp D^2 / (mu nu) = dp rho D^2 / mu^2
+// and for pipes, Be_D = 0.5 Re^2 (f_darcy L/D + K)
+//
+// Euler number: Eu = dp / (rho U^2)
+//
+// so that Eu = Be_D/Re^2 = 0.5 (f_darcy L/D + K)
+
+/// converts a fanning friction factor to a darcy friction factor
+///
+/// f_darcy = 4 f_fanning
+///
+/// ```rust
+/// use fluid_mechanics_rust::conversions::*;
+///
+/// // laminar flow at Re = 1000, f_fanning = 16/Re
+/// assert_eq!(fanning_to_darcy(16.0/1000.0), 64.0/1000.0);
+/// ```
+pub fn fanning_to_darcy(f_fanning: f64) -> f64 {
+    return 4.0 * f_fanning;
+}
+
+/// converts a darcy friction factor to a fanning friction factor
+///
+/// f_fanning = f_darcy/4
+pub fn darcy_to_fanning(f_darcy: f64) -> f64 {
+    return f_darcy / 4.0;
+}
+
+/// converts a Bejan number (Be_D) to an Euler number
+///
+/// Eu = Be_D / Re^2
+///
+/// both are based on the same pressure loss, and Re on the 
+/// same hydraulic diameter, panics if Re = 0
+///
+/// ```rust
+/// use fluid_mechanics_rust::conversions::*;
+///
+/// // Be_D = 0.5 Re^2 fldk, so Eu = 0.5 fldk
+/// let fldk = 3.0;
+/// let reynolds_number = 5000.0_f64;
+/// let bejan_number = 0.5 * reynolds_number.powf(2.0) * fldk;
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(bejan_to_euler(bejan_number, reynolds_number),
+///     0.5 * fldk, max_relative = 1e-12);
+/// ```
+pub fn bejan_to_euler(be: f64, reynolds: f64) -> f64 {
+
+    if reynolds == 0.0 {
+        panic!("Re = 0.0, Euler number is undefined");
+    }
+
+    return be / reynolds.powf(2.0);
+}
+
+/// converts an Euler number to a Bejan number (Be_D)
+///
+/// Be_D = Eu Re^2
+pub fn euler_to_bejan(euler_number: f64, reynolds: f64) -> f64 {
+    return euler_number * reynolds.powf(2.0);
+}
@@ -0,0 +1,112 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are helpers for developing flow near the pipe entrance,
+// where the fully developed friction factor used everywhere else
+// in this crate does not quite apply
+//
+// near the entrance, the velocity profile is still developing,
+// so wall shear (and pressure loss) differs from the fully
+// developed value. For short components, the entrance region is 
+// a large part of the component, so it's worth checking
+
+use uom::si::f64::*;
+use crate::churchill_friction_factor::SMOOTHING_LOWER_REYNOLDS;
+
+/// estimates the hydrodynamic entrance length, ie. the length 
+/// of pipe needed for the velocity profile to become fully developed
+///
+/// laminar flow (Re < 2300): L_e = 0.05 Re D
+///
+/// turbulent flow (Re >= 2300): L_e = 10 D
+///
+/// these are rough estimates, in particular, the entrance length
+/// jumps at Re = 2300 since there is no blending across the 
+/// transition region. Negative Re (reverse flow) is 
+/// taken by its magnitude
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use fluid_mechanics_rust::developing_flow::*;
+///
+/// let diameter = Length::new::<meter>(0.05);
+///
+/// // turbulent flow develops within 10 diameters
+/// let turbulent_entrance_length = entrance_length(1.0e5, diameter);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(turbulent_entrance_length.get::<meter>(),
+///                             0.5, max_relative = 1e-12);
+/// ```
+pub fn entrance_length(reynolds_number: f64, diameter: Length) -> Length {
+
+    if diameter.value <= 0.0 {
+        panic!("diameter <= 0.0, nonphysical");
+    }
+
+    let reynolds_number = reynolds_number.abs();
+
+    if reynolds_number < SMOOTHING_LOWER_REYNOLDS {
+        return 0.05 * reynolds_number * diameter;
+    }
+
+    return 10.0 * diameter;
+}
+
+/// returns true if the component is at least as long as the 
+/// entrance length (see entrance_length), ie. the flow is 
+/// fully developed by the component outlet
+///
+/// if this is false, the fully developed friction factor may 
+/// over or under predict the pressure loss in the component
+pub fn is_fully_developed(component_length: Length,
+                          entrance_length: Length) -> bool {
+
+    return component_length >= entrance_length;
+}
@@ -65,6 +65,78 @@ pub fn convert_dimensionless_number_to_float(dimensionless_number: Ratio) -> f64
     return dimensionless_number.value.into();
 }
 
+/// converts absolute roughness and hydraulic diameter
+/// into relative roughness (roughness ratio), ie. e/D
+///
+/// panics if the hydraulic diameter is zero or negative,
+/// or if the absolute roughness is negative
+pub fn relative_roughness(absolute_roughness: Length,
+                          hydraulic_diameter: Length) -> f64 {
+
+    if hydraulic_diameter.value <= 0.0 {
+        panic!("hydraulic Diameter <= 0.0, nonphysical");
+    }
+
+    if absolute_roughness.value < 0.0 {
+        panic!("absolute roughness < 0.0, nonphysical");
+    }
+
+    return convert_dimensionless_number_to_float(
+        absolute_roughness/hydraulic_diameter);
+}
+
+
+/// Reynolds number wrapped in a struct, so that it isn't 
+/// mixed up with other f64 values
+///
+/// the sign of the Reynolds number gives the flow direction,
+/// negative means reverse flow
+///
+/// ```rust
+/// use fluid_mechanics_rust::dimensionalisation::ReynoldsNumber;
+/// use fluid_mechanics_rust::churchill_friction_factor::FlowRegime;
+///
+/// let reynolds_number = ReynoldsNumber::from(-1.0e5);
+///
+/// assert_eq!(reynolds_number.magnitude(), 1.0e5);
+/// assert!(reynolds_number.is_reverse_flow());
+/// assert_eq!(reynolds_number.regime(), FlowRegime::Turbulent);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ReynoldsNumber(pub f64);
+
+impl ReynoldsNumber {
+
+    /// returns the magnitude of the Reynolds number,
+    /// ie. without the flow direction
+    pub fn magnitude(&self) -> f64 {
+        return self.0.abs();
+    }
+
+    /// returns true if Re < 0,
+    /// zero flow is not reverse flow
+    pub fn is_reverse_flow(&self) -> bool {
+        return self.0 < 0.0;
+    }
+
+    /// returns the flow regime based on the magnitude of Re
+    pub fn regime(&self) -> crate::churchill_friction_factor::FlowRegime {
+        return crate::churchill_friction_factor::flow_regime(self.0);
+    }
+}
+
+impl From<f64> for ReynoldsNumber {
+    fn from(reynolds_number: f64) -> Self {
+        return Self(reynolds_number);
+    }
+}
+
+impl From<ReynoldsNumber> for f64 {
+    fn from(reynolds_number: ReynoldsNumber) -> Self {
+        return reynolds_number.0;
+    }
+}
+
 
 /// struct which contains associated functions to calculate Re
 ///
@@ -80,6 +152,9 @@ impl CalcReynolds {
                      hydraulic_diameter: Length,
                      fluidViscosity: DynamicViscosity) -> f64 {
 
+        debug_assert!(velocity.value.is_finite(), 
+                      "velocity is NaN or infinite");
+
         if fluidViscosity.value <= 0.0 {
             panic!("fluid Viscosity <= 0.0, nonphysical");
         }
@@ -104,6 +179,20 @@ impl CalcReynolds {
     }
 
 
+    /// calculates Re = mass_flow/area * D_H/mu
+    /// same as from_mass_rate, but returns a ReynoldsNumber
+    pub fn from_mass_rate_typed(fluid_mass_flowrate: MassRate,
+                                cross_sectional_area: Area,
+                                hydraulic_diameter: Length,
+                                fluid_viscosity: DynamicViscosity) 
+        -> ReynoldsNumber {
+
+        return ReynoldsNumber(Self::from_mass_rate(fluid_mass_flowrate,
+                                                   cross_sectional_area,
+                                                   hydraulic_diameter,
+                                                   fluid_viscosity));
+    }
+
     #[allow(non_snake_case)]
     /// calculates Re = mass_flow/area * D_H/mu
     pub fn from_mass_rate(fluidMassFlowrate: MassRate,
@@ -111,6 +200,9 @@ impl CalcReynolds {
                         hydraulic_diameter: Length,
                         fluidViscosity: DynamicViscosity) -> f64 {
 
+        debug_assert!(fluidMassFlowrate.value.is_finite(), 
+                      "mass flowrate is NaN or infinite");
+
         if fluidViscosity.value <= 0.0 {
             panic!("fluid Viscosity <= 0.0, nonphysical");
         }
@@ -131,6 +223,47 @@ impl CalcReynolds {
         return convert_dimensionless_number_to_float(reynolds_number);
     }
 
+
+    /// calculates Re = G * D_H/mu
+    ///
+    /// where G is the mass flux (mass_flow/area), for correlations 
+    /// which are written in terms of mass flux.
+    /// This is the same as from_mass_rate with G = mass_flow/area
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::length::meter;
+    /// use uom::si::mass_flux::kilogram_per_square_meter_second;
+    /// use uom::si::dynamic_viscosity::millipascal_second;
+    /// use fluid_mechanics_rust::dimensionalisation::CalcReynolds;
+    ///
+    /// let reynolds_number = CalcReynolds::from_mass_flux(
+    ///     MassFlux::new::<kilogram_per_square_meter_second>(1000.0),
+    ///     Length::new::<meter>(0.02),
+    ///     DynamicViscosity::new::<millipascal_second>(1.0));
+    ///
+    /// extern crate approx;
+    /// approx::assert_relative_eq!(reynolds_number, 20000.0, 
+    ///                             max_relative = 1e-12);
+    /// ```
+    pub fn from_mass_flux(mass_flux: MassFlux,
+                          hydraulic_diameter: Length,
+                          fluid_viscosity: DynamicViscosity) -> f64 {
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        if hydraulic_diameter.value <= 0.0 {
+            panic!("hydraulic Diameter <= 0.0, nonphysical");
+        }
+
+        let reynolds_number = mass_flux *
+            hydraulic_diameter/
+            fluid_viscosity;
+
+        return convert_dimensionless_number_to_float(reynolds_number);
+    }
     
     #[allow(non_snake_case)]
     /// converts Re to mass flowrate using
@@ -159,6 +292,33 @@ impl CalcReynolds {
 
         return fluidMassFlowrate;
     }
+
+    /// converts Re to velocity using
+    /// Re = rho * U * D /mu
+    pub fn to_velocity(reynolds_number: f64,
+                       hydraulic_diameter: Length,
+                       fluid_density: MassDensity,
+                       fluid_viscosity: DynamicViscosity) -> Velocity {
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        if hydraulic_diameter.value <= 0.0 {
+            panic!("hydraulic Diameter <= 0.0, nonphysical");
+        }
+
+        if fluid_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        let fluid_velocity = fluid_viscosity *
+            reynolds_number /
+            fluid_density /
+            hydraulic_diameter;
+
+        return fluid_velocity;
+    }
 }
 
 /// contains assoc functions which help calculate bejan
@@ -179,6 +339,8 @@ impl CalcBejan {
               fluidDensity: MassDensity,
               fluidViscosity: DynamicViscosity) -> f64 {
 
+        debug_assert!(fluidPressure.value.is_finite(), 
+                      "pressure is NaN or infinite");
 
         if fluidViscosity.value <= 0.0 {
             panic!("fluid Viscosity <= 0.0, nonphysical");
@@ -210,6 +372,7 @@ impl CalcBejan {
                        fluidDensity: MassDensity,
                        fluidViscosity: DynamicViscosity) -> Pressure {
 
+        debug_assert!(Be_D.is_finite(), "Be_D is NaN or infinite");
 
         if fluidViscosity.value <= 0.0 {
             panic!("fluid Viscosity <= 0.0, nonphysical");
@@ -231,5 +394,448 @@ impl CalcBejan {
         return fluidPressure;
     }
 
+    /// calculates Reynolds number straight from the pressure loss
+    /// and component geometry
+    ///
+    /// the pressure loss is nondimensionalised into Be_D, and then
+    /// get_reynolds_number is used to find Re
+    ///
+    /// Be_D = Delta P * rho * D_H^2 / mu^2
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::length::{meter, millimeter};
+    /// use uom::si::pressure::pascal;
+    /// use uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use uom::si::dynamic_viscosity::millipascal_second;
+    ///
+    /// use fluid_mechanics_rust::dimensionalisation::CalcBejan;
+    ///
+    /// let reynolds_number = CalcBejan::reynolds_from_pressure(
+    ///     Pressure::new::<pascal>(100.0),
+    ///     Length::new::<meter>(2.79e-2),
+    ///     Length::new::<meter>(1.0),
+    ///     0.0,
+    ///     Length::new::<millimeter>(0.001),
+    ///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+    ///     DynamicViscosity::new::<millipascal_second>(1.0));
+    ///
+    /// assert!(reynolds_number > 0.0);
+    /// ```
+    pub fn reynolds_from_pressure(pressure_loss: Pressure,
+                                  hydraulic_diameter: Length,
+                                  length: Length,
+                                  form_loss_k: f64,
+                                  roughness: Length,
+                                  density: MassDensity,
+                                  viscosity: DynamicViscosity) -> f64 {
+
+        let bejan_number = Self::from_pressure(pressure_loss,
+                                               hydraulic_diameter,
+                                               density,
+                                               viscosity);
+
+        let roughness_ratio = relative_roughness(roughness, 
+                                                 hydraulic_diameter);
+
+        let length_to_diameter = convert_dimensionless_number_to_float(
+            length/hydraulic_diameter);
+
+        return crate::get_reynolds_number(bejan_number,
+                                          roughness_ratio,
+                                          length_to_diameter,
+                                          form_loss_k);
+    }
+
+    /// calculates Bejan number directly from mass flowrate 
+    /// and component geometry, without going through 
+    /// the pressure loss
+    ///
+    /// Re is calculated with CalcReynolds::from_mass_rate, then 
+    /// Be = 0.5 * Re^2 * (f * (L/D) + K) with get_bejan_d
+    ///
+    /// the density does not actually change Be for a given mass 
+    /// flowrate (Be = Delta P rho D^2/mu^2 and Delta P ~ 1/rho), 
+    /// it is only checked so that the inputs are the same as the 
+    /// pressure based functions
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::area::square_meter;
+    /// use uom::si::length::{meter, millimeter};
+    /// use uom::si::mass_rate::kilogram_per_second;
+    /// use uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use uom::si::dynamic_viscosity::millipascal_second;
+    ///
+    /// use fluid_mechanics_rust::dimensionalisation::CalcBejan;
+    ///
+    /// let bejan_number = CalcBejan::from_mass_rate(
+    ///     MassRate::new::<kilogram_per_second>(0.5),
+    ///     Area::new::<square_meter>(6.11e-4),
+    ///     Length::new::<meter>(2.79e-2),
+    ///     Length::new::<meter>(1.0),
+    ///     Length::new::<millimeter>(0.001),
+    ///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+    ///     DynamicViscosity::new::<millipascal_second>(1.0),
+    ///     0.0);
+    ///
+    /// assert!(bejan_number > 0.0);
+    /// ```
+    pub fn from_mass_rate(mass_rate: MassRate,
+                          area: Area,
+                          diameter: Length,
+                          length: Length,
+                          roughness: Length,
+                          density: MassDensity,
+                          viscosity: DynamicViscosity,
+                          form_loss_k: f64) -> f64 {
+
+        if density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        let reynolds_number = CalcReynolds::from_mass_rate(
+            mass_rate,
+            area,
+            diameter,
+            viscosity);
+
+        let roughness_ratio = relative_roughness(roughness, diameter);
+
+        let length_to_diameter = convert_dimensionless_number_to_float(
+            length/diameter);
+
+        return crate::get_bejan_d(reynolds_number,
+                                  roughness_ratio,
+                                  length_to_diameter,
+                                  form_loss_k);
+    }
+
+
+}
+
+/// calculates the Grashof number for natural convection,
+///
+/// Gr = g beta delta_T L^3 rho^2 / mu^2
+///
+/// beta is the thermal expansion coefficient and L is the 
+/// characteristic length (eg. the height of a natural circulation 
+/// loop or diameter of a pipe). g is taken as 9.81 m/s^2
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::temperature_coefficient::per_kelvin;
+/// use uom::si::temperature_interval::kelvin;
+/// use uom::si::length::meter;
+/// use uom::si::dynamic_viscosity::pascal_second;
+/// use fluid_mechanics_rust::dimensionalisation::grashof_number;
+///
+/// let grashof = grashof_number(
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     TemperatureCoefficient::new::<per_kelvin>(2.0e-4),
+///     TemperatureInterval::new::<kelvin>(10.0),
+///     Length::new::<meter>(0.1),
+///     DynamicViscosity::new::<pascal_second>(0.001));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(grashof, 9.81*2.0e-4*10.0*1.0e-3*1.0e12,
+///     max_relative = 1e-12);
+/// ```
+pub fn grashof_number(density: MassDensity,
+                      thermal_expansion: TemperatureCoefficient,
+                      delta_temp: TemperatureInterval,
+                      length: Length,
+                      viscosity: DynamicViscosity) -> f64 {
+
+    if viscosity.value <= 0.0 {
+        panic!("fluid Viscosity <= 0.0, nonphysical");
+    }
+
+    if density.value <= 0.0 {
+        panic!("fluid Density <= 0.0, nonphysical");
+    }
+
+    if length.value <= 0.0 {
+        panic!("characteristic Length <= 0.0, nonphysical");
+    }
+
+    let g = Acceleration::new::<uom::si::acceleration::meter_per_second_squared>(
+        9.81);
+
+    // beta * delta_T is dimensionless, 
+    // i take the value directly since both are in SI units 
+    let expansion_ratio: f64 = thermal_expansion.value * delta_temp.value;
+
+    let grashof: Ratio = g * length * length * length * density * density
+        / viscosity / viscosity * expansion_ratio;
+
+    return convert_dimensionless_number_to_float(grashof);
+}
+
+/// calculates the Rayleigh number,
+///
+/// Ra = Gr Pr
+pub fn rayleigh_number(grashof: f64, prandtl: f64) -> f64 {
+
+    if prandtl <= 0.0 {
+        panic!("Prandtl number <= 0.0, nonphysical");
+    }
+
+    return grashof * prandtl;
+}
+
+/// calculates the wall shear stress in a pipe from mass flowrate,
+/// eg. for erosion or corrosion estimates
+///
+/// tau_w = f rho v^2 / 8
+///
+/// where f is the darcy friction factor (churchill correlation)
+/// and v = m/(rho A) is the mean velocity.
+///
+/// For reverse flow, the wall shear stress is negative
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::area::square_meter;
+/// use uom::si::length::{meter, millimeter};
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use fluid_mechanics_rust::dimensionalisation::wall_shear_stress;
+///
+/// // laminar flow, f = 64/Re, so tau_w = 8 mu v / D
+/// let tau_w = wall_shear_stress(
+///     MassRate::new::<kilogram_per_second>(0.001),
+///     Area::new::<square_meter>(1.0e-4),
+///     Length::new::<meter>(std::f64::consts::FRAC_2_SQRT_PI * 1.0e-2),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     Length::new::<millimeter>(0.0));
+///
+/// let expected_tau_w = 8.0 * 1.0e-3 * 1.0e-2 / 
+///     (std::f64::consts::FRAC_2_SQRT_PI * 1.0e-2);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(tau_w.value, expected_tau_w,
+///     max_relative = 1e-3);
+/// ```
+pub fn wall_shear_stress(mass_flowrate: MassRate,
+                         area: Area,
+                         diameter: Length,
+                         density: MassDensity,
+                         viscosity: DynamicViscosity,
+                         roughness: Length) -> Pressure {
+
+    if area.value <= 0.0 {
+        panic!("cross sectional Area <= 0.0, nonphysical");
+    }
+
+    if density.value <= 0.0 {
+        panic!("fluid Density <= 0.0, nonphysical");
+    }
+
+    // no flow, no shear
+    // (and darcy f is undefined at Re = 0)
+    if mass_flowrate.value == 0.0 {
+        return Pressure::new::<uom::si::pressure::pascal>(0.0);
+    }
+
+    let reynolds_number = CalcReynolds::from_mass_rate(
+        mass_flowrate,
+        area,
+        diameter,
+        viscosity);
+
+    let roughness_ratio = relative_roughness(roughness, diameter);
+
+    let darcy_friction_factor = crate::churchill_friction_factor::darcy(
+        reynolds_number.abs(), roughness_ratio);
+
+    let velocity: Velocity = velocity_from_mass_rate(mass_flowrate, 
+                                                     area, 
+                                                     density);
+
+    return darcy_friction_factor * density * velocity * velocity.abs() / 8.0;
+}
+
+/// calculates mean velocity from mass flowrate
+///
+/// U = m / (rho A)
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::area::square_meter;
+/// use uom::si::velocity::meter_per_second;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::dimensionalisation::velocity_from_mass_rate;
+///
+/// let velocity = velocity_from_mass_rate(
+///     MassRate::new::<kilogram_per_second>(0.5),
+///     Area::new::<square_meter>(1.0e-3),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(velocity.get::<meter_per_second>(), 0.5,
+///     max_relative = 1e-12);
+/// ```
+pub fn velocity_from_mass_rate(mass_rate: MassRate,
+                               area: Area,
+                               density: MassDensity) -> Velocity {
+
+    if area.value <= 0.0 {
+        panic!("cross sectional Area <= 0.0, nonphysical");
+    }
+
+    if density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    return mass_rate / density / area;
+}
+
+/// calculates mass flux from mass flowrate, 
+/// for flux based correlations
+///
+/// G = m / A
+pub fn mass_flux(mass_rate: MassRate, area: Area) -> MassFlux {
+
+    if area.value <= 0.0 {
+        panic!("cross sectional Area <= 0.0, nonphysical");
+    }
+
+    return mass_rate / area;
+}
+
+/// Reynolds number below which pipe flow is usually taken 
+/// to be laminar, same as the lower bound of the smoothed
+/// darcy friction factor transition band
+pub const LAMINAR_LIMIT_RE: f64 = 
+    crate::churchill_friction_factor::SMOOTHING_LOWER_REYNOLDS;
+
+/// Reynolds number above which pipe flow is usually taken 
+/// to be fully turbulent, same as the upper bound of the smoothed
+/// darcy friction factor transition band
+pub const TURBULENT_LIMIT_RE: f64 = 
+    crate::churchill_friction_factor::SMOOTHING_UPPER_REYNOLDS;
+
+/// calculates the mass flowrate at which a component reaches
+/// a target Reynolds number, eg. LAMINAR_LIMIT_RE or 
+/// TURBULENT_LIMIT_RE, for checking design margins
+///
+/// this is CalcReynolds::to_mass_rate, 
+/// m = Re * mu * A / D_H
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use fluid_mechanics_rust::dimensionalisation::*;
+///
+/// let laminar_limit_mass_flowrate = transition_mass_flowrate(
+///     LAMINAR_LIMIT_RE,
+///     Area::new::<square_meter>(6.11e-4),
+///     Length::new::<meter>(2.79e-2),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// // 2300 * 1e-3 * 6.11e-4 / 2.79e-2 kg/s
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     laminar_limit_mass_flowrate.get::<kilogram_per_second>(),
+///     0.05037, max_relative = 1e-3);
+/// ```
+pub fn transition_mass_flowrate(target_reynolds: f64,
+                                area: Area,
+                                diameter: Length,
+                                viscosity: DynamicViscosity) -> MassRate {
+
+    return CalcReynolds::to_mass_rate(area, 
+                                      target_reynolds, 
+                                      diameter, 
+                                      viscosity);
+}
+
+/// calculates the Euler number from a pressure loss
+///
+/// Eu = Delta P / (rho U^2)
+///
+/// this is the same as Be_D/Re^2 
+/// (see conversions::bejan_to_euler), where Be_D and Re are 
+/// based on the same diameter and viscosity
+///
+/// panics if velocity is zero since Eu is undefined there,
+/// or if density is nonphysical
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use uom::si::velocity::meter_per_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::dimensionalisation::euler_number;
+///
+/// let euler = euler_number(
+///     Pressure::new::<pascal>(500.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     Velocity::new::<meter_per_second>(1.0));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(euler, 0.5, max_relative = 1e-12);
+/// ```
+pub fn euler_number(pressure_loss: Pressure,
+                    density: MassDensity,
+                    velocity: Velocity) -> f64 {
+
+    if density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    if velocity.value == 0.0 {
+        panic!("velocity = 0.0, Euler number is undefined");
+    }
+
+    let euler_number_ratio: Ratio = pressure_loss / (density * velocity * velocity);
+
+    return convert_dimensionless_number_to_float(euler_number_ratio);
+}
+
+/// calculates the Euler number from a pressure loss and 
+/// mass flowrate, where U = m/(rho A), see euler_number
+pub fn euler_number_from_mass_rate(pressure_loss: Pressure,
+                                   density: MassDensity,
+                                   mass_rate: MassRate,
+                                   area: Area) -> f64 {
+
+    let velocity = velocity_from_mass_rate(mass_rate, area, density);
+
+    return euler_number(pressure_loss, density, velocity);
+}
+
+/// calculates the pressure coefficient from a pressure difference,
+/// ie. the pressure difference over the dynamic pressure
+///
+/// Cp = Delta P / (0.5 rho U^2) = 2 Eu
+///
+/// panics if velocity is zero, see euler_number
+pub fn pressure_coefficient(pressure_difference: Pressure,
+                            density: MassDensity,
+                            velocity: Velocity) -> f64 {
+
+    return 2.0 * euler_number(pressure_difference, density, velocity);
+}
 
+/// calculates the pressure coefficient from a pressure 
+/// difference and mass flowrate, where U = m/(rho A), 
+/// see pressure_coefficient
+pub fn pressure_coefficient_from_mass_rate(pressure_difference: Pressure,
+                                           density: MassDensity,
+                                           mass_rate: MassRate,
+                                           area: Area) -> f64 {
+
+    return 2.0 * euler_number_from_mass_rate(pressure_difference, 
+                                             density, 
+                                             mass_rate, 
+                                             area);
 }
@@ -0,0 +1,435 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+//! the super collection handles parallel branches sharing 
+//! one inlet and outlet pressure, but loops where a bypass joins
+//! at an intermediate node don't fit into pure series and parallel
+//! arrangements
+//!
+//! here, i represent the loop as a graph, nodes are junctions 
+//! and edges are fluid components. 
+//!
+//! the unknowns are the pressures at internal nodes. Once those are 
+//! known, the mass flowrate through each edge comes from its 
+//! pressure change, so pressure consistency around every loop is 
+//! automatically satisfied. The solver then only has to enforce 
+//! mass conservation at each internal node.
+//!
+//! For one unknown, this is the same problem the Brent root finder
+//! solves for the parallel super collection, but the node pressure
+//! has no natural bracket when pumps are in the network, so for any 
+//! number of unknowns (including one), i use a Newton iteration 
+//! with a finite difference jacobian and a backtracking step.
+//!
+//! At least one node must have a fixed pressure. For a closed loop,
+//! fix the pressure at any one node as a reference, mass 
+//! conservation at that node then follows from the other nodes.
+
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+
+use crate::fluid_component_calculation::FluidComponent;
+
+/// a junction in the flow network
+///
+/// if the fixed pressure is None, the pressure is solved for
+/// and mass must be conserved at the node
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Node {
+    /// pressure at a boundary or reference node, 
+    /// None for internal nodes
+    pub fixed_pressure: Option<Pressure>,
+}
+
+impl Node {
+
+    /// a boundary or reference node with a set pressure
+    pub fn fixed(pressure: Pressure) -> Self {
+        return Self { fixed_pressure: Some(pressure) };
+    }
+
+    /// an internal node where the pressure is solved for
+    pub fn internal() -> Self {
+        return Self { fixed_pressure: None };
+    }
+}
+
+/// a fluid component connecting two nodes,
+///
+/// positive mass flowrate goes from from_node to to_node
+/// and the pressure change of the component is 
+/// pressure(to_node) - pressure(from_node)
+pub struct Edge<'network_lifetime> {
+    /// index of the node at the component inlet
+    pub from_node: usize,
+    /// index of the node at the component outlet
+    pub to_node: usize,
+    /// the fluid component
+    pub fluid_component: &'network_lifetime dyn FluidComponent,
+}
+
+/// node pressures and edge mass flowrates of a solved 
+/// flow network, indexed the same way as the nodes and edges
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowNetworkSolution {
+    /// pressure at every node, including fixed ones
+    pub node_pressures: Vec<Pressure>,
+    /// mass flowrate through every edge, from_node to to_node
+    pub edge_mass_flowrates: Vec<MassRate>,
+}
+
+/// a graph of nodes and fluid component edges
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use fluid_mechanics_rust::flow_network::*;
+///
+/// let mut flow_network = FlowNetwork::new();
+///
+/// let inlet = flow_network.add_node(
+///     Node::fixed(Pressure::new::<pascal>(101325.0)));
+/// let junction = flow_network.add_node(Node::internal());
+///
+/// assert_eq!(inlet, 0);
+/// assert_eq!(junction, 1);
+/// ```
+pub struct FlowNetwork<'network_lifetime> {
+    /// the junctions
+    pub nodes: Vec<Node>,
+    /// the fluid components connecting the junctions
+    pub edges: Vec<Edge<'network_lifetime>>,
+    /// maximum number of newton iterations
+    pub max_iterations: usize,
+    /// the network is converged when the mass imbalance at every
+    /// internal node is below this fraction of the largest 
+    /// edge mass flowrate
+    pub relative_tolerance: f64,
+}
+
+impl<'network_lifetime> FlowNetwork<'network_lifetime> {
+
+    /// an empty flow network
+    pub fn new() -> Self {
+        return Self {
+            nodes: vec![],
+            edges: vec![],
+            max_iterations: 100,
+            relative_tolerance: 1e-9,
+        };
+    }
+
+    /// adds a node and returns its index
+    pub fn add_node(&mut self, node: Node) -> usize {
+        self.nodes.push(node);
+        return self.nodes.len() - 1;
+    }
+
+    /// adds a fluid component between two nodes 
+    /// and returns the edge index
+    pub fn add_edge(&mut self,
+                    from_node: usize,
+                    to_node: usize,
+                    fluid_component: &'network_lifetime dyn FluidComponent) 
+        -> usize {
+
+        if from_node >= self.nodes.len() || to_node >= self.nodes.len() {
+            panic!("edge refers to a node which is not in the network");
+        }
+
+        self.edges.push(Edge { from_node, to_node, fluid_component });
+        return self.edges.len() - 1;
+    }
+
+    /// calculates the mass flowrate through every edge 
+    /// given the pressure at every node
+    pub fn get_edge_mass_flowrates(
+        &self, 
+        node_pressures: &[Pressure]) -> Vec<MassRate> {
+
+        let mut edge_mass_flowrates: Vec<MassRate> = vec![];
+
+        for edge in self.edges.iter() {
+            let pressure_change = 
+                node_pressures[edge.to_node] - node_pressures[edge.from_node];
+
+            edge_mass_flowrates.push(
+                edge.fluid_component.
+                get_mass_flowrate_from_pressure_change_immutable(
+                    pressure_change));
+        }
+
+        return edge_mass_flowrates;
+    }
+
+    /// solves for the internal node pressures and edge 
+    /// mass flowrates
+    ///
+    /// panics if no node has a fixed pressure or if the 
+    /// solver does not converge
+    pub fn solve(&self) -> FlowNetworkSolution {
+
+        let fixed_pressures: Vec<Pressure> = self.nodes.iter().
+            filter_map(|node| node.fixed_pressure).collect();
+
+        if fixed_pressures.is_empty() {
+            panic!("flow network needs at least one node \
+                   with a fixed pressure");
+        }
+
+        // i start the internal nodes off at the average 
+        // of the fixed pressures
+        let mut average_fixed_pressure = Pressure::new::<pascal>(0.0);
+        for pressure in fixed_pressures.iter() {
+            average_fixed_pressure += *pressure;
+        }
+        average_fixed_pressure /= fixed_pressures.len() as f64;
+
+        let internal_node_indices: Vec<usize> = 
+            (0..self.nodes.len()).
+            filter(|index| self.nodes[*index].fixed_pressure.is_none()).
+            collect();
+
+        let mut node_pressures: Vec<Pressure> = self.nodes.iter().
+            map(|node| node.fixed_pressure.unwrap_or(average_fixed_pressure)).
+            collect();
+
+        if internal_node_indices.is_empty() {
+            let edge_mass_flowrates = 
+                self.get_edge_mass_flowrates(&node_pressures);
+
+            return FlowNetworkSolution { node_pressures, edge_mass_flowrates };
+        }
+
+        let number_of_unknowns = internal_node_indices.len();
+
+        for _ in 0..self.max_iterations {
+
+            let residuals = self.get_mass_imbalances(
+                &node_pressures, &internal_node_indices);
+
+            if self.is_converged(&node_pressures, &residuals) {
+                let edge_mass_flowrates = 
+                    self.get_edge_mass_flowrates(&node_pressures);
+
+                return FlowNetworkSolution { 
+                    node_pressures, 
+                    edge_mass_flowrates };
+            }
+
+            // finite difference jacobian, 
+            // d(mass imbalance)/d(node pressure)
+            let mut jacobian = vec![vec![0.0; number_of_unknowns]; 
+                number_of_unknowns];
+
+            for (column, node_index) in 
+                internal_node_indices.iter().enumerate() {
+
+                    let pressure_value = node_pressures[*node_index].value;
+                    let pressure_step = 
+                        1e-7 * pressure_value.abs().max(1.0);
+
+                    let mut perturbed_pressures = node_pressures.clone();
+                    perturbed_pressures[*node_index] = 
+                        Pressure::new::<pascal>(pressure_value + pressure_step);
+
+                    let perturbed_residuals = self.get_mass_imbalances(
+                        &perturbed_pressures, &internal_node_indices);
+
+                    for row in 0..number_of_unknowns {
+                        jacobian[row][column] = 
+                            (perturbed_residuals[row] - residuals[row])
+                            / pressure_step;
+                    }
+                }
+
+            let negative_residuals: Vec<f64> = 
+                residuals.iter().map(|residual| -residual).collect();
+
+            let pressure_update = 
+                solve_linear_system(jacobian, negative_residuals);
+
+            // backtracking, halve the step until the 
+            // mass imbalance gets smaller
+            let residual_norm = max_abs(&residuals);
+            let mut step_fraction = 1.0;
+
+            loop {
+                let mut trial_pressures = node_pressures.clone();
+
+                for (row, node_index) in 
+                    internal_node_indices.iter().enumerate() {
+                        trial_pressures[*node_index] += 
+                            Pressure::new::<pascal>(
+                                step_fraction * pressure_update[row]);
+                    }
+
+                let trial_residuals = self.get_mass_imbalances(
+                    &trial_pressures, &internal_node_indices);
+
+                if max_abs(&trial_residuals) < residual_norm || 
+                    step_fraction < 1e-4 {
+                        node_pressures = trial_pressures;
+                        break;
+                    }
+
+                step_fraction *= 0.5;
+            }
+        }
+
+        panic!("flow network solver did not converge");
+    }
+
+    /// mass flowing into each internal node minus mass flowing out,
+    /// in kg/s
+    fn get_mass_imbalances(
+        &self,
+        node_pressures: &[Pressure],
+        internal_node_indices: &[usize]) -> Vec<f64> {
+
+        let edge_mass_flowrates = 
+            self.get_edge_mass_flowrates(node_pressures);
+
+        let mut mass_imbalances = vec![0.0; internal_node_indices.len()];
+
+        for (row, node_index) in internal_node_indices.iter().enumerate() {
+            for (edge, mass_flowrate) in 
+                self.edges.iter().zip(edge_mass_flowrates.iter()) {
+
+                    let mass_flowrate_value = 
+                        mass_flowrate.get::<kilogram_per_second>();
+
+                    if edge.to_node == *node_index {
+                        mass_imbalances[row] += mass_flowrate_value;
+                    }
+
+                    if edge.from_node == *node_index {
+                        mass_imbalances[row] -= mass_flowrate_value;
+                    }
+                }
+        }
+
+        return mass_imbalances;
+    }
+
+    fn is_converged(&self,
+                    node_pressures: &[Pressure],
+                    mass_imbalances: &[f64]) -> bool {
+
+        let largest_mass_flowrate = 
+            self.get_edge_mass_flowrates(node_pressures).iter().
+            map(|mass_flowrate| mass_flowrate.get::<kilogram_per_second>().abs()).
+            fold(0.0, f64::max);
+
+        // if nothing flows at all, i only accept an exact balance
+        // up to round off
+        let mass_tolerance = 
+            (self.relative_tolerance * largest_mass_flowrate).max(1e-15);
+
+        return max_abs(mass_imbalances) <= mass_tolerance;
+    }
+}
+
+impl<'network_lifetime> Default for FlowNetwork<'network_lifetime> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+fn max_abs(values: &[f64]) -> f64 {
+    return values.iter().map(|value| value.abs()).fold(0.0, f64::max);
+}
+
+/// gaussian elimination with partial pivoting, 
+/// the networks here are small so this is good enough
+fn solve_linear_system(mut matrix: Vec<Vec<f64>>, 
+                       mut right_hand_side: Vec<f64>) -> Vec<f64> {
+
+    let size = right_hand_side.len();
+
+    for pivot in 0..size {
+
+        let mut largest_row = pivot;
+        for row in (pivot + 1)..size {
+            if matrix[row][pivot].abs() > matrix[largest_row][pivot].abs() {
+                largest_row = row;
+            }
+        }
+
+        if matrix[largest_row][pivot] == 0.0 {
+            panic!("singular flow network jacobian, \
+                   check that every internal node is connected");
+        }
+
+        matrix.swap(pivot, largest_row);
+        right_hand_side.swap(pivot, largest_row);
+
+        for row in (pivot + 1)..size {
+            let factor = matrix[row][pivot] / matrix[pivot][pivot];
+            let pivot_row = matrix[pivot].clone();
+            for (entry, pivot_entry) in matrix[row][pivot..].iter_mut().
+                zip(pivot_row[pivot..].iter()) {
+                    *entry -= factor * pivot_entry;
+                }
+            right_hand_side[row] -= factor * right_hand_side[pivot];
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+
+    for row in (0..size).rev() {
+        let mut sum = right_hand_side[row];
+        for column in (row + 1)..size {
+            sum -= matrix[row][column] * solution[column];
+        }
+        solution[row] = sum / matrix[row][row];
+    }
+
+    return solution;
+}
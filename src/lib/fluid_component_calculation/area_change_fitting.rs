@@ -0,0 +1,602 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are sudden expansion and contraction fittings, ie. Borda-Carnot losses
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::angle::degree;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_calculation::custom_component_calc;
+
+/// form loss K of a sudden expansion (Borda-Carnot),
+/// referenced to the velocity in the smaller (upstream) area
+///
+/// K = (1 - A_small/A_large)^2
+///
+/// area_ratio is A_small/A_large, which must be within (0,1]
+///
+/// ```rust
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// // no area change, no loss
+/// assert_eq!(sudden_expansion_k(1.0), 0.0);
+/// // discharge into a reservoir, all the kinetic energy is lost
+/// assert_eq!(sudden_expansion_k(1.0e-12), (1.0 - 1.0e-12_f64).powf(2.0));
+/// ```
+pub fn sudden_expansion_k(area_ratio: f64) -> f64 {
+
+    if area_ratio <= 0.0 || area_ratio > 1.0 {
+        panic!("area ratio must be within (0,1]");
+    }
+
+    return (1.0 - area_ratio).powf(2.0);
+}
+
+/// form loss K of a sudden contraction,
+/// referenced to the velocity in the smaller (downstream) area
+///
+/// the flow contracts into a vena contracta of area Cc A_small, 
+/// and the loss is the Borda-Carnot expansion from the vena 
+/// contracta back to A_small:
+///
+/// K = (1/Cc - 1)^2
+///
+/// where i use the empirical (Weisbach) contraction coefficient
+///
+/// Cc = 0.62 + 0.38 (A_small/A_large)^3
+///
+/// area_ratio is A_small/A_large, which must be within (0,1]
+pub fn sudden_contraction_k(area_ratio: f64) -> f64 {
+
+    if area_ratio <= 0.0 || area_ratio > 1.0 {
+        panic!("area ratio must be within (0,1]");
+    }
+
+    let contraction_coefficient = 0.62 + 0.38 * area_ratio.powf(3.0);
+
+    return (1.0/contraction_coefficient - 1.0).powf(2.0);
+}
+
+/// pressure loss across an area change with form losses only,
+/// referenced to the smaller area
+///
+/// the forward K is used for forward flow and reverse K for 
+/// reverse flow, since an expansion in reverse is a contraction
+fn area_change_pressure_loss(mass_flowrate: MassRate,
+                             smaller_area: Area,
+                             forward_k: f64,
+                             reverse_k: f64,
+                             fluid_viscosity: DynamicViscosity,
+                             fluid_density: MassDensity) -> Pressure {
+
+    let custom_k = |reynolds_number: f64| -> f64 {
+        if reynolds_number < 0.0 {
+            return -reverse_k;
+        }
+        return forward_k;
+    };
+
+    // zero friction factor, the fitting is a pure form loss
+    let custom_darcy = |_reynolds_number: f64, 
+    _roughness_ratio: f64| -> f64 {
+        return 0.0;
+    };
+
+    let hydraulic_diameter = circular_diameter(smaller_area);
+
+    return custom_component_calc::CalcPressureLoss::from_mass_rate(
+        mass_flowrate,
+        smaller_area,
+        hydraulic_diameter,
+        fluid_viscosity,
+        fluid_density,
+        hydraulic_diameter,
+        Length::new::<meter>(0.0),
+        &custom_darcy,
+        &custom_k);
+}
+
+/// mass flowrate across an area change with form losses only,
+/// the inverse of area_change_pressure_loss
+fn area_change_mass_flowrate(pressure_loss: Pressure,
+                             smaller_area: Area,
+                             forward_k: f64,
+                             reverse_k: f64,
+                             fluid_viscosity: DynamicViscosity,
+                             fluid_density: MassDensity) -> MassRate {
+
+    // with no loss at all (area ratio of 1), the flowrate
+    // can't be found from the pressure loss
+    if forward_k == 0.0 && reverse_k == 0.0 {
+        return MassRate::new::<kilogram_per_second>(0.0);
+    }
+
+    let custom_k = |reynolds_number: f64| -> f64 {
+        if reynolds_number < 0.0 {
+            return -reverse_k;
+        }
+        return forward_k;
+    };
+
+    let custom_darcy = |_reynolds_number: f64, 
+    _roughness_ratio: f64| -> f64 {
+        return 0.0;
+    };
+
+    let hydraulic_diameter = circular_diameter(smaller_area);
+
+    return custom_component_calc::CalcPressureLoss::to_mass_rate(
+        pressure_loss,
+        smaller_area,
+        hydraulic_diameter,
+        fluid_viscosity,
+        fluid_density,
+        hydraulic_diameter,
+        Length::new::<meter>(0.0),
+        &custom_darcy,
+        &custom_k);
+}
+
+/// diameter of a circle with the given area
+fn circular_diameter(area: Area) -> Length {
+    return Length::new::<meter>(
+        (4.0 * area.value / std::f64::consts::PI).sqrt());
+}
+
+/// checks the areas and fluid properties of the fittings
+fn check_area_change_inputs(upstream_area: Area,
+                            downstream_area: Area,
+                            fluid_density: MassDensity,
+                            fluid_viscosity: DynamicViscosity) {
+
+    if upstream_area.value <= 0.0 || downstream_area.value <= 0.0 {
+        panic!("cross sectional Area <= 0.0, nonphysical");
+    }
+
+    if fluid_density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    if fluid_viscosity.value <= 0.0 {
+        panic!("fluid Viscosity <= 0.0, nonphysical");
+    }
+}
+
+/// A sudden expansion from a smaller upstream area to 
+/// a larger downstream area
+///
+/// the form loss is K = (1 - A1/A2)^2 (see sudden_expansion_k), 
+/// referenced to the upstream velocity, and is fed into the custom 
+/// fldk functions with zero friction factor. 
+///
+/// In reverse flow, the fitting is a sudden contraction,
+/// so sudden_contraction_k is used instead.
+///
+/// Only the irreversible loss is included, the reversible 
+/// pressure change from the velocity change (Bernoulli) is not.
+/// The fitting is taken as horizontal, and the hydraulic diameter
+/// and component length are those of a circle with the 
+/// upstream area (the length is nominal, the fldk functions
+/// need one but the friction factor is zero anyway)
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::area::square_meter;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let expansion = SuddenExpansion::new(
+///     Area::new::<square_meter>(1.0e-4),
+///     Area::new::<square_meter>(4.0e-4),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// assert_eq!(expansion.form_loss_k(), 0.75_f64.powf(2.0));
+///
+/// // pressure loss is K rho u^2 / 2 with u = m/(rho A1) = 1 m/s
+/// let pressure_loss = expansion.get_pressure_loss_immutable(
+///     MassRate::new::<kilogram_per_second>(0.1));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(pressure_loss.value, 
+///     0.75_f64.powf(2.0) * 1000.0 * 0.5, max_relative = 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuddenExpansion {
+    /// the smaller, upstream cross sectional area
+    pub upstream_area: Area,
+    /// the larger, downstream cross sectional area
+    pub downstream_area: Area,
+    /// fluid density
+    pub fluid_density: MassDensity,
+    /// fluid viscosity
+    pub fluid_viscosity: DynamicViscosity,
+    /// current mass flowrate through the fitting
+    pub mass_flowrate: MassRate,
+}
+
+impl SuddenExpansion {
+
+    /// constructor, the fitting starts with zero mass flowrate
+    ///
+    /// panics if the downstream area is smaller than the upstream 
+    /// area or if the inputs are nonphysical
+    pub fn new(upstream_area: Area,
+               downstream_area: Area,
+               fluid_density: MassDensity,
+               fluid_viscosity: DynamicViscosity) -> Self {
+
+        check_area_change_inputs(upstream_area, downstream_area,
+                                 fluid_density, fluid_viscosity);
+
+        if downstream_area < upstream_area {
+            panic!("downstream area < upstream area, \
+                   use SuddenContraction instead");
+        }
+
+        return Self {
+            upstream_area,
+            downstream_area,
+            fluid_density,
+            fluid_viscosity,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+
+    /// returns A1/A2, ie. upstream over downstream area
+    pub fn area_ratio(&self) -> f64 {
+        return (self.upstream_area / self.downstream_area).value;
+    }
+
+    /// form loss K in forward flow, 
+    /// referenced to the upstream velocity
+    pub fn form_loss_k(&self) -> f64 {
+        return sudden_expansion_k(self.area_ratio());
+    }
+
+    /// form loss K in reverse flow, ie. a contraction,
+    /// referenced to the upstream velocity
+    pub fn reverse_form_loss_k(&self) -> f64 {
+        return sudden_contraction_k(self.area_ratio());
+    }
+}
+
+impl FluidComponent for SuddenExpansion {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        return area_change_mass_flowrate(pressure_loss,
+                                         self.upstream_area,
+                                         self.form_loss_k(),
+                                         self.reverse_form_loss_k(),
+                                         self.fluid_viscosity,
+                                         self.fluid_density);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return area_change_pressure_loss(mass_flowrate,
+                                         self.upstream_area,
+                                         self.form_loss_k(),
+                                         self.reverse_form_loss_k(),
+                                         self.fluid_viscosity,
+                                         self.fluid_density);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.upstream_area;
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.upstream_area;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return circular_diameter(self.upstream_area);
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return circular_diameter(self.upstream_area);
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    /// nominal length, see the struct documentation
+    fn get_component_length(&mut self) -> Length {
+        return circular_diameter(self.upstream_area);
+    }
+
+    /// nominal length, see the struct documentation
+    fn get_component_length_immutable(&self) -> Length {
+        return circular_diameter(self.upstream_area);
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// the fitting is passive, so this panics
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("SuddenExpansion has no internal pressure source");
+    }
+}
+
+/// A sudden contraction from a larger upstream area to 
+/// a smaller downstream area
+///
+/// the form loss is K = (1/Cc - 1)^2 (see sudden_contraction_k), 
+/// referenced to the downstream velocity, and is fed into the custom 
+/// fldk functions with zero friction factor. 
+///
+/// In reverse flow, the fitting is a sudden expansion,
+/// so sudden_expansion_k is used instead.
+///
+/// As with SuddenExpansion, only the irreversible loss is included,
+/// the fitting is horizontal, and the hydraulic diameter and 
+/// nominal length are those of a circle with the downstream area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuddenContraction {
+    /// the larger, upstream cross sectional area
+    pub upstream_area: Area,
+    /// the smaller, downstream cross sectional area
+    pub downstream_area: Area,
+    /// fluid density
+    pub fluid_density: MassDensity,
+    /// fluid viscosity
+    pub fluid_viscosity: DynamicViscosity,
+    /// current mass flowrate through the fitting
+    pub mass_flowrate: MassRate,
+}
+
+impl SuddenContraction {
+
+    /// constructor, the fitting starts with zero mass flowrate
+    ///
+    /// panics if the downstream area is larger than the upstream 
+    /// area or if the inputs are nonphysical
+    pub fn new(upstream_area: Area,
+               downstream_area: Area,
+               fluid_density: MassDensity,
+               fluid_viscosity: DynamicViscosity) -> Self {
+
+        check_area_change_inputs(upstream_area, downstream_area,
+                                 fluid_density, fluid_viscosity);
+
+        if downstream_area > upstream_area {
+            panic!("downstream area > upstream area, \
+                   use SuddenExpansion instead");
+        }
+
+        return Self {
+            upstream_area,
+            downstream_area,
+            fluid_density,
+            fluid_viscosity,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+
+    /// returns A2/A1, ie. downstream over upstream area
+    pub fn area_ratio(&self) -> f64 {
+        return (self.downstream_area / self.upstream_area).value;
+    }
+
+    /// form loss K in forward flow, 
+    /// referenced to the downstream velocity
+    pub fn form_loss_k(&self) -> f64 {
+        return sudden_contraction_k(self.area_ratio());
+    }
+
+    /// form loss K in reverse flow, ie. an expansion,
+    /// referenced to the downstream velocity
+    pub fn reverse_form_loss_k(&self) -> f64 {
+        return sudden_expansion_k(self.area_ratio());
+    }
+}
+
+impl FluidComponent for SuddenContraction {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        return area_change_mass_flowrate(pressure_loss,
+                                         self.downstream_area,
+                                         self.form_loss_k(),
+                                         self.reverse_form_loss_k(),
+                                         self.fluid_viscosity,
+                                         self.fluid_density);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return area_change_pressure_loss(mass_flowrate,
+                                         self.downstream_area,
+                                         self.form_loss_k(),
+                                         self.reverse_form_loss_k(),
+                                         self.fluid_viscosity,
+                                         self.fluid_density);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.downstream_area;
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.downstream_area;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return circular_diameter(self.downstream_area);
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return circular_diameter(self.downstream_area);
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    /// nominal length, see the struct documentation
+    fn get_component_length(&mut self) -> Length {
+        return circular_diameter(self.downstream_area);
+    }
+
+    /// nominal length, see the struct documentation
+    fn get_component_length_immutable(&self) -> Length {
+        return circular_diameter(self.downstream_area);
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// the fitting is passive, so this panics
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("SuddenContraction has no internal pressure source");
+    }
+}
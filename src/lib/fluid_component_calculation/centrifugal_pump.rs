@@ -0,0 +1,354 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a centrifugal pump described by a head curve and affinity laws
+
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+
+use crate::fluid_component_calculation::find_mass_flowrate_root;
+
+/// A centrifugal pump with a head curve H(m), 
+/// ie. the pressure rise across the pump depends on the 
+/// mass flowrate through it
+///
+/// This is unlike the CTAHPump in the factory, where the internal 
+/// pressure source is a fixed value set by the user.
+/// Here, the internal pressure source is evaluated from the 
+/// current mass flowrate using the head curve.
+///
+/// The pump itself is assumed lossless (the head curve already
+/// accounts for the losses within the pump), so the 
+/// pressure loss is zero and:
+///
+/// pressure_change = H(m) + hydrostatic_pressure_change
+///
+/// The pump head only appears in the pressure change and 
+/// internal pressure source methods. Since the pressure loss is 
+/// zero at every mass flowrate, it cannot be inverted for mass 
+/// flowrate, so get_mass_flowrate_from_pressure_loss_immutable and 
+/// set_pressure_loss panic, use the pressure change methods instead
+///
+/// the head curve is a reference to a user specified function
+/// (or closure) of mass flowrate, it should decrease with flowrate 
+/// so that the operating point is unique
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::angle::degree;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::pressure::pascal;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// // H = 5000 Pa - 1000 Pa/(kg/s) * m
+/// let head_curve = |mass_flowrate: MassRate| -> Pressure {
+///     return Pressure::new::<pascal>(
+///         5000.0 - 1000.0 * mass_flowrate.get::<kilogram_per_second>());
+/// };
+///
+/// let pump = CentrifugalPump::new(
+///     &head_curve,
+///     Length::new::<meter>(2.79e-2),
+///     Area::new::<square_meter>(6.11e-4),
+///     Length::new::<meter>(0.36),
+///     Angle::new::<degree>(0.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// let pressure_change = pump.get_pressure_change_immutable(
+///     MassRate::new::<kilogram_per_second>(2.0));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(pressure_change.get::<pascal>(), 3000.0,
+///                             max_relative = 1e-9);
+///
+/// let mass_flowrate = pump.get_mass_flowrate_from_pressure_change_immutable(
+///     Pressure::new::<pascal>(3000.0));
+///
+/// approx::assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(), 2.0,
+///                             max_relative = 1e-6);
+/// ```
+pub struct CentrifugalPump<'pump_lifetime> {
+    /// pump head (as a pressure rise) as a function of mass flowrate
+    pub pump_head_curve: &'pump_lifetime dyn Fn(MassRate) -> Pressure,
+    /// current mass flowrate through the pump
+    pub mass_flowrate: MassRate,
+    /// hydraulic diameter of the pump inlet/outlet
+    pub hydraulic_diameter: Length,
+    /// cross sectional area of the pump inlet/outlet
+    pub xs_area: Area,
+    /// pump length (inlet to outlet)
+    pub component_length: Length,
+    /// incline angle of the line from pump inlet to outlet
+    pub incline_angle: Angle,
+    /// fluid density
+    pub fluid_density: MassDensity,
+    /// fluid viscosity
+    pub fluid_viscosity: DynamicViscosity,
+}
+
+impl<'pump_lifetime> CentrifugalPump<'pump_lifetime> {
+
+    /// constructor, the pump starts with zero mass flowrate
+    ///
+    /// panics if the geometry or fluid properties are nonphysical
+    pub fn new(pump_head_curve: &'pump_lifetime dyn Fn(MassRate) -> Pressure,
+               hydraulic_diameter: Length,
+               xs_area: Area,
+               component_length: Length,
+               incline_angle: Angle,
+               fluid_density: MassDensity,
+               fluid_viscosity: DynamicViscosity) -> Self {
+
+        if hydraulic_diameter.value <= 0.0 {
+            panic!("hydraulic Diameter <= 0.0, nonphysical");
+        }
+
+        if xs_area.value <= 0.0 {
+            panic!("cross sectional area <= 0.0, nonphysical");
+        }
+
+        if component_length.value < 0.0 {
+            panic!("component length < 0.0, nonphysical");
+        }
+
+        if fluid_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        return Self {
+            pump_head_curve,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+            hydraulic_diameter,
+            xs_area,
+            component_length,
+            incline_angle,
+            fluid_density,
+            fluid_viscosity,
+        };
+    }
+
+    /// returns the pump head (as a pressure rise) at 
+    /// a given mass flowrate
+    pub fn get_pump_head(&self, mass_flowrate: MassRate) -> Pressure {
+        return (self.pump_head_curve)(mass_flowrate);
+    }
+
+    /// finds the mass flowrate where the pump head equals
+    /// the required pump head
+    ///
+    /// i search between -10 and 10 kg/s first,
+    /// and then -10000 and 10000 kg/s
+    ///
+    /// panics if no such mass flowrate is found
+    fn get_mass_flowrate_from_pump_head(
+        &self, required_pump_head: Pressure) -> MassRate {
+
+        let pump_head_root = |mass_flow_kg_per_s: f64| -> f64 {
+            let mass_rate = 
+                MassRate::new::<kilogram_per_second>(mass_flow_kg_per_s);
+
+            return required_pump_head.get::<pascal>() - 
+                self.get_pump_head(mass_rate).get::<pascal>();
+        };
+
+        return find_mass_flowrate_root(&pump_head_root).
+            unwrap_or_else(|| panic!("pump head curve does not reach \
+                                      required head \n
+                                      within -10000 and 10000 kg/s"));
+    }
+}
+
+impl<'pump_lifetime> FluidComponent for CentrifugalPump<'pump_lifetime> {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    /// the pump head is a function of mass flowrate, so the 
+    /// pressure change is found directly from the head curve
+    /// rather than from the current internal pressure source
+    fn get_pressure_change_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        return self.get_pump_head(mass_flowrate) + 
+            self.get_hydrostatic_pressure_change_immutable();
+    }
+
+    /// inverts the pump head curve,
+    /// pressure_change = H(m) + hydrostatic_pressure_change
+    fn get_mass_flowrate_from_pressure_change_immutable(
+        &self, pressure_change: Pressure) -> MassRate {
+
+        let required_pump_head = pressure_change - 
+            self.get_hydrostatic_pressure_change_immutable();
+
+        return self.get_mass_flowrate_from_pump_head(required_pump_head);
+    }
+
+    /// the pump is lossless, so the pressure loss is zero at 
+    /// every mass flowrate and cannot be inverted, this panics
+    ///
+    /// use get_mass_flowrate_from_pressure_change_immutable instead
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, _pressure_loss: Pressure) -> MassRate {
+        panic!("CentrifugalPump is lossless, mass flowrate cannot be \n
+               found from pressure loss, use pressure change instead");
+    }
+
+    /// the pump is lossless, pressure loss is always zero
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// the pump is lossless, so this panics,
+    /// see get_mass_flowrate_from_pressure_loss_immutable
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    /// the pump is lossless, pressure loss is always zero
+    fn get_pressure_loss_immutable(
+        &self, _mass_flowrate: MassRate) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// sets the mass flowrate from the pressure change
+    /// using the head curve
+    fn set_pressure_change(&mut self, pressure_change: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_change_immutable(
+                pressure_change);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.xs_area;
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.xs_area;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.incline_angle;
+    }
+
+    /// the internal pressure source is the pump head
+    /// at the current mass flowrate
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.get_pump_head(self.mass_flowrate);
+    }
+
+    /// the internal pressure source is the pump head
+    /// at the current mass flowrate
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.get_pump_head(self.mass_flowrate);
+    }
+
+    /// the pump head is set by the head curve, 
+    /// so this panics, change pump_head_curve instead
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("CentrifugalPump internal pressure source is set by \n
+               the pump head curve, change pump_head_curve instead");
+    }
+}
@@ -0,0 +1,227 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a check valve wrapper which blocks reverse flow
+
+use uom::si::f64::*;
+use crate::fluid_component_calculation::FluidComponent;
+
+/// A check valve which wraps around a base fluid component
+///
+/// In forward flow (positive mass flowrate), the pressure loss is 
+/// just that of the base component.
+/// In reverse flow (negative mass flowrate), the pressure loss 
+/// of the base component is multiplied by the 
+/// reverse_flow_resistance_multiplier, so that hardly any 
+/// fluid gets through.
+///
+/// This replaces the trick of returning a huge K in reverse flow 
+/// (eg. Flowmeter20WithHighKCheckValve in the factory)
+///
+/// Since the base component pressure loss goes to zero as 
+/// Re goes to zero in both directions, the check valve pressure
+/// loss is continuous at zero flow
+///
+/// Everything else (geometry, fluid properties, hydrostatic
+/// pressure and internal pressure source) is taken from the base 
+/// component
+pub struct CheckValve<T: FluidComponent> {
+    /// the component which behaves normally in forward flow
+    pub base_component: T,
+    /// multiplies the base component pressure loss in reverse flow,
+    /// this should be a large number (eg. 1e6)
+    pub reverse_flow_resistance_multiplier: f64,
+}
+
+impl<T: FluidComponent> CheckValve<T> {
+
+    /// constructor, 
+    /// panics if reverse_flow_resistance_multiplier <= 0
+    pub fn new(base_component: T, 
+               reverse_flow_resistance_multiplier: f64) -> Self {
+
+        if reverse_flow_resistance_multiplier <= 0.0 {
+            panic!("reverse_flow_resistance_multiplier <= 0.0, \n
+                   nonphysical");
+        }
+
+        return Self { 
+            base_component, 
+            reverse_flow_resistance_multiplier 
+        };
+    }
+
+    /// scales the base component pressure loss in reverse flow
+    fn check_valve_pressure_loss(&self, 
+                                 base_pressure_loss: Pressure) -> Pressure {
+
+        if base_pressure_loss.value < 0.0 {
+            return base_pressure_loss * self.reverse_flow_resistance_multiplier;
+        }
+
+        return base_pressure_loss;
+    }
+
+    /// converts the check valve pressure loss into the base 
+    /// component pressure loss, ie the reverse of 
+    /// check_valve_pressure_loss
+    fn base_pressure_loss(&self, 
+                          check_valve_pressure_loss: Pressure) -> Pressure {
+
+        if check_valve_pressure_loss.value < 0.0 {
+            return check_valve_pressure_loss / 
+                self.reverse_flow_resistance_multiplier;
+        }
+
+        return check_valve_pressure_loss;
+    }
+}
+
+impl<T: FluidComponent> FluidComponent for CheckValve<T> {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        // the base component stores the scaled down pressure loss
+        // (see set_pressure_loss), so its mass flowrate is correct
+        return self.base_component.get_mass_flowrate();
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.base_component.set_mass_flowrate(mass_flowrate);
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+
+        let base_pressure_loss = self.base_pressure_loss(pressure_loss);
+
+        return self.base_component.
+            get_mass_flowrate_from_pressure_loss_immutable(
+                base_pressure_loss);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        let base_pressure_loss = self.base_component.get_pressure_loss();
+        return self.check_valve_pressure_loss(base_pressure_loss);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        let base_pressure_loss = self.base_pressure_loss(pressure_loss);
+        self.base_component.set_pressure_loss(base_pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        let base_pressure_loss = self.base_component.
+            get_pressure_loss_immutable(mass_flowrate);
+
+        return self.check_valve_pressure_loss(base_pressure_loss);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.base_component.get_cross_sectional_area();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.base_component.get_cross_sectional_area_immutable();
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.base_component.get_hydraulic_diameter();
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.base_component.get_hydraulic_diameter_immutable();
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.base_component.get_fluid_viscosity();
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.base_component.get_fluid_viscosity_immutable();
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.base_component.get_fluid_density();
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.base_component.get_fluid_density_immutable();
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.base_component.get_component_length();
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.base_component.get_component_length_immutable();
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.base_component.get_incline_angle();
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.base_component.get_incline_angle_immutable();
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.base_component.get_internal_pressure_source();
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.base_component.get_internal_pressure_source_immutable();
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.base_component.set_internal_pressure_source(internal_pressure);
+    }
+}
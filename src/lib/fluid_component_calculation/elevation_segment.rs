@@ -0,0 +1,339 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are segments with only hydrostatic pressure change, ie. no friction
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use uom::si::acceleration::meter_per_second_squared;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_calculation::standard_pipe_calc::{
+    FluidPipeCalcPressureChange, FluidPipeCalcPressureLoss};
+
+/// A vertical or inclined section which only contributes 
+/// hydrostatic pressure, friction and form losses are neglected
+///
+/// pressure_change = - rho g L sin(theta)
+///
+/// the hydrostatic pressure change is calculated using
+/// FluidPipeCalcPressureChange::get_hydrostatic_pressure_change
+///
+/// since the pressure loss is zero at any flowrate, the mass 
+/// flowrate cannot be found from the pressure loss, 
+/// i just return zero mass flowrate in that case
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::angle::degree;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::pressure::pascal;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let riser = ElevationSegment::new(
+///     Length::new::<meter>(2.0),
+///     Angle::new::<degree>(30.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     Length::new::<meter>(2.79e-2));
+///
+/// // 1 m of elevation, the flowrate doesn't matter
+/// let pressure_change = riser.get_pressure_change_immutable(
+///     MassRate::new::<kilogram_per_second>(0.5));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(pressure_change.get::<pascal>(), -9810.0,
+///                             max_relative = 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElevationSegment {
+    /// length of the segment from inlet to outlet
+    pub component_length: Length,
+    /// angle the segment makes with the horizontal plane
+    pub incline_angle: Angle,
+    /// fluid density used for the hydrostatic pressure
+    pub fluid_density: MassDensity,
+    /// fluid viscosity, only used for Reynolds number
+    /// calculations by other code
+    pub fluid_viscosity: DynamicViscosity,
+    /// hydraulic diameter, the segment is assumed circular
+    pub hydraulic_diameter: Length,
+    /// current mass flowrate through the segment
+    pub mass_flowrate: MassRate,
+}
+
+impl ElevationSegment {
+
+    /// constructor, the segment starts with zero mass flowrate
+    ///
+    /// panics if the geometry or fluid properties are nonphysical
+    pub fn new(component_length: Length,
+               incline_angle: Angle,
+               fluid_density: MassDensity,
+               fluid_viscosity: DynamicViscosity,
+               hydraulic_diameter: Length) -> Self {
+
+        if component_length.value < 0.0 {
+            panic!("component length < 0.0, nonphysical");
+        }
+
+        if fluid_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        if hydraulic_diameter.value <= 0.0 {
+            panic!("hydraulic Diameter <= 0.0, nonphysical");
+        }
+
+        return Self {
+            component_length,
+            incline_angle,
+            fluid_density,
+            fluid_viscosity,
+            hydraulic_diameter,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+}
+
+impl FluidPipeCalcPressureLoss for ElevationSegment {
+
+    /// no form losses
+    fn get_pipe_form_loss_k(&mut self) -> f64 {
+        return 0.0;
+    }
+
+    /// no form losses
+    fn get_pipe_form_loss_k_immutable(&self) -> f64 {
+        return 0.0;
+    }
+
+    /// no friction, so roughness is zero
+    fn get_pipe_absolute_roughness(&mut self) -> Length {
+        return Length::new::<meter>(0.0);
+    }
+
+    /// no friction, so roughness is zero
+    fn get_pipe_absolute_roughness_immutable(&self) -> Length {
+        return Length::new::<meter>(0.0);
+    }
+}
+
+impl FluidPipeCalcPressureChange for ElevationSegment {}
+
+impl FluidComponent for ElevationSegment {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    /// the pressure loss is zero at any flowrate, so it cannot 
+    /// be inverted, this panics like the CentrifugalPump does
+    ///
+    /// put the segment in series with a component that has 
+    /// friction if you need to solve for mass flowrate
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, _pressure_loss: Pressure) -> MassRate {
+        panic!("ElevationSegment is frictionless, mass flowrate cannot \n
+               be found from pressure loss or pressure change");
+    }
+
+    /// friction is neglected, pressure loss is zero
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// friction is neglected, so this panics,
+    /// see get_mass_flowrate_from_pressure_loss_immutable
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    /// friction is neglected, pressure loss is zero
+    fn get_pressure_loss_immutable(
+        &self, _mass_flowrate: MassRate) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.get_cross_sectional_area_immutable();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.hydraulic_diameter * self.hydraulic_diameter *
+            std::f64::consts::PI / 4.0;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_hydrostatic_pressure_change(&mut self) -> Pressure {
+        return self.get_hydrostatic_pressure_change_immutable();
+    }
+
+    fn get_hydrostatic_pressure_change_immutable(&self) -> Pressure {
+        return <Self as FluidPipeCalcPressureChange>::
+            get_hydrostatic_pressure_change(
+                self.component_length,
+                self.incline_angle,
+                self.fluid_density);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// the segment is passive, so this panics,
+    /// use a pipe or pump if you need an internal pressure source
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("ElevationSegment has no internal pressure source");
+    }
+}
+
+/// calculates the buoyancy driving pressure around a closed 
+/// natural circulation loop (eg. the DRACS loop)
+///
+/// Delta p = (rho_cold - rho_hot) g H
+///
+/// where H is the height between the thermal centres of the 
+/// heat source and heat sink, ie. the height of the hot and 
+/// cold legs. g is taken as 9.81 m/s^2 as in 
+/// get_hydrostatic_pressure_change.
+///
+/// At steady state, this driving pressure is balanced by the 
+/// pressure losses around the loop
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::pressure::pascal;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let driving_pressure = natural_circulation_driving_pressure(
+///     MassDensity::new::<kilogram_per_cubic_meter>(990.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     Length::new::<meter>(2.0));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(driving_pressure.get::<pascal>(), 
+///     10.0 * 9.81 * 2.0, max_relative = 1e-12);
+/// ```
+pub fn natural_circulation_driving_pressure(hot_density: MassDensity,
+                                            cold_density: MassDensity,
+                                            height: Length) -> Pressure {
+
+    if hot_density.value <= 0.0 || cold_density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    let g: Acceleration = 
+        Acceleration::new::<meter_per_second_squared>(9.81);
+
+    return (cold_density - hot_density) * g * height;
+}
@@ -1,6 +1,7 @@
 
 use uom::si::f64::*;
 use uom::si::acceleration::meter_per_second_squared;
+use crate::fluid_component_calculation::pressure_types::{PressureLoss, PressureChange};
 /// This is a generic fluid component trait,
 /// which specifies that fluid components in general
 /// should have the following properties accessed
@@ -17,7 +18,17 @@ pub trait FluidComponent {
     /// gets the mass flowrate of component given a 
     /// fixed pressure change
     /// does so by immutably borrowing the object
-    /// 
+    ///
+    /// the default implementation converts the pressure change
+    /// into a pressure loss,
+    ///
+    /// pressure_loss = -pressure_change + hydrostatic_pressure_increase
+    /// + pressure_source
+    ///
+    /// and calls get_mass_flowrate_from_pressure_loss_immutable,
+    /// so you normally only need to implement the loss based 
+    /// methods. For a horizontal component without an internal
+    /// pressure source, pressure change is just -pressure_loss
     fn get_mass_flowrate_from_pressure_change_immutable(
         &self, pressure_change: Pressure) -> MassRate {
 
@@ -121,9 +132,33 @@ pub trait FluidComponent {
             pressure_source;
     }
 
-    /// gets the pressure loss of component given a 
+    /// gets pressure change for a pipe given
+    /// the set parameters, same as get_pressure_change
+    /// but returned as a PressureChange so it can't be 
+    /// mistaken for a pressure loss
+    fn get_pressure_change_typed(&mut self) -> PressureChange {
+
+        let pressure_loss = PressureLoss(self.get_pressure_loss());
+
+        let hydrostatic_pressure_increase = 
+            self.get_hydrostatic_pressure_change();
+
+        let pressure_source = self.get_internal_pressure_source();
+
+        return PressureChange::from_loss_and_sources(
+            pressure_loss, 
+            hydrostatic_pressure_increase, 
+            pressure_source);
+    }
+
+    /// gets the pressure change of component given a 
     /// fixed mass flowrate
     /// does so by immutably borrowing the object
+    ///
+    /// the default implementation builds the pressure change from
+    /// get_pressure_loss_immutable, the hydrostatic pressure 
+    /// and internal pressure source, and is the inverse of
+    /// get_mass_flowrate_from_pressure_change_immutable
     fn get_pressure_change_immutable(
         &self, mass_flowrate: MassRate) -> Pressure{
 
@@ -268,3 +303,17 @@ pub trait FluidComponent {
         internal_pressure: Pressure);
 
 }
+
+/// A fluid component whose fluid properties (density, viscosity)
+/// depend on a fluid temperature stored within the component
+///
+/// setting the fluid temperature changes the density and viscosity
+/// used in the FluidComponent methods
+pub trait TemperatureDependentFluidComponent : FluidComponent {
+
+    /// gets the fluid temperature used for fluid properties
+    fn get_fluid_temp(&self) -> ThermodynamicTemperature;
+
+    /// sets the fluid temperature used for fluid properties
+    fn set_fluid_temp(&mut self, fluid_temp: ThermodynamicTemperature);
+}
@@ -0,0 +1,289 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a resistor with a user supplied pressure loss function
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::angle::degree;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_calculation::custom_component_calc;
+
+/// A minimal fluid component defined only by its geometry and
+/// a form loss K(Re), for prototyping components quickly
+///
+/// The pressure loss is calculated with the custom fldk functions
+/// with zero friction factor, so 
+///
+/// Be_D = 0.5 Re^2 K(Re)
+///
+/// Unlike the dowtherm A custom components, there are no fluid 
+/// property correlations, the density and viscosity are fixed at
+/// construction. As with the other custom components, K(Re) 
+/// should return a negative value for Re < 0 (reverse flow).
+///
+/// The resistor is taken as horizontal with no internal 
+/// pressure source.
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let constant_k = |reynolds_number: f64| -> f64 {
+///     if reynolds_number < 0.0 {
+///         return -5.0;
+///     }
+///     return 5.0;
+/// };
+///
+/// let resistor = GenericResistor::new(
+///     Length::new::<meter>(0.1),
+///     Length::new::<meter>(1.0),
+///     Area::new::<square_meter>(0.25 * std::f64::consts::PI * 0.01),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     &constant_k);
+///
+/// let mass_flowrate = MassRate::new::<kilogram_per_second>(1.0);
+/// let pressure_loss = resistor.get_pressure_loss_immutable(mass_flowrate);
+///
+/// let mass_flowrate_test = resistor.
+///     get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(mass_flowrate_test.value, 1.0,
+///     max_relative = 1e-6);
+/// ```
+#[derive(Clone, Copy)]
+pub struct GenericResistor<'a> {
+    /// hydraulic diameter
+    pub hydraulic_diameter: Length,
+    /// component length
+    pub component_length: Length,
+    /// cross sectional area
+    pub cross_sectional_area: Area,
+    /// fluid density
+    pub fluid_density: MassDensity,
+    /// fluid viscosity
+    pub fluid_viscosity: DynamicViscosity,
+    /// form loss K as a function of Re
+    pub custom_k: &'a dyn Fn(f64) -> f64,
+    /// current mass flowrate through the resistor
+    pub mass_flowrate: MassRate,
+}
+
+impl<'a> GenericResistor<'a> {
+
+    /// constructor, the resistor starts with zero mass flowrate
+    ///
+    /// panics if the geometry or fluid properties are nonphysical
+    pub fn new(hydraulic_diameter: Length,
+               component_length: Length,
+               cross_sectional_area: Area,
+               fluid_density: MassDensity,
+               fluid_viscosity: DynamicViscosity,
+               custom_k: &'a dyn Fn(f64) -> f64) -> Self {
+
+        if hydraulic_diameter.value <= 0.0 {
+            panic!("hydraulic Diameter <= 0.0, nonphysical");
+        }
+
+        // the fldk functions need L/D > 0
+        if component_length.value <= 0.0 {
+            panic!("component length <= 0.0, nonphysical");
+        }
+
+        if cross_sectional_area.value <= 0.0 {
+            panic!("cross sectional Area <= 0.0, nonphysical");
+        }
+
+        if fluid_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        return Self {
+            hydraulic_diameter,
+            component_length,
+            cross_sectional_area,
+            fluid_density,
+            fluid_viscosity,
+            custom_k,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+}
+
+/// zero friction factor, the resistor is a pure K(Re)
+fn zero_darcy(_reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+    return 0.0;
+}
+
+impl<'a> FluidComponent for GenericResistor<'a> {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        return custom_component_calc::CalcPressureLoss::to_mass_rate(
+            pressure_loss,
+            self.cross_sectional_area,
+            self.hydraulic_diameter,
+            self.fluid_viscosity,
+            self.fluid_density,
+            self.component_length,
+            Length::new::<meter>(0.0),
+            &zero_darcy,
+            self.custom_k);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return custom_component_calc::CalcPressureLoss::from_mass_rate(
+            mass_flowrate,
+            self.cross_sectional_area,
+            self.hydraulic_diameter,
+            self.fluid_viscosity,
+            self.fluid_density,
+            self.component_length,
+            Length::new::<meter>(0.0),
+            &zero_darcy,
+            self.custom_k);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.cross_sectional_area;
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.cross_sectional_area;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.fluid_density;
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return Angle::new::<degree>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// no internal pressure source
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    /// the resistor is passive, so this panics
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("GenericResistor has no internal pressure source");
+    }
+}
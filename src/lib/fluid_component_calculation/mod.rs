@@ -76,6 +76,93 @@ pub mod error_traits;
 pub mod fluid_component_trait;
 pub use fluid_component_trait::*;
 
+/// PressureLoss and PressureChange structs, which wrap 
+/// pressures so that losses and changes are not mixed up
+pub mod pressure_types;
+pub use pressure_types::*;
+
+/// a check valve which wraps around a base fluid component
+/// and multiplies its resistance in reverse flow
+pub mod check_valve;
+pub use check_valve::*;
+
+/// an orifice plate whose form loss K is calculated from
+/// the beta ratio and discharge coefficient
+pub mod orifice_plate;
+pub use orifice_plate::*;
+
+/// a centrifugal pump whose internal pressure source
+/// follows a head curve, ie. a function of mass flowrate
+pub mod centrifugal_pump;
+pub use centrifugal_pump::*;
+
+/// a frictionless vertical or inclined section
+/// which only contributes hydrostatic pressure
+pub mod elevation_segment;
+pub use elevation_segment::*;
+
+/// sudden expansion and sudden contraction fittings whose 
+/// form loss K is calculated from the area ratio
+pub mod area_change_fitting;
+pub use area_change_fitting::*;
+
+/// homogeneous equilibrium model two phase friction multipliers
+/// and a two phase pipe, for slightly flashing fluids
+pub mod two_phase;
+
+/// momentum (acceleration) pressure drop for fluids whose density
+/// changes along a pipe, and a heated pipe which includes it
+pub mod momentum;
+
+/// a wrapper which multiplies the pressure loss of an existing 
+/// component by a constant factor, for calibration
+pub mod scaled_component;
+pub use scaled_component::*;
+
+/// pipe bends (elbows) with a Reynolds number dependent 
+/// form loss from the Crane equivalent length method
+pub mod pipe_bend;
+pub use pipe_bend::*;
+
+/// a minimal component defined only by geometry and a K(Re) 
+/// closure, for prototyping
+pub mod generic_resistor;
+pub use generic_resistor::*;
+
+/// finds the mass flowrate (kg/s) at which root_function is zero,
+/// shared by components and loops which can't be inverted directly
+///
+/// i search between -10 and 10 kg/s first,
+/// and then -10000 and 10000 kg/s, 
+/// returns None if neither bracket contains a root,
+/// callers should panic with a message that suits them
+pub(crate) fn find_mass_flowrate_root(
+    root_function: &dyn Fn(f64) -> f64) -> Option<uom::si::f64::MassRate> {
+
+    use roots::find_root_brent;
+    use roots::SimpleConvergency;
+    use uom::si::mass_rate::kilogram_per_second;
+
+    for mass_flowrate_bound in [10.0_f64, 10_000.0_f64] {
+
+        let mut convergency = 
+            SimpleConvergency { eps:1e-15f64, max_iter:100 };
+
+        let mass_flowrate_result = find_root_brent(
+            -mass_flowrate_bound,
+            mass_flowrate_bound,
+            root_function,
+            &mut convergency);
+
+        if let Ok(mass_flow_kg_per_s) = mass_flowrate_result {
+            return Some(uom::si::f64::MassRate::new::<kilogram_per_second>(
+                mass_flow_kg_per_s));
+        }
+    }
+
+    return None;
+}
+
 
 /// primitive tests and examples to use the FluidComponent 
 /// Traits
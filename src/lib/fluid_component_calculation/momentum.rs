@@ -0,0 +1,398 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is the momentum (acceleration) pressure change for heated pipes
+
+use uom::si::f64::*;
+use uom::si::length::{meter, millimeter};
+use uom::si::angle::degree;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_calculation::standard_pipe_calc::{
+    FluidPipeCalcPressureChange, FluidPipeCalcPressureLoss};
+use crate::fluid_component_calculation::find_mass_flowrate_root;
+
+/// momentum (acceleration) pressure drop of a fluid whose density 
+/// changes between inlet and outlet, eg. in a heated channel
+///
+/// dp = m^2 (1/rho_out - 1/rho_in) / A^2
+///
+/// this is the pressure used up to accelerate the fluid, so it 
+/// adds to the frictional pressure loss. It is positive when the 
+/// fluid expands (heating) and negative when it contracts (cooling)
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::area::square_meter;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_component_calculation::momentum::*;
+///
+/// let momentum_pressure_drop = momentum_pressure_change(
+///     MassRate::new::<kilogram_per_second>(1.0),
+///     Area::new::<square_meter>(1.0e-3),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(500.0));
+///
+/// // G^2 = 1e6 kg^2/(m^4 s^2), (1/500 - 1/1000) = 1e-3 m^3/kg
+/// extern crate approx;
+/// approx::assert_relative_eq!(momentum_pressure_drop.value, 1000.0,
+///     max_relative = 1e-12);
+/// ```
+pub fn momentum_pressure_change(mass_rate: MassRate,
+                                area: Area,
+                                density_in: MassDensity,
+                                density_out: MassDensity) -> Pressure {
+
+    if area.value <= 0.0 {
+        panic!("cross sectional Area <= 0.0, nonphysical");
+    }
+
+    if density_in.value <= 0.0 || density_out.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    return mass_rate * mass_rate 
+        * (1.0/density_out - 1.0/density_in) 
+        / (area * area);
+}
+
+/// A heated (or cooled) pipe, where the fluid density 
+/// changes from inlet to outlet
+///
+/// The pressure loss is the frictional loss, evaluated at the 
+/// mean density, plus the momentum pressure drop 
+/// (see momentum_pressure_change). 
+///
+/// The momentum term is added on to the frictional loss
+/// rather than folded into the form loss K, since it is negative 
+/// for a cooled pipe and may be larger than K. Because of this, 
+/// the mass flowrate is found from the pressure loss using 
+/// the brent root finder rather than the standard pipe 
+/// calculations. The inlet and outlet densities are where the 
+/// fluid enters and leaves the pipe, so in reverse flow the 
+/// momentum term flips sign along with the frictional loss.
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+/// use fluid_mechanics_rust::fluid_component_calculation::momentum::*;
+///
+/// let heated_pipe = HeatedPipe::new(
+///     "heater",
+///     2.79e-2, 
+///     1.0, 
+///     0.001, 
+///     0.0, 
+///     0.0,
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(900.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+///
+/// let pressure_loss = 
+///     heated_pipe.get_pressure_loss_immutable(mass_flowrate);
+///
+/// let mass_flowrate_test = heated_pipe.
+///     get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(mass_flowrate_test.value, 0.5,
+///     max_relative = 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatedPipe {
+    _name: &'static str,
+    /// hydraulic diameter of the pipe
+    pub hydraulic_diameter: Length,
+    /// pipe length
+    pub pipe_length: Length,
+    /// absolute roughness of the pipe
+    pub absolute_roughness: Length,
+    /// incline angle of the pipe
+    pub incline_angle: Angle,
+    /// form loss K of the pipe, not including the momentum term
+    pub form_loss_k: f64,
+    /// fluid density where the fluid enters the pipe
+    pub inlet_density: MassDensity,
+    /// fluid density where the fluid leaves the pipe
+    pub outlet_density: MassDensity,
+    /// fluid viscosity, taken as constant along the pipe
+    pub fluid_viscosity: DynamicViscosity,
+    /// internal pressure source, eg. a pump
+    pub internal_pressure_source: Pressure,
+    /// current mass flowrate through the pipe
+    pub mass_flowrate: MassRate,
+}
+
+impl HeatedPipe {
+
+    /// constructor for a heated pipe, which starts 
+    /// with zero mass flowrate
+    pub fn new(name: &'static str,
+               hydraulic_diameter_meters: f64,
+               pipe_length_meters: f64,
+               absolute_roughness_millimeters: f64,
+               incline_angle_degrees: f64,
+               form_loss_k: f64,
+               inlet_density: MassDensity,
+               outlet_density: MassDensity,
+               fluid_viscosity: DynamicViscosity) -> Self {
+
+        if hydraulic_diameter_meters <= 0.0 {
+            panic!("hydraulic diameter <= 0.0, nonphysical");
+        }
+
+        if inlet_density.value <= 0.0 || outlet_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        if fluid_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        return Self {
+            _name: name,
+            hydraulic_diameter: Length::new::<meter>(
+                hydraulic_diameter_meters),
+            pipe_length: Length::new::<meter>(pipe_length_meters),
+            absolute_roughness: Length::new::<millimeter>(
+                absolute_roughness_millimeters),
+            incline_angle: Angle::new::<degree>(incline_angle_degrees),
+            form_loss_k,
+            inlet_density,
+            outlet_density,
+            fluid_viscosity,
+            internal_pressure_source: Pressure::new::<pascal>(0.0),
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+
+    /// returns the mean of the inlet and outlet density,
+    /// used for friction and hydrostatic pressure
+    pub fn mean_density(&self) -> MassDensity {
+        return 0.5 * (self.inlet_density + self.outlet_density);
+    }
+
+    /// returns the momentum pressure drop at the given mass 
+    /// flowrate, see momentum_pressure_change
+    ///
+    /// this is signed like the frictional loss, ie. for 
+    /// reverse flow the sign is flipped
+    pub fn momentum_pressure_loss(&self, mass_flowrate: MassRate) -> Pressure {
+
+        let momentum_pressure_drop = momentum_pressure_change(
+            mass_flowrate,
+            self.get_cross_sectional_area_immutable(),
+            self.inlet_density,
+            self.outlet_density);
+
+        if mass_flowrate.value < 0.0 {
+            return -momentum_pressure_drop;
+        }
+
+        return momentum_pressure_drop;
+    }
+}
+
+impl FluidPipeCalcPressureLoss for HeatedPipe {
+
+    fn get_pipe_form_loss_k(&mut self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_form_loss_k_immutable(&self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_absolute_roughness(&mut self) -> Length {
+        return self.absolute_roughness;
+    }
+
+    fn get_pipe_absolute_roughness_immutable(&self) -> Length {
+        return self.absolute_roughness;
+    }
+}
+
+impl FluidPipeCalcPressureChange for HeatedPipe {}
+
+impl FluidComponent for HeatedPipe {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    /// solves for the mass flowrate using the brent root finder,
+    /// since the momentum term is not part of the 
+    /// standard pipe calculations
+    ///
+    /// i search between -10 and 10 kg/s first,
+    /// and then -10000 and 10000 kg/s
+    ///
+    /// panics if no such mass flowrate is found
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+
+        let pressure_loss_root = |mass_flow_kg_per_s: f64| -> f64 {
+            let mass_rate = 
+                MassRate::new::<kilogram_per_second>(mass_flow_kg_per_s);
+
+            return pressure_loss.get::<pascal>() - 
+                self.get_pressure_loss_immutable(mass_rate).get::<pascal>();
+        };
+
+        return find_mass_flowrate_root(&pressure_loss_root).
+            unwrap_or_else(|| panic!("heated pipe pressure loss not \
+                                      reached \n
+                                      within -10000 and 10000 kg/s"));
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    /// frictional loss at mean density plus the momentum 
+    /// pressure drop
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        let frictional_pressure_loss = 
+            <Self as FluidPipeCalcPressureLoss>::pipe_calc_pressure_loss(
+                mass_flowrate,
+                self.get_cross_sectional_area_immutable(),
+                self.hydraulic_diameter,
+                self.fluid_viscosity,
+                self.mean_density(),
+                self.pipe_length,
+                self.absolute_roughness,
+                self.form_loss_k);
+
+        return frictional_pressure_loss 
+            + self.momentum_pressure_loss(mass_flowrate);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.get_cross_sectional_area_immutable();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.hydraulic_diameter * self.hydraulic_diameter 
+            * std::f64::consts::PI / 4.0;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.fluid_viscosity;
+    }
+
+    /// returns the mean density
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.mean_density();
+    }
+
+    /// returns the mean density
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.mean_density();
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.pipe_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.pipe_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.internal_pressure_source = internal_pressure;
+    }
+}
@@ -0,0 +1,254 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is an orifice plate, ie. a discharge coefficient based restriction
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use crate::fluid_component_calculation::custom_component_calc;
+
+/// An orifice plate used for flow measurement
+///
+/// Unlike a fixed K component, the form loss K here is worked out 
+/// from the beta ratio (orifice diameter/pipe diameter) and the 
+/// discharge coefficient Cd.
+///
+/// The textbook relation K = (1/Cd^2 - 1)/beta^4 is the head 
+/// loss across the vena contracta, and doesn't go to zero as 
+/// beta goes to 1. What we want for a loop calculation is the 
+/// permanent (unrecovered) pressure loss, so i use the 
+/// ISO 5167 style form, referenced to the pipe velocity:
+///
+/// K = ( sqrt(1 - beta^4 (1 - Cd^2)) / (Cd beta^2) - 1 )^2
+///
+/// which goes to zero as beta goes to 1 (no plate, no loss).
+///
+/// The K is then fed into the custom fldk functions with 
+/// zero friction factor, so the orifice plate behaves like a 
+/// pure form loss. Reverse flow gives the same loss in the 
+/// opposite direction.
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::inch;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::OrificePlate;
+///
+/// let orifice = OrificePlate::new(
+///     Length::new::<inch>(1.0),
+///     Length::new::<inch>(0.5),
+///     0.61);
+///
+/// let mass_rate = MassRate::new::<kilogram_per_second>(0.5);
+/// let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+/// let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+///
+/// let pressure_loss = orifice.pressure_loss_from_mass_rate(
+///     mass_rate, viscosity, density);
+///
+/// let mass_rate_test = orifice.mass_rate_from_pressure_loss(
+///     pressure_loss, viscosity, density);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(mass_rate.value, 
+///                             mass_rate_test.value,
+///                             max_relative=0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrificePlate {
+    /// diameter of the pipe the orifice plate sits in
+    pub pipe_diameter: Length,
+    /// diameter of the orifice hole
+    pub orifice_diameter: Length,
+    /// discharge coefficient Cd, between 0 and 1
+    pub discharge_coefficient: f64,
+}
+
+impl OrificePlate {
+
+    /// constructor, 
+    /// panics if the diameters are nonphysical, if the orifice
+    /// is not smaller than the pipe, or if Cd is not within (0,1]
+    pub fn new(pipe_diameter: Length,
+               orifice_diameter: Length,
+               discharge_coefficient: f64) -> Self {
+
+        if pipe_diameter.value <= 0.0 {
+            panic!("pipe_diameter <= 0.0, nonphysical");
+        }
+
+        if orifice_diameter.value <= 0.0 {
+            panic!("orifice_diameter <= 0.0, nonphysical");
+        }
+
+        // at beta = 1, K = 0 and there is no friction either, so 
+        // the mass flowrate can't be found from the pressure loss
+        if orifice_diameter >= pipe_diameter {
+            panic!("orifice_diameter >= pipe_diameter, \
+                   no restriction so flowrate cannot be found from \
+                   pressure loss");
+        }
+
+        if discharge_coefficient <= 0.0 || discharge_coefficient > 1.0 {
+            panic!("discharge_coefficient must be within (0,1]");
+        }
+
+        return Self {
+            pipe_diameter,
+            orifice_diameter,
+            discharge_coefficient,
+        };
+    }
+
+    /// returns beta = d_orifice/D_pipe
+    pub fn beta_ratio(&self) -> f64 {
+        let beta = self.orifice_diameter.get::<meter>()/
+            self.pipe_diameter.get::<meter>();
+
+        return beta;
+    }
+
+    /// returns the permanent form loss K, referenced to 
+    /// the velocity in the pipe
+    pub fn form_loss_k(&self) -> f64 {
+
+        let beta = self.beta_ratio();
+        let cd = self.discharge_coefficient;
+
+        let beta_4 = beta.powf(4.0);
+
+        let k_sqrt = (1.0 - beta_4 * (1.0 - cd.powf(2.0))).sqrt()/
+            (cd * beta.powf(2.0)) - 1.0;
+
+        return k_sqrt.powf(2.0);
+    }
+
+    /// returns the pipe cross sectional area
+    pub fn pipe_cross_sectional_area(&self) -> Area {
+        return self.pipe_diameter * self.pipe_diameter * 
+            std::f64::consts::PI / 4.0;
+    }
+
+    /// calculates pressure loss across the orifice plate
+    /// given a mass flowrate
+    pub fn pressure_loss_from_mass_rate(
+        &self,
+        mass_rate: MassRate,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity) -> Pressure {
+
+        let k = self.form_loss_k();
+        let custom_k = |reynolds_number: f64| -> f64 {
+            if reynolds_number < 0.0 {
+                return -k;
+            }
+            return k;
+        };
+
+        // zero friction factor, the orifice is a pure form loss
+        let custom_darcy = |_reynolds_number: f64, 
+        _roughness_ratio: f64| -> f64 {
+            return 0.0;
+        };
+
+        // the fldk functions need a positive length,
+        // but it doesn't matter since the friction factor is zero
+        let nominal_length = self.pipe_diameter;
+        let absolute_roughness = Length::new::<meter>(0.0);
+
+        return custom_component_calc::CalcPressureLoss::from_mass_rate(
+            mass_rate,
+            self.pipe_cross_sectional_area(),
+            self.pipe_diameter,
+            fluid_viscosity,
+            fluid_density,
+            nominal_length,
+            absolute_roughness,
+            &custom_darcy,
+            &custom_k);
+    }
+
+    /// calculates mass flowrate through the orifice plate
+    /// given a pressure loss
+    pub fn mass_rate_from_pressure_loss(
+        &self,
+        pressure_loss: Pressure,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity) -> MassRate {
+
+        let k = self.form_loss_k();
+        let custom_k = |reynolds_number: f64| -> f64 {
+            if reynolds_number < 0.0 {
+                return -k;
+            }
+            return k;
+        };
+
+        let custom_darcy = |_reynolds_number: f64, 
+        _roughness_ratio: f64| -> f64 {
+            return 0.0;
+        };
+
+        let nominal_length = self.pipe_diameter;
+        let absolute_roughness = Length::new::<meter>(0.0);
+
+        return custom_component_calc::CalcPressureLoss::to_mass_rate(
+            pressure_loss,
+            self.pipe_cross_sectional_area(),
+            self.pipe_diameter,
+            fluid_viscosity,
+            fluid_density,
+            nominal_length,
+            absolute_roughness,
+            &custom_darcy,
+            &custom_k);
+    }
+}
@@ -0,0 +1,298 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a pipe bend, ie. a pipe with a curvature dependent form loss
+
+use uom::si::f64::*;
+use uom::si::length::meter;
+use uom::si::angle::degree;
+use crate::churchill_friction_factor;
+use crate::fluid_component_calculation::custom_component_calc;
+
+/// equivalent length ratios (L_eq/D) of 90 degree bends
+/// against bend radius ratio (r/D), from the Crane TP-410 tables
+const BEND_RADIUS_RATIO_TABLE: [(f64, f64); 12] = [
+    (1.0, 20.0),
+    (1.5, 14.0),
+    (2.0, 12.0),
+    (3.0, 12.0),
+    (4.0, 14.0),
+    (6.0, 17.0),
+    (8.0, 24.0),
+    (10.0, 30.0),
+    (12.0, 34.0),
+    (14.0, 38.0),
+    (16.0, 42.0),
+    (20.0, 50.0),
+];
+
+/// A smooth pipe bend (elbow) of circular cross section
+///
+/// The form loss is Reynolds number dependent, using the Crane 
+/// equivalent length method:
+///
+/// K = f (L_eq/D)
+///
+/// where f is the Churchill darcy friction factor at the bend's 
+/// Reynolds number and roughness, and L_eq/D is the bend 
+/// coefficient, interpolated from the Crane table for 90 degree 
+/// bends at the radius ratio r/D (1 to 20), and scaled
+/// linearly with the bend angle
+///
+/// At high Re, f tends to the fully turbulent friction factor f_T
+/// and this gives the usual K = f_T (L_eq/D).
+///
+/// Just like the orifice plate, the fluid properties are passed 
+/// in when calculating the pressure loss. The bend is taken as
+/// horizontal, and reverse flow gives the same loss in the 
+/// opposite direction.
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::{inch, millimeter};
+/// use uom::si::angle::degree;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::PipeBend;
+///
+/// let bend = PipeBend::new(
+///     Length::new::<inch>(1.0),
+///     Angle::new::<degree>(90.0),
+///     1.5,
+///     Length::new::<millimeter>(0.045));
+///
+/// let mass_rate = MassRate::new::<kilogram_per_second>(0.5);
+/// let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+/// let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+///
+/// let pressure_loss = bend.pressure_loss_from_mass_rate(
+///     mass_rate, viscosity, density);
+///
+/// let mass_rate_test = bend.mass_rate_from_pressure_loss(
+///     pressure_loss, viscosity, density);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(mass_rate.value, 
+///                             mass_rate_test.value,
+///                             max_relative=0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipeBend {
+    /// internal diameter of the pipe
+    pub diameter: Length,
+    /// bend angle, 90 degrees for a standard elbow
+    pub bend_angle: Angle,
+    /// bend radius (to the pipe centreline) over pipe diameter, r/D
+    pub radius_ratio: f64,
+    /// absolute roughness of the pipe wall
+    pub absolute_roughness: Length,
+}
+
+impl PipeBend {
+
+    /// constructor, 
+    /// panics if the diameter or roughness are nonphysical, if 
+    /// the bend angle is not within (0,180] degrees or if the 
+    /// radius ratio is outside the Crane table range of 1 to 20
+    pub fn new(diameter: Length,
+               bend_angle: Angle,
+               radius_ratio: f64,
+               absolute_roughness: Length) -> Self {
+
+        if diameter.value <= 0.0 {
+            panic!("diameter <= 0.0, nonphysical");
+        }
+
+        if absolute_roughness.value < 0.0 {
+            panic!("absolute_roughness < 0.0, nonphysical");
+        }
+
+        let bend_angle_degrees = bend_angle.get::<degree>();
+
+        if bend_angle_degrees <= 0.0 || bend_angle_degrees > 180.0 {
+            panic!("bend_angle must be within (0,180] degrees");
+        }
+
+        if !(1.0..=20.0).contains(&radius_ratio) {
+            panic!("radius_ratio must be within [1,20]");
+        }
+
+        return Self {
+            diameter,
+            bend_angle,
+            radius_ratio,
+            absolute_roughness,
+        };
+    }
+
+    /// returns the bend coefficient L_eq/D, interpolated 
+    /// from the Crane table for 90 degree bends and scaled 
+    /// by bend_angle/90 degrees
+    pub fn equivalent_length_ratio(&self) -> f64 {
+
+        let mut length_ratio_90_degrees = BEND_RADIUS_RATIO_TABLE[0].1;
+
+        for pair in BEND_RADIUS_RATIO_TABLE.windows(2) {
+            let (lower_radius_ratio, lower_length_ratio) = pair[0];
+            let (upper_radius_ratio, upper_length_ratio) = pair[1];
+
+            if (lower_radius_ratio..=upper_radius_ratio).
+                contains(&self.radius_ratio) {
+
+                let interpolation_fraction = 
+                    (self.radius_ratio - lower_radius_ratio)/
+                    (upper_radius_ratio - lower_radius_ratio);
+
+                length_ratio_90_degrees = lower_length_ratio 
+                    + interpolation_fraction 
+                    * (upper_length_ratio - lower_length_ratio);
+                break;
+            }
+        }
+
+        return length_ratio_90_degrees * 
+            self.bend_angle.get::<degree>() / 90.0;
+    }
+
+    /// returns the roughness ratio (absolute roughness/diameter)
+    pub fn roughness_ratio(&self) -> f64 {
+        return self.absolute_roughness.get::<meter>()/
+            self.diameter.get::<meter>();
+    }
+
+    /// returns the form loss K = f (L_eq/D) at a given 
+    /// Reynolds number, referenced to the pipe velocity
+    ///
+    /// the magnitude of K is returned for reverse flow,
+    /// panics at Re = 0 since the laminar friction factor 
+    /// is infinite there
+    pub fn form_loss_k(&self, reynolds_number: f64) -> f64 {
+
+        let darcy = churchill_friction_factor::darcy(
+            reynolds_number.abs(), self.roughness_ratio());
+
+        return darcy * self.equivalent_length_ratio();
+    }
+
+    /// returns the pipe cross sectional area
+    pub fn pipe_cross_sectional_area(&self) -> Area {
+        return self.diameter * self.diameter * 
+            std::f64::consts::PI / 4.0;
+    }
+
+    /// returns the equivalent length L_eq, which is used as the
+    /// component length in the fldk functions
+    pub fn equivalent_length(&self) -> Length {
+        return self.diameter * self.equivalent_length_ratio();
+    }
+
+    /// calculates pressure loss across the bend
+    /// given a mass flowrate
+    pub fn pressure_loss_from_mass_rate(
+        &self,
+        mass_rate: MassRate,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity) -> Pressure {
+
+        // the bend coefficient goes in as L/D, so fldk 
+        // gives f (L_eq/D) with no extra form loss
+        let custom_k = |_reynolds_number: f64| -> f64 {
+            return 0.0;
+        };
+
+        return custom_component_calc::CalcPressureLoss::from_mass_rate(
+            mass_rate,
+            self.pipe_cross_sectional_area(),
+            self.diameter,
+            fluid_viscosity,
+            fluid_density,
+            self.equivalent_length(),
+            self.absolute_roughness,
+            &bend_darcy,
+            &custom_k);
+    }
+
+    /// calculates mass flowrate through the bend
+    /// given a pressure loss
+    pub fn mass_rate_from_pressure_loss(
+        &self,
+        pressure_loss: Pressure,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity) -> MassRate {
+
+        let custom_k = |_reynolds_number: f64| -> f64 {
+            return 0.0;
+        };
+
+        return custom_component_calc::CalcPressureLoss::to_mass_rate(
+            pressure_loss,
+            self.pipe_cross_sectional_area(),
+            self.diameter,
+            fluid_viscosity,
+            fluid_density,
+            self.equivalent_length(),
+            self.absolute_roughness,
+            &bend_darcy,
+            &custom_k);
+    }
+}
+
+/// churchill darcy friction factor, with the sign flipped 
+/// for reverse flow
+fn bend_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+
+    let darcy = churchill_friction_factor::darcy(
+        reynolds_number.abs(), roughness_ratio);
+
+    if reynolds_number < 0.0 {
+        return -darcy;
+    }
+    return darcy;
+}
@@ -0,0 +1,151 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are newtypes so that pressure loss and pressure change are not mixed up
+
+use uom::si::f64::*;
+
+/// A pressure loss, ie. the frictional and form losses 
+/// of a component, which is positive for forward flow
+///
+/// wrapping it in a struct means it can't be mixed up with 
+/// a pressure change, which has the opposite sign 
+/// (and includes hydrostatic pressure and internal pressure sources)
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let pressure_loss = PressureLoss(Pressure::new::<pascal>(1000.0));
+/// let hydrostatic_pressure = Pressure::new::<pascal>(-9810.0);
+/// let internal_pressure = Pressure::new::<pascal>(500.0);
+///
+/// let pressure_change = PressureChange::from_loss_and_sources(
+///     pressure_loss, hydrostatic_pressure, internal_pressure);
+///
+/// assert_eq!(pressure_change.0.get::<pascal>(), -1000.0 - 9810.0 + 500.0);
+///
+/// let pressure_loss_back = pressure_change.to_loss(
+///     hydrostatic_pressure, internal_pressure);
+///
+/// assert_eq!(pressure_loss_back, pressure_loss);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PressureLoss(pub Pressure);
+
+/// A pressure change across a component (outlet minus inlet),
+///
+/// pressure_change = -pressure_loss + hydrostatic_pressure + 
+/// internal_pressure_source
+///
+/// see PressureLoss 
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PressureChange(pub Pressure);
+
+impl PressureLoss {
+
+    /// obtains the pressure loss from the pressure change
+    ///
+    /// pressure_loss = -pressure_change + hydrostatic_pressure + 
+    /// internal_pressure_source
+    pub fn from_change_and_sources(
+        pressure_change: PressureChange,
+        hydrostatic_pressure: Pressure,
+        internal_pressure_source: Pressure) -> Self {
+
+        return Self(-pressure_change.0 + hydrostatic_pressure + 
+                    internal_pressure_source);
+    }
+
+    /// obtains the pressure change from this pressure loss
+    pub fn to_change(&self,
+                     hydrostatic_pressure: Pressure,
+                     internal_pressure_source: Pressure) -> PressureChange {
+
+        return PressureChange::from_loss_and_sources(
+            *self, hydrostatic_pressure, internal_pressure_source);
+    }
+}
+
+impl PressureChange {
+
+    /// obtains the pressure change from the pressure loss
+    ///
+    /// pressure_change = -pressure_loss + hydrostatic_pressure + 
+    /// internal_pressure_source
+    pub fn from_loss_and_sources(
+        pressure_loss: PressureLoss,
+        hydrostatic_pressure: Pressure,
+        internal_pressure_source: Pressure) -> Self {
+
+        return Self(-pressure_loss.0 + hydrostatic_pressure + 
+                    internal_pressure_source);
+    }
+
+    /// obtains the pressure loss from this pressure change
+    pub fn to_loss(&self,
+                   hydrostatic_pressure: Pressure,
+                   internal_pressure_source: Pressure) -> PressureLoss {
+
+        return PressureLoss::from_change_and_sources(
+            *self, hydrostatic_pressure, internal_pressure_source);
+    }
+}
+
+impl From<PressureLoss> for Pressure {
+    fn from(pressure_loss: PressureLoss) -> Self {
+        return pressure_loss.0;
+    }
+}
+
+impl From<PressureChange> for Pressure {
+    fn from(pressure_change: PressureChange) -> Self {
+        return pressure_change.0;
+    }
+}
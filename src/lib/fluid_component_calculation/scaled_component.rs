@@ -0,0 +1,220 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a wrapper which scales the pressure loss of another component
+
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use crate::fluid_component_calculation::FluidComponent;
+
+/// A wrapper which scales the pressure loss of an existing 
+/// fluid component by a constant factor, eg. for calibrating 
+/// a component against experimental data without rewriting it
+///
+/// pressure_loss = factor * inner_pressure_loss
+///
+/// the inner component is only borrowed immutably, so all 
+/// geometry, fluid properties and pressure sources come from the
+/// immutable methods of the inner component. The mass flowrate of
+/// the wrapper is stored within the wrapper itself.
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::area::square_meter;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+///
+/// let expansion = SuddenExpansion::new(
+///     Area::new::<square_meter>(1.0e-4),
+///     Area::new::<square_meter>(4.0e-4),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// let scaled_expansion = ScaledComponent::new(&expansion, 1.5);
+///
+/// let mass_flowrate = MassRate::new::<kilogram_per_second>(0.1);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     scaled_expansion.get_pressure_loss_immutable(mass_flowrate).value,
+///     1.5 * expansion.get_pressure_loss_immutable(mass_flowrate).value,
+///     max_relative = 1e-12);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScaledComponent<'a> {
+    /// the component whose pressure loss is scaled
+    pub inner: &'a dyn FluidComponent,
+    /// multiplier for the pressure loss, must be > 0
+    pub factor: f64,
+    /// current mass flowrate through the component
+    pub mass_flowrate: MassRate,
+}
+
+impl<'a> ScaledComponent<'a> {
+
+    /// constructor, the component starts with zero mass flowrate
+    ///
+    /// panics if factor <= 0.0, as a zero or negative 
+    /// pressure loss multiplier is nonphysical
+    pub fn new(inner: &'a dyn FluidComponent, factor: f64) -> Self {
+
+        if factor <= 0.0 {
+            panic!("scaling factor <= 0.0, nonphysical");
+        }
+
+        return Self {
+            inner,
+            factor,
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+}
+
+impl<'a> FluidComponent for ScaledComponent<'a> {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    /// the inner component sees the unscaled pressure loss,
+    /// ie. pressure_loss/factor
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        return self.inner.get_mass_flowrate_from_pressure_loss_immutable(
+            pressure_loss / self.factor);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return self.factor * 
+            self.inner.get_pressure_loss_immutable(mass_flowrate);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.inner.get_cross_sectional_area_immutable();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.inner.get_cross_sectional_area_immutable();
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.inner.get_hydraulic_diameter_immutable();
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.inner.get_hydraulic_diameter_immutable();
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.inner.get_fluid_viscosity_immutable();
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.inner.get_fluid_viscosity_immutable();
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.inner.get_fluid_density_immutable();
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.inner.get_fluid_density_immutable();
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.inner.get_component_length_immutable();
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.inner.get_component_length_immutable();
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.inner.get_incline_angle_immutable();
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.inner.get_incline_angle_immutable();
+    }
+
+    /// the internal pressure source is not scaled
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.inner.get_internal_pressure_source_immutable();
+    }
+
+    /// the internal pressure source is not scaled
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.inner.get_internal_pressure_source_immutable();
+    }
+
+    /// the inner component is borrowed immutably, so this panics,
+    /// set the pressure source on the inner component instead
+    fn set_internal_pressure_source(
+        &mut self,
+        _internal_pressure: Pressure) {
+        panic!("ScaledComponent cannot set the internal pressure source \
+               of the inner component");
+    }
+}
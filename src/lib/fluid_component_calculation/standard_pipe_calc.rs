@@ -50,7 +50,9 @@
 use crate::churchill_friction_factor;
 use crate::dimensionalisation;
 use crate::fluid_component_calculation::FluidComponent;
+use uom::si::pressure::pascal;
 use uom::si::acceleration::meter_per_second_squared;
+use crate::physical_constants::PhysicalConstants;
 
 use uom::si::f64::*;
 
@@ -187,6 +189,25 @@ FluidComponent{
         return hydrostatic_pressure_increase;
     }
 
+    /// calculates hydrostatic pressure change, same as 
+    /// get_hydrostatic_pressure_change, but with user supplied 
+    /// physical constants (eg. a different gravity)
+    fn get_hydrostatic_pressure_change_with_constants(
+        pipe_length: Length,
+        incline_angle: Angle,
+        fluid_density: MassDensity,
+        physical_constants: &PhysicalConstants) -> Pressure {
+
+        // gravity acts downwards, so pressure decreases going up
+        let g: Acceleration = -physical_constants.gravity;
+        let delta_h: Length = pipe_length*incline_angle.sin();
+
+        let hydrostatic_pressure_increase: Pressure =
+            fluid_density * g * delta_h;
+
+        return hydrostatic_pressure_increase;
+    }
+
 }
 
 /// provides generic methods to calculate mass flowrate
@@ -213,8 +234,11 @@ pub trait FluidPipeCalcPressureLoss {
 
     /// a function calculates pressure
     /// loss given a mass flowrate and pipe properties
+    ///
+    /// the pipe is symmetric, ie. reverse flow gives the same 
+    /// magnitude of pressure loss as forward flow
     fn pipe_calc_pressure_loss(
-        mut fluid_mass_flowrate: MassRate,
+        fluid_mass_flowrate: MassRate,
         cross_sectional_area: Area,
         hydraulic_diameter: Length,
         fluid_viscosity: DynamicViscosity,
@@ -222,6 +246,45 @@ pub trait FluidPipeCalcPressureLoss {
         pipe_length: Length,
         absolute_roughness: Length,
         form_loss_k: f64) -> Pressure {
+
+        return Self::pipe_calc_pressure_loss_asymmetric(
+            fluid_mass_flowrate,
+            cross_sectional_area,
+            hydraulic_diameter,
+            fluid_viscosity,
+            fluid_density,
+            pipe_length,
+            absolute_roughness,
+            form_loss_k,
+            1.0);
+    }
+
+    /// a function calculates pressure
+    /// loss given a mass flowrate and pipe properties,
+    /// for components which are not symmetric in reverse flow,
+    /// eg. diffusers or fluidic diodes
+    ///
+    /// for reverse flow (negative mass flowrate), the form loss
+    /// K is multiplied by reverse_flow_k_multiplier, the friction
+    /// losses are unchanged. A multiplier of 1.0 is the same as 
+    /// pipe_calc_pressure_loss
+    ///
+    /// panics if reverse_flow_k_multiplier < 0
+    fn pipe_calc_pressure_loss_asymmetric(
+        mut fluid_mass_flowrate: MassRate,
+        cross_sectional_area: Area,
+        hydraulic_diameter: Length,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity,
+        pipe_length: Length,
+        absolute_roughness: Length,
+        mut form_loss_k: f64,
+        reverse_flow_k_multiplier: f64) -> Pressure {
+
+        if reverse_flow_k_multiplier < 0.0 {
+            panic!("reverse_flow_k_multiplier < 0.0, nonphysical");
+        }
+
         // first let's calculate roughness ratio
 
         let roughness_ratio_quantity = absolute_roughness/hydraulic_diameter;
@@ -239,6 +302,7 @@ pub trait FluidPipeCalcPressureLoss {
 
         if reverse_flow {
             fluid_mass_flowrate = fluid_mass_flowrate * -1.0;
+            form_loss_k = form_loss_k * reverse_flow_k_multiplier;
         }
 
         // and let's get the reynolds_number and L/D
@@ -293,6 +357,44 @@ pub trait FluidPipeCalcPressureLoss {
         absolute_roughness: Length,
         form_loss_k: f64) -> MassRate {
 
+        return Self::pipe_calc_mass_flowrate_asymmetric(
+            pressure_loss,
+            cross_sectional_area,
+            hydraulic_diameter,
+            fluid_viscosity,
+            fluid_density,
+            pipe_length,
+            absolute_roughness,
+            form_loss_k,
+            1.0);
+    }
+
+    /// a function which calculates mass flowrate
+    /// given a pressure loss and pipe properties,
+    /// for components which are not symmetric in reverse flow
+    ///
+    /// a negative pressure loss means reverse flow, so the form 
+    /// loss K is multiplied by reverse_flow_k_multiplier,
+    /// see pipe_calc_pressure_loss_asymmetric
+    fn pipe_calc_mass_flowrate_asymmetric(
+        pressure_loss: Pressure,
+        cross_sectional_area: Area,
+        hydraulic_diameter: Length,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity,
+        pipe_length: Length,
+        absolute_roughness: Length,
+        mut form_loss_k: f64,
+        reverse_flow_k_multiplier: f64) -> MassRate {
+
+        if reverse_flow_k_multiplier < 0.0 {
+            panic!("reverse_flow_k_multiplier < 0.0, nonphysical");
+        }
+
+        if pressure_loss.value < 0.0 {
+            form_loss_k = form_loss_k * reverse_flow_k_multiplier;
+        }
+
         // first let's get our relevant ratios:
         let roughness_ratio_quantity = absolute_roughness/hydraulic_diameter;
 
@@ -383,7 +485,7 @@ impl CalcPressureLoss {
     /// println!("pressure loss : {:?} (Pascals) ", pressure_loss);
     /// ```
     #[allow(non_snake_case)]
-    pub fn from_mass_rate(mut fluidMassFlowrate: MassRate,
+    pub fn from_mass_rate(fluidMassFlowrate: MassRate,
                           crossSectionalArea: Area,
                           hydraulicDiameter: Length,
                           fluidViscosity: DynamicViscosity,
@@ -391,6 +493,78 @@ impl CalcPressureLoss {
                           pipeLength: Length,
                           absolute_roughness: Length,
                           formLossK: f64) -> Pressure {
+
+        return Self::from_mass_rate_with_smoothing(
+            fluidMassFlowrate,
+            crossSectionalArea,
+            hydraulicDiameter,
+            fluidViscosity,
+            fluidDensity,
+            pipeLength,
+            absolute_roughness,
+            formLossK,
+            false);
+    }
+
+    /// calculates pressure loss in a pipe from mass flowrate
+    /// with an option to smooth the friction factor across the
+    /// transition region (Re = 2300 to 4000)
+    ///
+    /// if smoothed is false, this is the same as from_mass_rate
+    ///
+    /// if smoothed is true, a C1 continuous blend between 64/Re
+    /// and the churchill correlation is used
+    /// (see churchill_friction_factor::smoothed_darcy).
+    /// This slightly alters pressure losses in the transition region
+    /// but makes the pressure loss curve smoother for root finders
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// extern crate uom;
+    /// use uom::si::mass_rate::kilogram_per_second;
+    /// use uom::si::dynamic_viscosity::pascal_second;
+    /// use uom::si::length::{meter,millimeter,foot,inch};
+    /// use uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use uom::si::area::square_meter;
+    /// 
+    /// use uom::si::f64::*;
+    ///
+    /// let fluid_mass_flowrate = MassRate::new::<kilogram_per_second>(0.015);
+    /// let cross_sectional_area= Area::new::<square_meter>(4e-5);
+    /// let hydraulic_diameter= Length::new::<inch>(3.0);
+    /// let fluid_viscosity= DynamicViscosity::new::<pascal_second>(0.001);
+    /// let fluid_density= MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    /// let pipe_length= Length::new::<foot>(6.0);
+    /// let absolute_roughness= Length::new::<millimeter>(0.001);
+    /// let form_loss_k= 5.0;
+    /// use crate::fluid_mechanics_rust::
+    ///     fluid_component_calculation::
+    ///     standard_pipe_calc::CalcPressureLoss;
+    ///
+    /// let pressure_loss = CalcPressureLoss::from_mass_rate_with_smoothing(
+    ///         fluid_mass_flowrate,
+    ///         cross_sectional_area,
+    ///         hydraulic_diameter,
+    ///         fluid_viscosity,
+    ///         fluid_density,
+    ///         pipe_length,
+    ///         absolute_roughness,
+    ///         form_loss_k,
+    ///         true);
+    ///
+    /// println!("pressure loss : {:?} (Pascals) ", pressure_loss);
+    /// ```
+    #[allow(non_snake_case)]
+    pub fn from_mass_rate_with_smoothing(mut fluidMassFlowrate: MassRate,
+                          crossSectionalArea: Area,
+                          hydraulicDiameter: Length,
+                          fluidViscosity: DynamicViscosity,
+                          fluidDensity: MassDensity,
+                          pipeLength: Length,
+                          absolute_roughness: Length,
+                          formLossK: f64,
+                          smoothed: bool) -> Pressure {
         // first let's calculate roughness ratio
 
         let roughnessRatioQuantity = absolute_roughness/hydraulicDiameter;
@@ -424,11 +598,12 @@ impl CalcPressureLoss {
         // then let's obtain the pipe Bejan Number
         // given the Re
 
-        let Be = churchill_friction_factor::getBe(
+        let Be = churchill_friction_factor::get_bejan_number_with_smoothing(
             Re,
             roughnessRatio,
             lengthToDiameterRatio,
-            formLossK);
+            formLossK,
+            smoothed);
 
         // once we get Be, we can get the pressure loss terms
         //
@@ -448,6 +623,85 @@ impl CalcPressureLoss {
         return pressureLoss;
     }
 
+    /// calculates pressure loss in a pipe from mass flowrate,
+    /// same as from_mass_rate, except that the darcy friction 
+    /// factor comes from the given correlation rather than 
+    /// the churchill correlation
+    ///
+    /// from_mass_rate is the same as using Churchill here.
+    /// Reverse flow gives a negative pressure loss, and zero
+    /// flow gives zero pressure loss
+    ///
+    /// ```rust
+    /// use uom::si::mass_rate::kilogram_per_second;
+    /// use uom::si::dynamic_viscosity::millipascal_second;
+    /// use uom::si::length::{meter, millimeter};
+    /// use uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use uom::si::area::square_meter;
+    /// use uom::si::f64::*;
+    /// use fluid_mechanics_rust::churchill_friction_factor::Haaland;
+    /// use fluid_mechanics_rust::fluid_component_calculation::
+    ///     standard_pipe_calc::CalcPressureLoss;
+    ///
+    /// let pressure_loss = CalcPressureLoss::from_mass_rate_with_correlation(
+    ///     MassRate::new::<kilogram_per_second>(0.5),
+    ///     Area::new::<square_meter>(6.11e-4),
+    ///     Length::new::<meter>(2.79e-2),
+    ///     DynamicViscosity::new::<millipascal_second>(1.0),
+    ///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+    ///     Length::new::<meter>(1.0),
+    ///     Length::new::<millimeter>(0.001),
+    ///     5.0,
+    ///     &Haaland);
+    ///
+    /// println!("pressure loss : {:?} (Pascals) ", pressure_loss);
+    /// ```
+    pub fn from_mass_rate_with_correlation(
+        fluid_mass_flowrate: MassRate,
+        cross_sectional_area: Area,
+        hydraulic_diameter: Length,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity,
+        pipe_length: Length,
+        absolute_roughness: Length,
+        form_loss_k: f64,
+        correlation: &dyn churchill_friction_factor::FrictionFactorCorrelation) 
+        -> Pressure {
+
+        let roughness_ratio = dimensionalisation::relative_roughness(
+            absolute_roughness, hydraulic_diameter);
+
+        // Re is signed here, the friction factor uses its magnitude
+        let reynolds_number = dimensionalisation::CalcReynolds::from_mass_rate(
+            fluid_mass_flowrate,
+            cross_sectional_area,
+            hydraulic_diameter,
+            fluid_viscosity);
+
+        if reynolds_number == 0.0 {
+            return Pressure::new::<pascal>(0.0);
+        }
+
+        let length_to_diameter_ratio = 
+            dimensionalisation::convert_dimensionless_number_to_float(
+                pipe_length/hydraulic_diameter);
+
+        let darcy_friction_factor = correlation.darcy(
+            reynolds_number.abs(), roughness_ratio);
+
+        let fldk = darcy_friction_factor * length_to_diameter_ratio 
+            + form_loss_k;
+
+        let bejan_number = 0.5 * fldk * reynolds_number * 
+            reynolds_number.abs();
+
+        return dimensionalisation::CalcBejan::to_pressure(
+            bejan_number,
+            hydraulic_diameter,
+            fluid_density,
+            fluid_viscosity);
+    }
+
     /// this calculates mass flowrate in a pipe given pressure losses
     ///
     /// Example:
@@ -507,6 +761,38 @@ impl CalcPressureLoss {
                         absolute_roughness: Length,
                         formLossK: f64) -> MassRate {
 
+        return Self::to_mass_rate_with_smoothing(
+            pressureLoss,
+            crossSectionalArea,
+            hydraulicDiameter,
+            fluidViscosity,
+            fluidDensity,
+            pipeLength,
+            absolute_roughness,
+            formLossK,
+            false);
+    }
+
+    /// calculates mass flowrate in a pipe given pressure losses
+    /// with an option to smooth the friction factor across the
+    /// transition region (Re = 2300 to 4000)
+    ///
+    /// if smoothed is false, this is the same as to_mass_rate
+    ///
+    /// if smoothed is true, this slightly alters the mass flowrate
+    /// obtained in the transition region, 
+    /// see churchill_friction_factor::smoothed_darcy
+    #[allow(non_snake_case)]
+    pub fn to_mass_rate_with_smoothing(pressureLoss: Pressure,
+                        crossSectionalArea: Area,
+                        hydraulicDiameter: Length,
+                        fluidViscosity: DynamicViscosity,
+                        fluidDensity: MassDensity,
+                        pipeLength: Length,
+                        absolute_roughness: Length,
+                        formLossK: f64,
+                        smoothed: bool) -> MassRate {
+
         // first let's get our relevant ratios:
         let roughnessRatioQuantity = absolute_roughness/hydraulicDiameter;
 
@@ -525,10 +811,12 @@ impl CalcPressureLoss {
             fluidDensity, fluidViscosity);
 
         // let's get Re
-        let Re_D = churchill_friction_factor::getRe(Be_D,
-                                                   roughnessRatio,
-                                                   lengthToDiameterRatio,
-                                                   formLossK);
+        let Re_D = churchill_friction_factor::get_reynolds_number_with_smoothing(
+            Be_D,
+            roughnessRatio,
+            lengthToDiameterRatio,
+            formLossK,
+            smoothed);
 
 
         // and finally return mass flowrate
@@ -544,3 +832,74 @@ impl CalcPressureLoss {
     }
 }
 
+
+/// calculates pressure loss in a pipe directly from the mean 
+/// fluid velocity, for when you have velocity rather than 
+/// mass flowrate
+///
+/// Re = rho * |v| * D / mu
+///
+/// Delta P = (f * (L/D) + K) * rho * v^2/2
+///
+/// where f is the churchill darcy friction factor. Like 
+/// CalcPressureLoss::from_mass_rate, the pipe is symmetric, so
+/// reverse flow (negative velocity) gives a negative pressure loss
+/// of the same magnitude, and zero velocity gives zero pressure loss
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::velocity::meter_per_second;
+/// use uom::si::length::{meter, millimeter};
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::pressure::pascal;
+/// use fluid_mechanics_rust::fluid_component_calculation::
+///     standard_pipe_calc::pressure_loss_from_velocity;
+///
+/// let pressure_loss = pressure_loss_from_velocity(
+///     Velocity::new::<meter_per_second>(1.0),
+///     Length::new::<meter>(2.79e-2),
+///     Length::new::<meter>(1.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     Length::new::<millimeter>(0.001),
+///     5.0);
+///
+/// // at least the form losses, K * rho * v^2/2 = 2500 Pa
+/// assert!(pressure_loss.get::<pascal>() > 2500.0);
+/// ```
+pub fn pressure_loss_from_velocity(velocity: Velocity,
+                                   diameter: Length,
+                                   length: Length,
+                                   density: MassDensity,
+                                   viscosity: DynamicViscosity,
+                                   roughness: Length,
+                                   form_loss_k: f64) -> Pressure {
+
+    // the nonphysical inputs are checked when calculating Re
+    let reynolds_number = dimensionalisation::CalcReynolds::from_velocity(
+        density,
+        velocity.abs(),
+        diameter,
+        viscosity);
+
+    if reynolds_number == 0.0 {
+        return Pressure::new::<pascal>(0.0);
+    }
+
+    let roughness_ratio = 
+        dimensionalisation::relative_roughness(roughness, diameter);
+
+    let length_to_diameter_ratio = 
+        dimensionalisation::convert_dimensionless_number_to_float(
+            length/diameter);
+
+    let fldk = churchill_friction_factor::fLDK(
+        reynolds_number,
+        roughness_ratio,
+        length_to_diameter_ratio,
+        form_loss_k);
+
+    // velocity * velocity.abs() keeps the sign for reverse flow
+    return fldk * density * velocity * velocity.abs() * 0.5;
+}
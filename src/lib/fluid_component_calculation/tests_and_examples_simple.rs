@@ -418,6 +418,20 @@ pub mod fluid_component_tests_and_examples {
         assert_eq!(pipe_mass_flowrate,
                    air_pipe_1.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss));
 
+        // the pressure change versions come for free from the 
+        // FluidComponent trait defaults. Since the air pipe is 
+        // horizontal with no internal pressure source, 
+        // pressure change is just the negative of pressure loss
+        let pressure_change = 
+            air_pipe_1.get_pressure_change_immutable(pipe_mass_flowrate);
+
+        assert_eq!(pressure_change,
+                   -air_pipe_1.get_pressure_loss_immutable(pipe_mass_flowrate));
+
+        assert_eq!(pipe_mass_flowrate,
+                   air_pipe_1.get_mass_flowrate_from_pressure_change_immutable(
+                       -pressure_loss));
+
         return;
 
     }
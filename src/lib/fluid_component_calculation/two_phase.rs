@@ -0,0 +1,437 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are homogeneous two phase multipliers and a two phase pipe
+
+use uom::si::f64::*;
+use uom::si::length::{meter, millimeter};
+use uom::si::angle::degree;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_calculation::standard_pipe_calc::{
+    FluidPipeCalcPressureChange, FluidPipeCalcPressureLoss};
+
+/// homogeneous equilibrium model (HEM) two phase friction 
+/// multiplier, based on liquid only flow (phi^2_lo)
+///
+/// phi^2_lo = 1 + x (rho_l/rho_v - 1)
+///
+/// x is the vapor quality, within [0,1] and 
+/// the density ratio is rho_l/rho_v, which should be >= 1
+///
+/// this neglects the change in viscosity with quality,
+/// see homogeneous_two_phase_multiplier_with_viscosity
+///
+/// ```rust
+/// use fluid_mechanics_rust::fluid_component_calculation::two_phase::*;
+///
+/// // single phase liquid has no multiplier
+/// assert_eq!(homogeneous_two_phase_multiplier(0.0, 1000.0), 1.0);
+///
+/// // all vapor flow is faster by the density ratio
+/// assert_eq!(homogeneous_two_phase_multiplier(1.0, 1000.0), 1000.0);
+/// ```
+pub fn homogeneous_two_phase_multiplier(quality: f64,
+                                        density_ratio: f64) -> f64 {
+
+    if !(0.0..=1.0).contains(&quality) {
+        panic!("vapor quality must be within [0,1]");
+    }
+
+    if density_ratio <= 0.0 {
+        panic!("liquid to vapor density ratio <= 0.0, nonphysical");
+    }
+
+    return 1.0 + quality * (density_ratio - 1.0);
+}
+
+/// homogeneous equilibrium model (HEM) two phase friction 
+/// multiplier, based on liquid only flow (phi^2_lo), 
+/// with the McAdams two phase viscosity
+///
+/// phi^2_lo = [1 + x (rho_l/rho_v - 1)] [1 + x (mu_l/mu_v - 1)]^(-1/4)
+///
+/// the viscosity correction assumes a Blasius type friction 
+/// factor, f ~ Re^(-1/4), so it is meant for turbulent flow
+///
+/// viscosity_ratio is mu_l/mu_v
+pub fn homogeneous_two_phase_multiplier_with_viscosity(
+    quality: f64,
+    density_ratio: f64,
+    viscosity_ratio: f64) -> f64 {
+
+    if viscosity_ratio <= 0.0 {
+        panic!("liquid to vapor viscosity ratio <= 0.0, nonphysical");
+    }
+
+    let density_multiplier = 
+        homogeneous_two_phase_multiplier(quality, density_ratio);
+
+    let viscosity_multiplier = 
+        (1.0 + quality * (viscosity_ratio - 1.0)).powf(-0.25);
+
+    return density_multiplier * viscosity_multiplier;
+}
+
+/// A pipe carrying a homogeneous two phase mixture,
+/// eg. slightly flashing Dowtherm A or water
+///
+/// This is a crude correction, the pressure loss is the
+/// single phase pressure loss for the whole flow as liquid 
+/// (calculated using pipe_calc_pressure_loss) multiplied by 
+/// the HEM multiplier phi^2_lo 
+/// (see homogeneous_two_phase_multiplier_with_viscosity)
+///
+/// The quality is constant along the pipe, ie. i don't 
+/// calculate flashing along the pipe. 
+/// Hydrostatic pressure is calculated using the homogeneous 
+/// mixture density:
+///
+/// 1/rho_h = x/rho_v + (1-x)/rho_l
+///
+/// and the fluid viscosity returned is the McAdams viscosity:
+///
+/// 1/mu_h = x/mu_v + (1-x)/mu_l
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+///
+/// use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+/// use fluid_mechanics_rust::fluid_component_calculation::two_phase::*;
+///
+/// let mut two_phase_pipe = TwoPhasePipe::new(
+///     "flashing_pipe",
+///     2.79e-2, 
+///     1.0, 
+///     0.001, 
+///     0.0, 
+///     0.0,
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     DynamicViscosity::new::<millipascal_second>(0.01));
+///
+/// let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+///
+/// let single_phase_loss = 
+///     two_phase_pipe.get_pressure_loss_immutable(mass_flowrate);
+///
+/// // at 1% quality, the loss goes up by about an order of magnitude
+/// two_phase_pipe.vapor_quality = 0.01;
+///
+/// let two_phase_loss = 
+///     two_phase_pipe.get_pressure_loss_immutable(mass_flowrate);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(two_phase_loss.value/single_phase_loss.value,
+///     two_phase_pipe.two_phase_multiplier(), max_relative = 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoPhasePipe {
+    _name: &'static str,
+    /// hydraulic diameter of the pipe
+    pub hydraulic_diameter: Length,
+    /// pipe length
+    pub pipe_length: Length,
+    /// absolute roughness of the pipe
+    pub absolute_roughness: Length,
+    /// incline angle of the pipe
+    pub incline_angle: Angle,
+    /// form loss K of the pipe
+    pub form_loss_k: f64,
+    /// vapor quality, within [0,1]
+    pub vapor_quality: f64,
+    /// saturated liquid density
+    pub liquid_density: MassDensity,
+    /// saturated vapor density
+    pub vapor_density: MassDensity,
+    /// saturated liquid viscosity
+    pub liquid_viscosity: DynamicViscosity,
+    /// saturated vapor viscosity
+    pub vapor_viscosity: DynamicViscosity,
+    /// internal pressure source, eg. a pump
+    pub internal_pressure_source: Pressure,
+    /// current mass flowrate through the pipe
+    pub mass_flowrate: MassRate,
+}
+
+impl TwoPhasePipe {
+
+    /// constructor for a two phase pipe, which starts 
+    /// with zero quality (ie. all liquid) and 
+    /// zero mass flowrate
+    pub fn new(name: &'static str,
+               hydraulic_diameter_meters: f64,
+               pipe_length_meters: f64,
+               absolute_roughness_millimeters: f64,
+               incline_angle_degrees: f64,
+               form_loss_k: f64,
+               liquid_density: MassDensity,
+               vapor_density: MassDensity,
+               liquid_viscosity: DynamicViscosity,
+               vapor_viscosity: DynamicViscosity) -> Self {
+
+        if hydraulic_diameter_meters <= 0.0 {
+            panic!("hydraulic diameter <= 0.0, nonphysical");
+        }
+
+        if liquid_density.value <= 0.0 || vapor_density.value <= 0.0 {
+            panic!("fluidDensity <= 0.0, nonphysical");
+        }
+
+        if liquid_viscosity.value <= 0.0 || vapor_viscosity.value <= 0.0 {
+            panic!("fluid Viscosity <= 0.0, nonphysical");
+        }
+
+        return Self {
+            _name: name,
+            hydraulic_diameter: Length::new::<meter>(
+                hydraulic_diameter_meters),
+            pipe_length: Length::new::<meter>(pipe_length_meters),
+            absolute_roughness: Length::new::<millimeter>(
+                absolute_roughness_millimeters),
+            incline_angle: Angle::new::<degree>(incline_angle_degrees),
+            form_loss_k,
+            vapor_quality: 0.0,
+            liquid_density,
+            vapor_density,
+            liquid_viscosity,
+            vapor_viscosity,
+            internal_pressure_source: Pressure::new::<pascal>(0.0),
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+
+    /// returns the HEM two phase multiplier phi^2_lo 
+    /// at the current vapor quality
+    pub fn two_phase_multiplier(&self) -> f64 {
+
+        let density_ratio = 
+            (self.liquid_density / self.vapor_density).value;
+
+        let viscosity_ratio = 
+            (self.liquid_viscosity / self.vapor_viscosity).value;
+
+        return homogeneous_two_phase_multiplier_with_viscosity(
+            self.vapor_quality,
+            density_ratio,
+            viscosity_ratio);
+    }
+
+    /// returns the homogeneous mixture density
+    /// 1/rho_h = x/rho_v + (1-x)/rho_l
+    pub fn homogeneous_density(&self) -> MassDensity {
+
+        let specific_volume = 
+            self.vapor_quality / self.vapor_density + 
+            (1.0 - self.vapor_quality) / self.liquid_density;
+
+        return 1.0 / specific_volume;
+    }
+
+    /// returns the McAdams two phase viscosity
+    /// 1/mu_h = x/mu_v + (1-x)/mu_l
+    pub fn homogeneous_viscosity(&self) -> DynamicViscosity {
+
+        let fluidity = 
+            self.vapor_quality / self.vapor_viscosity + 
+            (1.0 - self.vapor_quality) / self.liquid_viscosity;
+
+        return 1.0 / fluidity;
+    }
+}
+
+impl FluidPipeCalcPressureLoss for TwoPhasePipe {
+
+    fn get_pipe_form_loss_k(&mut self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_form_loss_k_immutable(&self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_absolute_roughness(&mut self) -> Length {
+        return self.absolute_roughness;
+    }
+
+    fn get_pipe_absolute_roughness_immutable(&self) -> Length {
+        return self.absolute_roughness;
+    }
+}
+
+impl FluidPipeCalcPressureChange for TwoPhasePipe {}
+
+impl FluidComponent for TwoPhasePipe {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    /// the multiplier does not depend on mass flowrate, so
+    /// i divide it out and solve the liquid only pipe
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+
+        let liquid_only_pressure_loss = 
+            pressure_loss / self.two_phase_multiplier();
+
+        return <Self as FluidPipeCalcPressureLoss>::pipe_calc_mass_flowrate(
+            liquid_only_pressure_loss,
+            self.get_cross_sectional_area_immutable(),
+            self.hydraulic_diameter,
+            self.liquid_viscosity,
+            self.liquid_density,
+            self.pipe_length,
+            self.absolute_roughness,
+            self.form_loss_k);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    /// liquid only pressure loss multiplied by phi^2_lo
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        let liquid_only_pressure_loss = 
+            <Self as FluidPipeCalcPressureLoss>::pipe_calc_pressure_loss(
+                mass_flowrate,
+                self.get_cross_sectional_area_immutable(),
+                self.hydraulic_diameter,
+                self.liquid_viscosity,
+                self.liquid_density,
+                self.pipe_length,
+                self.absolute_roughness,
+                self.form_loss_k);
+
+        return liquid_only_pressure_loss * self.two_phase_multiplier();
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.get_cross_sectional_area_immutable();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.hydraulic_diameter * self.hydraulic_diameter 
+            * std::f64::consts::PI / 4.0;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    /// returns the McAdams two phase viscosity
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return self.homogeneous_viscosity();
+    }
+
+    /// returns the McAdams two phase viscosity
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return self.homogeneous_viscosity();
+    }
+
+    /// returns the homogeneous mixture density
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return self.homogeneous_density();
+    }
+
+    /// returns the homogeneous mixture density
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return self.homogeneous_density();
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.pipe_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.pipe_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.internal_pressure_source = internal_pressure;
+    }
+}
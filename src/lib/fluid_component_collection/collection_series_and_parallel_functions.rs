@@ -51,6 +51,22 @@ use uom::si::mass_rate::kilogram_per_second;
 use uom::si::pressure::pascal;
 
 use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_collection::SolverConfig;
+use crate::dimensionalisation;
+
+/// the linear (laminar) fast path for series collections is only
+/// used if the Reynolds number in every component stays 
+/// below this, see 
+/// calculate_mass_flowrate_from_pressure_change_linear
+pub const LINEAR_SOLVER_MAX_REYNOLDS: f64 = 2000.0;
+
+/// mass flowrate (kg/s) used to probe the flow resistance of a 
+/// series collection for the linear (laminar) fast path
+const LINEAR_SOLVER_PROBE_MASS_FLOWRATE_KG_PER_S: f64 = 1e-6;
+
+/// the linear solution must reproduce the pressure change to within
+/// this relative tolerance, otherwise the brent solver is used
+const LINEAR_SOLVER_RELATIVE_TOLERANCE: f64 = 1e-9;
 
 // the peroxide crate for root finders
 
@@ -140,10 +156,32 @@ pub trait FluidComponentCollectionSeriesAssociatedFunctions {
     /// for a given fluid component collection
     /// it needs a vector of mutable references to
     /// any object which implements FluidComponent
+    ///
+    /// the brent solver uses abs_tol = 1e-15 and max_iter = 30
+    /// (SolverConfig::series_collection_default),
+    /// use calculate_mass_flowrate_from_pressure_change_with_config
+    /// if you want to change these
     fn calculate_mass_flowrate_from_pressure_change(
         pressure_change: Pressure,
         fluid_component_vector: &Vec<&dyn FluidComponent>) -> MassRate {
 
+        let solver_config = SolverConfig::series_collection_default();
+
+        return Self::calculate_mass_flowrate_from_pressure_change_with_config(
+            pressure_change,
+            fluid_component_vector,
+            &solver_config);
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for a given fluid component collection, 
+    /// with user supplied tolerance and iteration limits 
+    /// for the brent solver (see SolverConfig)
+    fn calculate_mass_flowrate_from_pressure_change_with_config(
+        pressure_change: Pressure,
+        fluid_component_vector: &Vec<&dyn FluidComponent>,
+        solver_config: &SolverConfig) -> MassRate {
+
         // a few key issues here:
         //
         // the method i'm going to use here is iteration
@@ -325,7 +363,7 @@ pub trait FluidComponentCollectionSeriesAssociatedFunctions {
         // But having done so, I want to use the newton raphson method to
         // try and converge this result, hopefully within 30 iterations
 
-        let mut convergency = SimpleConvergency { eps:1e-15f64, max_iter:30 };
+        let mut convergency = solver_config.convergency();
 
         let mut mass_flowrate_result =
             if forward_flow_true != true {
@@ -442,6 +480,209 @@ pub trait FluidComponentCollectionSeriesAssociatedFunctions {
         return MassRate::new::<kilogram_per_second>(mass_flowrate_result.unwrap());
     }
 
+    /// calculates mass flowrate from pressure change for 
+    /// fluid components in series, using a guess (eg. the mass 
+    /// flowrate from the previous timestep) as a warm start
+    ///
+    /// in transient simulations, the flow changes little between 
+    /// timesteps. So instead of bracketing between 0 and 10 kg/s, 
+    /// i bracket within 10% (or 1 g/s) of the guess and widen that
+    /// bracket tenfold a few times if the root isn't in it.
+    ///
+    /// if that fails, i fall back to 
+    /// calculate_mass_flowrate_from_pressure_change
+    fn calculate_mass_flowrate_from_pressure_change_with_guess(
+        pressure_change: Pressure,
+        fluid_component_vector: &Vec<&dyn FluidComponent>,
+        initial_guess: MassRate) -> MassRate {
+
+        // same as the cold start, pressure losses within 9 Pa 
+        // (about 1 mm h2o) of zero flow are taken as zero flow
+        let zero_mass_flow: MassRate 
+            = MassRate::new::<kilogram_per_second>(0.0);
+
+        let pressure_change_0kg_per_second: Pressure 
+            = Self::calculate_pressure_change_from_mass_flowrate(
+                zero_mass_flow, 
+                fluid_component_vector);
+
+        let pressure_loss_pascals = 
+            -(pressure_change - pressure_change_0kg_per_second).value;
+
+        if pressure_loss_pascals.abs() < 9_f64 {
+            return zero_mass_flow;
+        }
+
+        let mass_flow_from_pressure_chg_root = 
+            |mass_flow_kg_per_s: f64| -> f64 {
+
+            let mass_rate = 
+                MassRate::new::<kilogram_per_second>(mass_flow_kg_per_s);
+
+            let pressure_change_tested = 
+                Self::calculate_pressure_change_from_mass_flowrate(
+                mass_rate, 
+                fluid_component_vector);
+
+            return pressure_change.value - pressure_change_tested.value;
+        };
+
+        // same tolerance as the cold start
+        let solver_config = SolverConfig::series_collection_default();
+
+        let guess_kg_per_s = initial_guess.get::<kilogram_per_second>();
+
+        let mut half_bracket_width_kg_per_s = 
+            (0.1 * guess_kg_per_s.abs()).max(1e-3);
+
+        for _ in 0..3 {
+
+            let lower_bound = guess_kg_per_s - half_bracket_width_kg_per_s;
+            let upper_bound = guess_kg_per_s + half_bracket_width_kg_per_s;
+
+            // only call brent if the root is bracketed
+            if mass_flow_from_pressure_chg_root(lower_bound) *
+                mass_flow_from_pressure_chg_root(upper_bound) <= 0.0 {
+
+                    let mut convergency = 
+                        solver_config.convergency();
+
+                    let mass_flowrate_result = find_root_brent(
+                        lower_bound,
+                        upper_bound,
+                        &mass_flow_from_pressure_chg_root,
+                        &mut convergency);
+
+                    if let Ok(mass_flow_kg_per_s) = mass_flowrate_result {
+                        return MassRate::new::<kilogram_per_second>(
+                            mass_flow_kg_per_s);
+                    }
+                }
+
+            half_bracket_width_kg_per_s *= 10.0;
+        }
+
+        return Self::calculate_mass_flowrate_from_pressure_change(
+            pressure_change, fluid_component_vector);
+    }
+
+    /// calculates mass flowrate from pressure change for 
+    /// fluid components in series, solving directly if the flow
+    /// is laminar (creeping flow)
+    ///
+    /// in laminar flow, f = 64/Re, so the pressure loss is linear 
+    /// in mass flowrate:
+    ///
+    /// pressure_change = pressure_change_at_zero_flow - R m
+    ///
+    /// i get the flow resistance R by probing the collection at 
+    /// a tiny mass flowrate (1e-6 kg/s), and then 
+    /// m = (pressure_change_at_zero_flow - pressure_change)/R
+    ///
+    /// The linear solution is only returned if 
+    /// (1) the Reynolds number in every component is below 
+    /// LINEAR_SOLVER_MAX_REYNOLDS (2000) at that mass flowrate and
+    /// (2) the collection reproduces the pressure change at that 
+    /// mass flowrate to within a relative tolerance of 1e-9. 
+    /// The second guard catches components which are not linear 
+    /// even in laminar flow, eg. those with a form loss K or 
+    /// a pump head curve.
+    ///
+    /// Otherwise, i fall back to 
+    /// calculate_mass_flowrate_from_pressure_change (brent solver).
+    ///
+    /// Unlike the brent solver, small pressure losses (within 9 Pa of 
+    /// zero flow) are not rounded to zero flow here, since creeping 
+    /// flows often have pressure losses that small
+    fn calculate_mass_flowrate_from_pressure_change_linear(
+        pressure_change: Pressure,
+        fluid_component_vector: &Vec<&dyn FluidComponent>) -> MassRate {
+
+        match Self::try_linear_mass_flowrate_from_pressure_change(
+            pressure_change, fluid_component_vector) {
+            Some(linear_mass_flowrate) => return linear_mass_flowrate,
+            None => return Self::calculate_mass_flowrate_from_pressure_change(
+                pressure_change, fluid_component_vector),
+        }
+    }
+
+    /// the laminar fast path of 
+    /// calculate_mass_flowrate_from_pressure_change_linear on its own,
+    ///
+    /// returns None whenever either guard fails and the brent solver 
+    /// would be used instead, so callers can tell which path was taken
+    fn try_linear_mass_flowrate_from_pressure_change(
+        pressure_change: Pressure,
+        fluid_component_vector: &Vec<&dyn FluidComponent>) 
+        -> Option<MassRate> {
+
+        let zero_mass_flow: MassRate 
+            = MassRate::new::<kilogram_per_second>(0.0);
+
+        let pressure_change_0kg_per_second: Pressure 
+            = Self::calculate_pressure_change_from_mass_flowrate(
+                zero_mass_flow, 
+                fluid_component_vector);
+
+        let probe_mass_flowrate = MassRate::new::<kilogram_per_second>(
+            LINEAR_SOLVER_PROBE_MASS_FLOWRATE_KG_PER_S);
+
+        let probe_pressure_change: Pressure 
+            = Self::calculate_pressure_change_from_mass_flowrate(
+                probe_mass_flowrate, 
+                fluid_component_vector);
+
+        // flow resistance in Pa/(kg/s)
+        let flow_resistance = 
+            (pressure_change_0kg_per_second - probe_pressure_change).value
+            / LINEAR_SOLVER_PROBE_MASS_FLOWRATE_KG_PER_S;
+
+        if !flow_resistance.is_finite() || flow_resistance <= 0.0 {
+            return None;
+        }
+
+        let pressure_loss_pascals = 
+            (pressure_change_0kg_per_second - pressure_change).value;
+
+        if pressure_loss_pascals == 0.0 {
+            return Some(zero_mass_flow);
+        }
+
+        let linear_mass_flowrate = MassRate::new::<kilogram_per_second>(
+            pressure_loss_pascals / flow_resistance);
+
+        // guard 1: all components must be laminar
+        for fluid_component in fluid_component_vector.iter() {
+
+            let reynolds_number = 
+                dimensionalisation::CalcReynolds::from_mass_rate(
+                    linear_mass_flowrate,
+                    fluid_component.get_cross_sectional_area_immutable(),
+                    fluid_component.get_hydraulic_diameter_immutable(),
+                    fluid_component.get_fluid_viscosity_immutable());
+
+            if reynolds_number.abs() >= LINEAR_SOLVER_MAX_REYNOLDS {
+                return None;
+            }
+        }
+
+        // guard 2: the collection must actually be linear
+        let linear_pressure_change = 
+            Self::calculate_pressure_change_from_mass_flowrate(
+                linear_mass_flowrate, 
+                fluid_component_vector);
+
+        let pressure_change_error = 
+            (linear_pressure_change - pressure_change).value.abs();
+
+        if pressure_change_error > 
+            LINEAR_SOLVER_RELATIVE_TOLERANCE * pressure_loss_pascals.abs() {
+            return None;
+        }
+
+        return Some(linear_mass_flowrate);
+    }
+
 }
 
 
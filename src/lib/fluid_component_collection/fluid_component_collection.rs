@@ -45,10 +45,53 @@
 //
 // Main author of the code: Theodore Kay Chen Ong, supervised by
 // Professor Per F. Peterson
-use uom::si::f64::{Pressure, MassRate};
+use uom::si::f64::{Pressure, MassRate, Length, Volume, MassDensity, DynamicViscosity};
+use uom::si::length::meter;
+use uom::si::volume::cubic_meter;
 use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
 
 use crate::fluid_component_calculation::FluidComponent;
+use crate::dimensionalisation;
+use crate::churchill_friction_factor;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// result for one fluid component in a network result,
+/// see FluidComponentCollection::network_result
+///
+/// plain f64 in SI units are used rather than uom quantities 
+/// so that the json is easy to read with downstream tooling
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComponentResult {
+    /// component name, since not all components have names, 
+    /// this is component_<index> where index is the position in the 
+    /// fluid component vector
+    pub name: String,
+    /// pressure change across the component in Pa
+    pub pressure_change_pa: f64,
+    /// Reynolds number in the component
+    pub reynolds: f64,
+}
+
+/// summary of a solved fluid component collection,
+/// see FluidComponentCollection::network_result
+///
+/// with the serde feature, this can be written to a file 
+/// (eg. json) for downstream tooling
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetworkResult {
+    /// pressure change across the whole collection in Pa
+    pub total_pressure_change_pa: f64,
+    /// mass flowrate through the collection in kg/s
+    pub mass_flowrate_kg_per_s: f64,
+    /// results for each component, in the order of the 
+    /// fluid component vector
+    pub per_component: Vec<ComponentResult>,
+}
 
 /// a fluid component collection,
 /// which contains fluid components stored into a vector
@@ -159,6 +202,285 @@ pub trait FluidComponentCollection<'trait_lifetime> : FluidComponentCollectionMe
         self.set_fluid_component_vector(fluid_component_vector_mutable);
     }
 
+    /// returns an iterator over the fluid components 
+    /// in the collection, in order
+    ///
+    /// this saves you from looping over 
+    /// get_immutable_fluid_component_vector() yourself
+    fn components(&self) 
+        -> std::slice::Iter<'_, &'trait_lifetime dyn FluidComponent> {
+        return self.get_immutable_fluid_component_vector().iter();
+    }
+
+    /// returns the number of fluid components in the collection
+    fn len(&self) -> usize {
+        return self.get_immutable_fluid_component_vector().len();
+    }
+
+    /// returns true if the collection has no fluid components
+    fn is_empty(&self) -> bool {
+        return self.get_immutable_fluid_component_vector().is_empty();
+    }
+
+    /// returns the sum of the lengths of all fluid components
+    /// in the collection
+    fn total_component_length(&self) -> Length {
+
+        let mut total_length = Length::new::<meter>(0.0);
+
+        for fluid_component in self.components() {
+
+            total_length += 
+                fluid_component.get_component_length_immutable();
+        }
+
+        return total_length;
+    }
+
+    /// returns the total volume of fluid in the collection,
+    /// ie. the sum of cross sectional area times length
+    /// of each fluid component
+    ///
+    /// multiply this by density to get the mass inventory
+    fn total_fluid_volume(&self) -> Volume {
+
+        let mut total_volume = Volume::new::<cubic_meter>(0.0);
+
+        for fluid_component in self.components() {
+
+            total_volume += 
+                fluid_component.get_cross_sectional_area_immutable() *
+                fluid_component.get_component_length_immutable();
+        }
+
+        return total_volume;
+    }
+
+    /// returns the total Bejan number of the collection
+    /// (taken in series) at the given mass flowrate, ie. the sum 
+    /// of each component's Bejan number
+    ///
+    /// Be_D = pressure_loss * rho * D^2 / mu^2
+    ///
+    /// each component's pressure loss is converted with 
+    /// CalcBejan::from_pressure using the same reference diameter,
+    /// density and viscosity, not the component's own. 
+    /// So the total Bejan number depends on the reference 
+    /// diameter (it scales with D^2), and is only comparable to 
+    /// Reynolds numbers based on that same diameter. The collection 
+    /// is assumed isothermal
+    fn total_bejan_number(&self,
+                          mass_flowrate: MassRate,
+                          density: MassDensity,
+                          viscosity: DynamicViscosity,
+                          reference_diameter: Length) -> f64 {
+
+        let mut total_bejan_number: f64 = 0.0;
+
+        for fluid_component in self.components() {
+
+            total_bejan_number += 
+                dimensionalisation::CalcBejan::from_pressure(
+                    fluid_component.get_pressure_loss_immutable(
+                        mass_flowrate),
+                    reference_diameter,
+                    density,
+                    viscosity);
+        }
+
+        return total_bejan_number;
+    }
+
+    /// returns the form loss K of a single equivalent pipe 
+    /// which has the same pressure loss as the whole collection 
+    /// (taken in series) at a reference Reynolds number
+    ///
+    /// the reference Reynolds number is based on the
+    /// equivalent pipe's hydraulic diameter and a circular
+    /// cross sectional area. i use the density and viscosity 
+    /// of the first component, so the collection is assumed
+    /// isothermal
+    ///
+    /// the total Bejan number is:
+    ///
+    /// Be = 0.5 * Re^2 * (f * (L/D) + K)
+    ///
+    /// so that 
+    ///
+    /// K = 2 Be / Re^2 - f * (L/D)
+    ///
+    /// note that this is an approximation, the friction factor 
+    /// of each component changes with Re differently from the 
+    /// equivalent pipe, so the K is only valid near the 
+    /// reference Reynolds number
+    ///
+    /// panics if the collection is empty or reference Re is zero
+    fn equivalent_form_loss_k(&self,
+                              reference_reynolds: f64,
+                              hydraulic_diameter: Length,
+                              length: Length,
+                              roughness: Length) -> f64 {
+
+        if reference_reynolds == 0.0 {
+            panic!("reference Re = 0.0, equivalent K is undefined");
+        }
+
+        let fluid_component_vector = 
+            self.get_immutable_fluid_component_vector();
+
+        if fluid_component_vector.is_empty() {
+            panic!("empty fluid component collection, \
+                   equivalent K is undefined");
+        }
+
+        let fluid_density = 
+            fluid_component_vector[0].get_fluid_density_immutable();
+        let fluid_viscosity = 
+            fluid_component_vector[0].get_fluid_viscosity_immutable();
+
+        // first i get the mass flowrate at the reference Re
+        let cross_sectional_area = 
+            hydraulic_diameter * hydraulic_diameter * 
+            std::f64::consts::PI / 4.0;
+
+        let reference_mass_flowrate = 
+            dimensionalisation::CalcReynolds::to_mass_rate(
+                cross_sectional_area,
+                reference_reynolds,
+                hydraulic_diameter,
+                fluid_viscosity);
+
+        // next i sum up the pressure losses in series
+        let mut total_pressure_loss = Pressure::new::<pascal>(0.0);
+
+        for fluid_component in fluid_component_vector.iter() {
+            total_pressure_loss += 
+                fluid_component.get_pressure_loss_immutable(
+                    reference_mass_flowrate);
+        }
+
+        // then convert to a Bejan number and back solve for K
+        let total_bejan_number = 
+            dimensionalisation::CalcBejan::from_pressure(
+                total_pressure_loss,
+                hydraulic_diameter,
+                fluid_density,
+                fluid_viscosity);
+
+        let total_fldk = 2.0 * total_bejan_number / 
+            reference_reynolds.powf(2.0);
+
+        let roughness_ratio: f64 = 
+            dimensionalisation::relative_roughness(
+                roughness, hydraulic_diameter);
+
+        let length_to_diameter: f64 = 
+            (length / hydraulic_diameter).value;
+
+        let darcy_friction_factor = 
+            churchill_friction_factor::darcy(
+                reference_reynolds.abs(), roughness_ratio);
+
+        return total_fldk.abs() - darcy_friction_factor * length_to_diameter;
+    }
+
+    /// returns the pressure change of each fluid component
+    /// at the given mass flowrate, indexed by the component's 
+    /// position in the fluid component vector 
+    /// (not all components have names)
+    ///
+    /// this is the same per component loop as in 
+    /// calculate_pressure_change_from_mass_flowrate, so for a 
+    /// series collection, the pressure changes sum up to the
+    /// total pressure change. It is useful to see which component
+    /// dominates the pressure loss
+    fn component_pressure_changes(&self,
+                                  mass_flowrate: MassRate) 
+        -> Vec<(usize, Pressure)> {
+
+        let mut component_pressure_changes: Vec<(usize, Pressure)> = 
+            vec![];
+
+        for (index, fluid_component) in self.components().enumerate() {
+
+            component_pressure_changes.push(
+                (index, 
+                 fluid_component.get_pressure_change_immutable(
+                     mass_flowrate)));
+        }
+
+        return component_pressure_changes;
+    }
+
+    /// summarises the collection at the given mass flowrate, ie.
+    /// the total pressure change (get_pressure_change) and the 
+    /// pressure change and Reynolds number of each component
+    ///
+    /// like component_pressure_changes, every component is 
+    /// evaluated at the same mass flowrate, so the per component
+    /// results are meant for series collections
+    fn network_result(&self, mass_flowrate: MassRate) -> NetworkResult {
+
+        let mut per_component: Vec<ComponentResult> = 
+            Vec::with_capacity(self.len());
+
+        for (index, fluid_component) in self.components().enumerate() {
+
+            let reynolds = dimensionalisation::CalcReynolds::from_mass_rate(
+                mass_flowrate,
+                fluid_component.get_cross_sectional_area_immutable(),
+                fluid_component.get_hydraulic_diameter_immutable(),
+                fluid_component.get_fluid_viscosity_immutable());
+
+            per_component.push(ComponentResult {
+                name: format!("component_{}", index),
+                pressure_change_pa: fluid_component.
+                    get_pressure_change_immutable(mass_flowrate).
+                    get::<pascal>(),
+                reynolds,
+            });
+        }
+
+        return NetworkResult {
+            total_pressure_change_pa: 
+                self.get_pressure_change(mass_flowrate).get::<pascal>(),
+            mass_flowrate_kg_per_s: 
+                mass_flowrate.get::<kilogram_per_second>(),
+            per_component,
+        };
+    }
+
+    /// returns the pressure at each node of a series collection,
+    /// ie. the inlet, each junction between components, and 
+    /// the outlet, for plotting a pressure profile
+    ///
+    /// starting from the inlet pressure, i add each component's 
+    /// pressure change (get_pressure_change_immutable), which 
+    /// subtracts the pressure loss and adds the hydrostatic and 
+    /// internal pressure terms. So for N components, there are 
+    /// N+1 node pressures, in the order of the fluid component 
+    /// vector, and the last one is the inlet pressure plus the
+    /// total pressure change of the series
+    fn node_pressures(&self, 
+                      mass_flowrate: MassRate, 
+                      inlet_pressure: Pressure) -> Vec<Pressure> {
+
+        let mut node_pressures: Vec<Pressure> = 
+            Vec::with_capacity(self.len() + 1);
+
+        let mut node_pressure = inlet_pressure;
+        node_pressures.push(node_pressure);
+
+        for fluid_component in self.components() {
+
+            node_pressure += 
+                fluid_component.get_pressure_change_immutable(mass_flowrate);
+            node_pressures.push(node_pressure);
+        }
+
+        return node_pressures;
+    }
+
 
 }
 
@@ -240,3 +562,22 @@ pub trait FluidComponentCollectionMethods{
 
 }
 
+
+/// joins two fluid component collections in series,
+/// ie. the components of collection a followed by those of b
+///
+/// this returns the merged fluid component vector, which you can
+/// use to construct a new series collection (or pass to the 
+/// FluidComponentCollectionSeriesAssociatedFunctions directly).
+/// The components themselves are not copied, only the references
+pub fn concat_series<'a>(a: &dyn FluidComponentCollection<'a>,
+                         b: &dyn FluidComponentCollection<'a>) 
+    -> Vec<&'a dyn FluidComponent> {
+
+    let mut merged_fluid_component_vector: Vec<&'a dyn FluidComponent> = 
+        a.get_immutable_fluid_component_vector().clone();
+
+    merged_fluid_component_vector.extend(b.components());
+
+    return merged_fluid_component_vector;
+}
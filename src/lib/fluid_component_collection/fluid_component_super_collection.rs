@@ -46,6 +46,47 @@
 // Main author of the code: Theodore Kay Chen Ong, supervised by
 // Professor Per F. Peterson
 use crate::fluid_component_collection::FluidComponentCollectionMethods;
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+
+/// mass flowrates with a magnitude at or below this (in kg/s)
+/// are taken as stagnant, since the solvers only converge 
+/// the flowrate to within some tolerance
+pub const STAGNANT_MASS_FLOWRATE_KG_PER_S: f64 = 1e-9;
+
+/// direction of flow through a branch or component,
+/// relative to the direction defined as positive mass flowrate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowDirection {
+    /// positive mass flowrate
+    Forward,
+    /// negative mass flowrate, eg. a reversed natural 
+    /// circulation branch
+    Reverse,
+    /// (practically) zero mass flowrate
+    Stagnant,
+}
+
+impl FlowDirection {
+
+    /// classifies a mass flowrate into a flow direction,
+    /// see STAGNANT_MASS_FLOWRATE_KG_PER_S
+    pub fn from_mass_flowrate(mass_flowrate: MassRate) -> Self {
+
+        let mass_flowrate_value = 
+            mass_flowrate.get::<kilogram_per_second>();
+
+        if mass_flowrate_value.abs() <= STAGNANT_MASS_FLOWRATE_KG_PER_S {
+            return FlowDirection::Stagnant;
+        }
+
+        if mass_flowrate_value > 0.0 {
+            return FlowDirection::Forward;
+        }
+
+        return FlowDirection::Reverse;
+    }
+}
 
 /// a fluid component super collection
 /// which contains fluid components stored into a vector
@@ -175,6 +216,56 @@ FluidComponentCollectionMethods{
         self.set_vector(fluid_component_super_vector_mutable);
     }
 
+    /// returns the mass flowrate through each branch of a parallel 
+    /// super collection given the total mass flowrate through it
+    ///
+    /// the pressure change across the super collection is solved
+    /// first (get_pressure_change), and then the mass flowrate 
+    /// through each branch is calculated from that pressure change
+    /// with get_mass_flowrate_from_pressure_change
+    ///
+    /// the branch mass flowrates sum to the total mass flowrate 
+    /// to within the solver tolerance
+    ///
+    /// this only makes sense for parallel super collections, since
+    /// branches in series all have the same mass flowrate
+    fn branch_mass_flowrates(&self, 
+                             total_mass_flowrate: MassRate) 
+        -> Vec<MassRate> {
 
+        let pressure_change = 
+            self.get_pressure_change(total_mass_flowrate);
+
+        let fluid_component_super_vector =
+            self.get_immutable_vector();
+
+        let mut branch_mass_flowrate_vector: Vec<MassRate> = 
+            Vec::with_capacity(fluid_component_super_vector.len());
+
+        for fluid_component_collection in 
+            fluid_component_super_vector.iter() {
+
+            branch_mass_flowrate_vector.push(fluid_component_collection.
+                get_mass_flowrate_from_pressure_change(pressure_change));
+        }
+
+        return branch_mass_flowrate_vector;
+    }
+
+    /// returns the flow direction in each branch of a parallel 
+    /// super collection given the total mass flowrate through it,
+    /// see branch_mass_flowrates
+    ///
+    /// this is useful to detect reverse flow, eg. when one 
+    /// natural circulation branch flows backwards
+    fn branch_flow_directions(&self, 
+                              total_mass_flowrate: MassRate) 
+        -> Vec<FlowDirection> {
+
+        return self.branch_mass_flowrates(total_mass_flowrate)
+            .into_iter()
+            .map(FlowDirection::from_mass_flowrate)
+            .collect();
+    }
 
 }
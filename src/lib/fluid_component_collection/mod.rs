@@ -73,10 +73,16 @@ pub use collection_series_and_parallel_functions::*;
 /// for calculating pressure changes and mass flowrates
 /// of fluid components in series and parallel
 ///
-/// note: multithreaded operations not included here
+/// note: multithreaded operations are only included for 
+/// the series pressure change calculation, behind the rayon feature
 pub mod super_collection_series_and_parallel_functions;
 pub use super_collection_series_and_parallel_functions::*;
 
+/// This module contains the tolerance and iteration settings
+/// for the mass flowrate solvers
+pub mod solver_config;
+pub use solver_config::*;
+
 /// This module contains tests and examples for the fluid component
 /// collections and super collection traits
 pub mod tests_and_examples;
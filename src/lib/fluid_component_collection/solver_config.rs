@@ -0,0 +1,121 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+extern crate roots;
+use roots::SimpleConvergency;
+
+/// tolerance and iteration settings for the brent solver 
+/// used to find mass flowrate from pressure change
+///
+/// abs_tol is passed into the roots crate as the convergence 
+/// epsilon, which is an absolute tolerance checked against both 
+/// the bracket width (in kg/s) and the pressure change 
+/// residual (in Pa), not a relative one. 
+/// So a tighter abs_tol gives a more accurate mass flowrate,
+/// and a looser one converges in fewer iterations
+///
+/// the solvers have different defaults, so there is no single 
+/// Default, use series_collection_default or 
+/// super_collection_default to match what each solver 
+/// does without a config
+///
+/// ```rust
+/// use fluid_mechanics_rust::fluid_component_collection::SolverConfig;
+///
+/// let solver_config = SolverConfig::series_collection_default();
+///
+/// assert_eq!(solver_config.abs_tol, 1e-15);
+/// assert_eq!(solver_config.max_iter, 30);
+///
+/// let solver_config = SolverConfig::super_collection_default();
+///
+/// assert_eq!(solver_config.abs_tol, 1e-9);
+/// assert_eq!(solver_config.max_iter, 30);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverConfig {
+    /// absolute convergence tolerance for the brent solver,
+    /// in kg/s for the bracket width and Pa for the residual
+    pub abs_tol: f64,
+    /// maximum number of iterations for each bracket 
+    /// the solver tries
+    pub max_iter: usize,
+}
+
+impl SolverConfig {
+
+    /// settings used by the series fluid component collection 
+    /// solver (FluidComponentCollectionSeriesAssociatedFunctions)
+    /// by default, abs_tol = 1e-15 and max_iter = 30
+    pub fn series_collection_default() -> Self {
+        return Self {
+            abs_tol: 1e-15,
+            max_iter: 30,
+        };
+    }
+
+    /// settings used by the series super collection solver
+    /// (FluidComponentSuperCollectionSeriesAssociatedFunctions)
+    /// by default, abs_tol = 1e-9 and max_iter = 30
+    pub fn super_collection_default() -> Self {
+        return Self {
+            abs_tol: 1e-9,
+            max_iter: 30,
+        };
+    }
+
+    /// returns the convergency object used by the roots crate
+    pub(crate) fn convergency(&self) -> SimpleConvergency<f64> {
+
+        if self.abs_tol <= 0.0 {
+            panic!("solver abs_tol <= 0.0, the solver cannot converge");
+        }
+
+        return SimpleConvergency { eps: self.abs_tol, max_iter: self.max_iter };
+    }
+}
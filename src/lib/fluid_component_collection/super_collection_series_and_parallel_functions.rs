@@ -48,11 +48,16 @@
 
 
 use uom::num_traits::ToPrimitive;
-use uom::si::f64::{Pressure, MassRate};
+use uom::si::f64::{Pressure, MassRate, ThermodynamicTemperature};
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::pressure::pascal;
 
 use crate::fluid_component_collection::FluidComponentCollectionMethods;
+use crate::fluid_component_calculation::TemperatureDependentFluidComponent;
+use crate::fluid_component_collection::SolverConfig;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 // the peroxide crate for root finders
 
@@ -62,6 +67,74 @@ extern crate roots;
 use roots::find_root_brent;
 use roots::SimpleConvergency;
 
+/// default magnitude of the mass flowrate bracket (kg/s) used by 
+/// the series super collection solver
+///
+/// this is the final fallback bracket, the solver tries 10 kg/s
+/// and 10,000 kg/s first (about the flowrate of water carrying 
+/// 3 GWth in a PWR). 20,000,000 kg/s is a tenth of the Amazon 
+/// river's flow, which bounds any man made flow
+pub const DEFAULT_MASS_FLOWRATE_BOUND_KG_PER_S: f64 = 20_000_000.0;
+
+
+/// sets the fluid temperature of each temperature dependent
+/// component, panics if the slices are not the same length
+fn set_fluid_temperatures(
+    fluid_component_vector: &mut [&mut dyn TemperatureDependentFluidComponent],
+    fluid_temperatures: &[ThermodynamicTemperature]) {
+
+    if fluid_component_vector.len() != fluid_temperatures.len() {
+        panic!("fluid_component_vector and fluid_temperatures \n
+               must be the same length");
+    }
+
+    for (fluid_component, fluid_temp) in 
+        fluid_component_vector.iter_mut().zip(fluid_temperatures.iter()) {
+            fluid_component.set_fluid_temp(*fluid_temp);
+        }
+}
+
+/// temperature dependent components in series, treated as
+/// a single collection so that they can be solved with the 
+/// series super collection solver
+struct TemperatureDependentSeries<'series_lifetime, 'component_lifetime> {
+    fluid_component_vector: 
+        &'series_lifetime [&'component_lifetime mut dyn 
+        TemperatureDependentFluidComponent],
+}
+
+impl<'series_lifetime, 'component_lifetime> 
+FluidComponentSuperCollectionSeriesAssociatedFunctions 
+for TemperatureDependentSeries<'series_lifetime, 'component_lifetime> {}
+
+impl<'series_lifetime, 'component_lifetime> FluidComponentCollectionMethods 
+for TemperatureDependentSeries<'series_lifetime, 'component_lifetime> {
+
+    fn get_pressure_change(&self, 
+                           fluid_mass_flowrate: MassRate) -> Pressure {
+
+        let mut final_pressure_change: Pressure =
+            Pressure::new::<pascal>(0.0);
+
+        for fluid_component in self.fluid_component_vector.iter() {
+            final_pressure_change += 
+                fluid_component.get_pressure_change_immutable(
+                    fluid_mass_flowrate);
+        }
+
+        return final_pressure_change;
+    }
+
+    fn get_mass_flowrate_from_pressure_change(
+        &self, 
+        pressure_change: Pressure) -> MassRate {
+
+        return <Self as FluidComponentSuperCollectionSeriesAssociatedFunctions>
+            ::calculate_mass_flowrate_from_pressure_change(
+                pressure_change,
+                &vec![self]);
+    }
+}
 
 /// contains associated functions which take a fluid component collection
 /// vector and calculate mass flowrates and pressure changes
@@ -134,11 +207,110 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
 
     }
 
+    /// calculates pressure change from mass flowrate
+    /// for a given fluid component collection,
+    /// same as calculate_pressure_change_from_mass_flowrate
+    /// except that the get_pressure_change for each collection
+    /// is evaluated on multiple threads using rayon
+    ///
+    /// the pressure changes are summed up in the same order as the 
+    /// serial version, so the result is exactly the same
+    ///
+    /// the collections must be Sync so that they can be shared
+    /// across threads
+    #[cfg(feature = "rayon")]
+    fn calculate_pressure_change_from_mass_flowrate_parallel(
+        mass_flowrate: MassRate,
+        fluid_component_collection_vector: 
+        &Vec<&(dyn FluidComponentCollectionMethods + Sync)>) -> Pressure {
+
+        // par_iter with collect preserves the order of the 
+        // collections in the vector
+        let pressure_vector: Vec<Pressure> = 
+            fluid_component_collection_vector.par_iter()
+            .map(|fluid_component_collection| {
+                fluid_component_collection.get_pressure_change(mass_flowrate)
+            })
+            .collect();
+
+        let mut final_pressure_change: Pressure =
+            Pressure::new::<pascal>(0.0);
+        // now we sum everything up
+
+        for pressure_change in pressure_vector {
+
+            final_pressure_change += pressure_change;
+
+        }
+
+        return final_pressure_change;
+    }
+
+    /// calculates pressure change from mass flowrate
+    /// for fluid components whose properties depend on temperature,
+    /// connected in series
+    ///
+    /// the fluid temperatures are parallel to the fluid 
+    /// components, ie. fluid_temperatures[i] is the 
+    /// temperature of fluid_component_vector[i].
+    /// Each component's fluid temperature is set first 
+    /// (so density and viscosity are recalculated), and then the 
+    /// pressure changes are summed
+    ///
+    /// panics if the two slices are not the same length
+    fn calculate_pressure_change_with_temperatures(
+        mass_flowrate: MassRate,
+        fluid_component_vector: 
+        &mut [&mut dyn TemperatureDependentFluidComponent],
+        fluid_temperatures: &[ThermodynamicTemperature]) -> Pressure {
+
+        set_fluid_temperatures(fluid_component_vector, fluid_temperatures);
+
+        let temperature_dependent_series = TemperatureDependentSeries {
+            fluid_component_vector,
+        };
+
+        return temperature_dependent_series.get_pressure_change(mass_flowrate);
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for fluid components whose properties depend on temperature,
+    /// connected in series
+    ///
+    /// like calculate_pressure_change_with_temperatures, 
+    /// each component's fluid temperature is set first, then
+    /// the components are solved for mass flowrate as a single 
+    /// series collection using 
+    /// calculate_mass_flowrate_from_pressure_change
+    ///
+    /// panics if the two slices are not the same length
+    fn calculate_mass_flowrate_with_temperatures(
+        pressure_change: Pressure,
+        fluid_component_vector: 
+        &mut [&mut dyn TemperatureDependentFluidComponent],
+        fluid_temperatures: &[ThermodynamicTemperature]) -> MassRate {
+
+        set_fluid_temperatures(fluid_component_vector, fluid_temperatures);
+
+        let temperature_dependent_series = TemperatureDependentSeries {
+            fluid_component_vector,
+        };
+
+        return Self::calculate_mass_flowrate_from_pressure_change(
+            pressure_change,
+            &vec![&temperature_dependent_series]);
+    }
+
     /// calculates mass flowrate from pressure change
     /// for a given fluid component super collection
     /// it needs a vector of mutable references to
     /// any object which implements FluidComponent
     ///
+    /// the mass flowrate is bracketed between -20,000,000 kg/s and 
+    /// 20,000,000 kg/s (see the comments in 
+    /// calculate_mass_flowrate_from_pressure_change_with_bounds 
+    /// for why), if you want a different bracket, use
+    /// calculate_mass_flowrate_from_pressure_change_with_bounds
     ///
     /// [PENDING EDITS]
     /// 
@@ -147,6 +319,92 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
         fluid_component_collection_vector: 
         &Vec<&dyn FluidComponentCollectionMethods>) -> MassRate {
 
+        let upper_bound = MassRate::new::<kilogram_per_second>(
+            DEFAULT_MASS_FLOWRATE_BOUND_KG_PER_S);
+        let lower_bound = -upper_bound;
+
+        return Self::calculate_mass_flowrate_from_pressure_change_with_bounds(
+            pressure_change,
+            fluid_component_collection_vector,
+            lower_bound,
+            upper_bound);
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for a given fluid component super collection,
+    /// but with a user supplied bracket (lower and upper 
+    /// mass flowrate) for the brent solver
+    ///
+    /// For microfluidic or small loops, the default bracket of 
+    /// 20,000,000 kg/s is far too big, so a tighter bracket converges
+    /// faster. The solver still tries a small bracket 
+    /// (10 kg/s, then 10,000 kg/s) first, but these are clipped 
+    /// to the bounds you supply
+    ///
+    /// it panics if lower is not less than upper
+    ///
+    fn calculate_mass_flowrate_from_pressure_change_with_bounds(
+        pressure_change: Pressure,
+        fluid_component_collection_vector: 
+        &Vec<&dyn FluidComponentCollectionMethods>,
+        lower: MassRate,
+        upper: MassRate) -> MassRate {
+
+        return Self::calculate_mass_flowrate_from_pressure_change_with_bounds_and_config(
+            pressure_change,
+            fluid_component_collection_vector,
+            lower,
+            upper,
+            &SolverConfig::super_collection_default());
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for a given fluid component super collection,
+    /// with the default bracket (see 
+    /// calculate_mass_flowrate_from_pressure_change) but user 
+    /// supplied tolerance and iteration limits (see SolverConfig)
+    fn calculate_mass_flowrate_from_pressure_change_with_config(
+        pressure_change: Pressure,
+        fluid_component_collection_vector: 
+        &Vec<&dyn FluidComponentCollectionMethods>,
+        solver_config: &SolverConfig) -> MassRate {
+
+        let upper_bound = MassRate::new::<kilogram_per_second>(
+            DEFAULT_MASS_FLOWRATE_BOUND_KG_PER_S);
+        let lower_bound = -upper_bound;
+
+        return Self::calculate_mass_flowrate_from_pressure_change_with_bounds_and_config(
+            pressure_change,
+            fluid_component_collection_vector,
+            lower_bound,
+            upper_bound,
+            solver_config);
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for a given fluid component super collection,
+    /// with both a user supplied bracket (as in 
+    /// calculate_mass_flowrate_from_pressure_change_with_bounds)
+    /// and user supplied tolerance and iteration limits 
+    /// (see SolverConfig)
+    ///
+    /// it panics if lower is not less than upper
+    fn calculate_mass_flowrate_from_pressure_change_with_bounds_and_config(
+        pressure_change: Pressure,
+        fluid_component_collection_vector: 
+        &Vec<&dyn FluidComponentCollectionMethods>,
+        lower: MassRate,
+        upper: MassRate,
+        solver_config: &SolverConfig) -> MassRate {
+
+        if lower >= upper {
+            panic!("mass flowrate bounds: lower bound must be less than \n
+                   upper bound");
+        }
+
+        let lower_bound_kg_per_s = lower.get::<kilogram_per_second>();
+        let upper_bound_kg_per_s = upper.get::<kilogram_per_second>();
+
         // To iteratively find mass flowrate from pressure change,
         // I first need to find an initial guess of mass flowrates with which
         // to iterate
@@ -554,7 +812,7 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
         // But having done so, I want to use the newton raphson method to
         // try and converge this result, hopefully within 30 iterations
 
-        let mut convergency = SimpleConvergency { eps:1e-9f64, max_iter:30 };
+        let mut convergency = solver_config.convergency();
 
         let mut mass_flowrate_result =
             if forward_flow_true != true {
@@ -562,8 +820,8 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
                 // i will search between -10 and 0 for the bracketing
                 let mass_flowrate_result 
                     = find_root_brent(
-                        -10_f64,
-                        -0_f64,
+                        f64::max(-10_f64, lower_bound_kg_per_s),
+                        f64::min(-0_f64, upper_bound_kg_per_s),
                         &mass_flow_from_pressure_chg_root,
                         &mut convergency);
 
@@ -574,8 +832,8 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
             } else {
                 let mass_flowrate_result 
                     = find_root_brent(
-                        10_f64,
-                        0_f64,
+                        f64::min(10_f64, upper_bound_kg_per_s),
+                        f64::max(0_f64, lower_bound_kg_per_s),
                         &mass_flow_from_pressure_chg_root,
                         &mut convergency);
                 
@@ -623,7 +881,7 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
         // quickly,
         //
         // I'll do 10,000 kg/s in each flow branch first
-        // then 200,000,000
+        // then the user supplied bounds (20,000,000 kg/s by default)
 
 
         mass_flowrate_result = 
@@ -634,8 +892,8 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
 
                     mass_flowrate_result 
                         = find_root_brent(
-                            10_000_f64,
-                            -10_000_f64,
+                            f64::min(10_000_f64, upper_bound_kg_per_s),
+                            f64::max(-10_000_f64, lower_bound_kg_per_s),
                             &mass_flow_from_pressure_chg_root,
                             &mut convergency);
 
@@ -651,8 +909,8 @@ pub trait FluidComponentSuperCollectionSeriesAssociatedFunctions {
 
                     mass_flowrate_result 
                         = find_root_brent(
-                            20_000_000_f64,
-                            -20_000_000_f64,
+                            upper_bound_kg_per_s,
+                            lower_bound_kg_per_s,
                             &mass_flow_from_pressure_chg_root,
                             &mut convergency);
 
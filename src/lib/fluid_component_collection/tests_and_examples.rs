@@ -541,10 +541,10 @@ pub mod fluid_component_collection_test_and_examples {
             get_pressure_change(pipe_airflow);
 
         // the pressure losses are about -174650 Pa
-        approx::assert_relative_eq!(
-            pipe_pressure_change.value,
-            -174650.0,
-            max_relative=0.001);
+        crate::test_utils::assert_pressure_eq(
+            pipe_pressure_change,
+            Pressure::new::<pascal>(-174650.0),
+            0.001);
 
         // i will also test the get pressure loss function
 
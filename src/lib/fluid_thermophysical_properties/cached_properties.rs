@@ -0,0 +1,238 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a cache for fluid properties evaluated at repeated temperatures
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
+use crate::fluid_thermophysical_properties::FluidProperties;
+
+/// maximum number of temperatures cached per property, 
+/// once this is reached the cache for that property is cleared
+pub const CACHED_FLUID_PROPERTIES_CAPACITY: usize = 1024;
+
+/// A wrapper around any FluidProperties object which memoizes 
+/// density, viscosity, enthalpy, specific heat capacity and 
+/// thermal conductivity by temperature
+///
+/// The temperature is quantized to temperature_resolution_kelvin
+/// (1e-6 K by default) before it is used as a key, so two 
+/// temperatures within the same resolution step return the same 
+/// (cached) value. On a cache miss, the inner fluid properties 
+/// are called and the result stored. get_temperature_from_enthalpy,
+/// thermal_expansion_coefficient and the temperature range are 
+/// not cached, they are passed straight to the inner properties.
+///
+/// Thread safety: the cache uses RefCell, so this wrapper is not
+/// Sync and cannot be shared between threads (eg. with the 
+/// rayon feature). Make one CachedFluidProperties per thread 
+/// around the same inner properties instead. 
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+///
+/// let dowtherm_props = DowthermAProperties::new();
+/// let cached_props = CachedFluidProperties::new(&dowtherm_props);
+///
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+///
+/// assert_eq!(cached_props.density(fluid_temp), 
+///            dowtherm_props.density(fluid_temp));
+/// ```
+pub struct CachedFluidProperties<'a> {
+    /// the fluid properties being cached
+    pub inner: &'a dyn FluidProperties,
+    /// temperature step used to quantize the cache keys
+    pub temperature_resolution_kelvin: f64,
+    density_cache: RefCell<HashMap<i64, MassDensity>>,
+    viscosity_cache: RefCell<HashMap<i64, DynamicViscosity>>,
+    enthalpy_cache: RefCell<HashMap<i64, AvailableEnergy>>,
+    specific_heat_capacity_cache: RefCell<HashMap<i64, SpecificHeatCapacity>>,
+    thermal_conductivity_cache: RefCell<HashMap<i64, ThermalConductivity>>,
+}
+
+impl<'a> CachedFluidProperties<'a> {
+
+    /// constructor, with a temperature resolution of 1e-6 K
+    pub fn new(inner: &'a dyn FluidProperties) -> Self {
+        return Self::with_resolution(inner, 1.0e-6);
+    }
+
+    /// constructor with a user specified temperature resolution,
+    /// 
+    /// a coarser resolution gives more cache hits but 
+    /// less accurate properties, panics if resolution <= 0.0
+    pub fn with_resolution(inner: &'a dyn FluidProperties,
+                           temperature_resolution_kelvin: f64) -> Self {
+
+        if temperature_resolution_kelvin <= 0.0 {
+            panic!("temperature resolution <= 0.0, nonphysical");
+        }
+
+        return Self {
+            inner,
+            temperature_resolution_kelvin,
+            density_cache: RefCell::new(HashMap::new()),
+            viscosity_cache: RefCell::new(HashMap::new()),
+            enthalpy_cache: RefCell::new(HashMap::new()),
+            specific_heat_capacity_cache: RefCell::new(HashMap::new()),
+            thermal_conductivity_cache: RefCell::new(HashMap::new()),
+        };
+    }
+
+    /// empties all the caches
+    pub fn clear(&self) {
+        self.density_cache.borrow_mut().clear();
+        self.viscosity_cache.borrow_mut().clear();
+        self.enthalpy_cache.borrow_mut().clear();
+        self.specific_heat_capacity_cache.borrow_mut().clear();
+        self.thermal_conductivity_cache.borrow_mut().clear();
+    }
+
+    /// quantizes the temperature into a cache key
+    fn cache_key(&self, fluid_temp: ThermodynamicTemperature) -> i64 {
+        return (fluid_temp.get::<kelvin>() / 
+                self.temperature_resolution_kelvin).round() as i64;
+    }
+
+    /// returns the cached value for the temperature,
+    /// or calculates and stores it on a cache miss
+    fn get_or_calculate<Q: Copy>(
+        &self,
+        cache: &RefCell<HashMap<i64, Q>>,
+        fluid_temp: ThermodynamicTemperature,
+        calculate_property: &dyn Fn(ThermodynamicTemperature) -> Q) -> Q {
+
+        let key = self.cache_key(fluid_temp);
+
+        if let Some(cached_value) = cache.borrow().get(&key) {
+            return *cached_value;
+        }
+
+        // the inner properties are called outside of the borrow, 
+        // so a panic there (eg. out of range) leaves the cache usable
+        let calculated_value = calculate_property(fluid_temp);
+
+        let mut cache_mutable = cache.borrow_mut();
+
+        if cache_mutable.len() >= CACHED_FLUID_PROPERTIES_CAPACITY {
+            cache_mutable.clear();
+        }
+
+        cache_mutable.insert(key, calculated_value);
+
+        return calculated_value;
+    }
+}
+
+impl<'a> FluidProperties for CachedFluidProperties<'a> {
+
+    fn density(&self,
+               fluid_temp: ThermodynamicTemperature) -> MassDensity {
+        return self.get_or_calculate(
+            &self.density_cache, fluid_temp, 
+            &|temp| self.inner.density(temp));
+    }
+
+    fn viscosity(&self,
+                 fluid_temp: ThermodynamicTemperature) -> DynamicViscosity {
+        return self.get_or_calculate(
+            &self.viscosity_cache, fluid_temp, 
+            &|temp| self.inner.viscosity(temp));
+    }
+
+    fn enthalpy(&self,
+                fluid_temp: ThermodynamicTemperature) -> AvailableEnergy {
+        return self.get_or_calculate(
+            &self.enthalpy_cache, fluid_temp, 
+            &|temp| self.inner.enthalpy(temp));
+    }
+
+    fn specific_heat_capacity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity {
+        return self.get_or_calculate(
+            &self.specific_heat_capacity_cache, fluid_temp, 
+            &|temp| self.inner.specific_heat_capacity(temp));
+    }
+
+    fn thermal_conductivity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity {
+        return self.get_or_calculate(
+            &self.thermal_conductivity_cache, fluid_temp, 
+            &|temp| self.inner.thermal_conductivity(temp));
+    }
+
+    /// not cached, keyed by enthalpy rather than temperature
+    fn get_temperature_from_enthalpy(
+        &self,
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature {
+        return self.inner.get_temperature_from_enthalpy(fluid_enthalpy);
+    }
+
+    /// not cached, so that the inner properties' own 
+    /// expression (analytic or not) is used
+    fn thermal_expansion_coefficient(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> TemperatureCoefficient {
+        return self.inner.thermal_expansion_coefficient(fluid_temp);
+    }
+
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return self.inner.min_temp();
+    }
+
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return self.inner.max_temp();
+    }
+}
@@ -1,13 +1,26 @@
 use uom::si::f64::*;
+use uom::si::thermodynamic_temperature::kelvin;
 
 /// This is a library containing all the properties which one can
 /// choose for the ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions
 pub mod property_library;
 pub use property_library::*;
 
+/// a wrapper which memoizes fluid properties by temperature,
+/// for expensive property correlations
+pub mod cached_properties;
+pub use cached_properties::*;
+
 /// contains tests and examples to use the fluid thermophysical properties
 pub mod tests_and_examples;
 
+/// kinematic viscosity, ie. dynamic viscosity over density
+///
+/// uom 0.34 does not have a kinematic viscosity quantity,
+/// but it has the same units (m^2/s) as a diffusion coefficient,
+/// so i use that 
+pub type KinematicViscosity = DiffusionCoefficient;
+
 
 // ideally i'd want an easy way to make a selection of which fluid i want
 // to use, perhaps via an enum or something,
@@ -147,13 +160,59 @@ pub trait ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions
                       fluid_properties: &dyn FluidProperties) -> f64 {
         // mu * cp/k
 
-        let prandtl = fluid_properties.viscosity(fluid_temp)
-            *fluid_properties.specific_heat_capacity(fluid_temp)
-            /fluid_properties.thermal_conductivity(fluid_temp);
+        return Self::prandtl_number_from_properties(
+            fluid_properties.viscosity(fluid_temp),
+            fluid_properties.specific_heat_capacity(fluid_temp),
+            fluid_properties.thermal_conductivity(fluid_temp));
+    }
+
+    /// Prandtl number calculated by
+    /// mu cp / k
+    ///
+    /// from properties you have already calculated, so that 
+    /// expensive property correlations are only evaluated once
+    fn prandtl_number_from_properties(
+        fluid_viscosity: DynamicViscosity,
+        fluid_specific_heat_capacity: SpecificHeatCapacity,
+        fluid_thermal_conductivity: ThermalConductivity) -> f64 {
+
+        let prandtl: Ratio = fluid_viscosity
+            *fluid_specific_heat_capacity
+            /fluid_thermal_conductivity;
 
         return prandtl.value;
     }
 
+    /// kinematic viscosity calculated by
+    /// mu / rho
+    ///
+    /// this is the nu in the Bejan number
+    /// Be = (P * D^2)/(mu * nu)
+    fn kinematic_viscosity(fluid_temp: ThermodynamicTemperature,
+                           fluid_properties: &dyn FluidProperties) 
+        -> KinematicViscosity {
+
+        let kinematic_viscosity: KinematicViscosity = 
+            fluid_properties.viscosity(fluid_temp)
+            /fluid_properties.density(fluid_temp);
+
+        return kinematic_viscosity;
+    }
+
+    /// thermal diffusivity calculated by
+    /// k / (rho cp)
+    fn thermal_diffusivity(fluid_temp: ThermodynamicTemperature,
+                           fluid_properties: &dyn FluidProperties) 
+        -> DiffusionCoefficient {
+
+        let thermal_diffusivity: DiffusionCoefficient = 
+            fluid_properties.thermal_conductivity(fluid_temp)
+            /fluid_properties.density(fluid_temp)
+            /fluid_properties.specific_heat_capacity(fluid_temp);
+
+        return thermal_diffusivity;
+    }
+
     /// get fluid temperature 
     /// this is a get function which forces the user
     /// to remember to have a fluid temperature property
@@ -230,6 +289,137 @@ pub trait FluidProperties {
     fn get_temperature_from_enthalpy(
         &self,
         fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature;
+
+    /// fluid thermal expansion coefficient based on temperature,
+    ///
+    /// beta = -1/rho * d(rho)/dT
+    ///
+    /// by default this is a central difference of the density
+    /// over 1 K (kept within min_temp and max_temp), 
+    /// fluids with an analytic expression should override this
+    fn thermal_expansion_coefficient(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> TemperatureCoefficient {
+
+        let half_step = TemperatureInterval::new::<
+            uom::si::temperature_interval::kelvin>(0.5);
+
+        let mut lower_temp = fluid_temp - half_step;
+        let mut upper_temp = fluid_temp + half_step;
+
+        if lower_temp < self.min_temp() {
+            lower_temp = self.min_temp();
+        }
+
+        if upper_temp > self.max_temp() {
+            upper_temp = self.max_temp();
+        }
+
+        let density_change = 
+            self.density(upper_temp) - self.density(lower_temp);
+
+        // both temperatures are in kelvin, so the difference 
+        // is the temperature interval in kelvin
+        let temperature_change_kelvin = 
+            upper_temp.get::<kelvin>() - lower_temp.get::<kelvin>();
+
+        let thermal_expansion_value = -density_change.value
+            /temperature_change_kelvin/self.density(fluid_temp).value;
+
+        return TemperatureCoefficient::new::<
+            uom::si::temperature_coefficient::per_kelvin>(
+                thermal_expansion_value);
+    }
+
+    /// lowest temperature at which the property correlations are valid
+    ///
+    /// by default this is absolute zero, fluids with a narrower 
+    /// correlation range should override this
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<kelvin>(0.0);
+    }
+
+    /// highest temperature at which the property correlations are valid
+    ///
+    /// by default this is unbounded, fluids with a narrower 
+    /// correlation range should override this
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<kelvin>(f64::MAX);
+    }
+
+    /// checks if the temperature is within the correlation range,
+    /// (min_temp and max_temp inclusive)
+    ///
+    /// this is useful so that you can check before calling density()
+    /// and the like, which panic outside the range
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::thermodynamic_temperature::degree_celsius;
+    /// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    ///
+    /// let dowtherm_props = DowthermAProperties::new();
+    ///
+    /// assert!(dowtherm_props.is_temp_valid(
+    ///     ThermodynamicTemperature::new::<degree_celsius>(50.0)));
+    /// assert!(!dowtherm_props.is_temp_valid(
+    ///     ThermodynamicTemperature::new::<degree_celsius>(200.0)));
+    /// ```
+    fn is_temp_valid(&self, fluid_temp: ThermodynamicTemperature) -> bool {
+        return fluid_temp >= self.min_temp() 
+            && fluid_temp <= self.max_temp();
+    }
 }
 
 
+/// A selection of the fluids which have property correlations 
+/// in the property library
+///
+/// This saves you from having to remember the struct names,
+/// you can just get a boxed trait object of the properties
+/// and inject it into your pipe or component
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+///
+/// let props = FluidKind::DowthermA.properties();
+///
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+/// let density = props.density(fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(1078.0 - 0.85*50.0, density.value);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FluidKind {
+    /// Dowtherm A, valid from 20C to 180C
+    DowthermA,
+    /// liquid water at about 1 atm, valid from 5C to 150C
+    WaterLiquid,
+    /// Therminol VP 1, valid from 20C to 180C
+    TherminolVP1,
+    /// molten salt FLiBe (2LiF-BeF2), valid from 732 K to 1000 K
+    Flibe,
+}
+
+impl FluidKind {
+
+    /// returns the fluid properties object as a boxed trait object
+    ///
+    /// the property structs hold no data, so the box owns 
+    /// everything it needs and lives as long as you keep it
+    pub fn properties(&self) -> Box<dyn FluidProperties> {
+        match self {
+            FluidKind::DowthermA => 
+                return Box::new(DowthermAProperties::new()),
+            FluidKind::WaterLiquid => 
+                return Box::new(WaterLiquidProperties::new()),
+            FluidKind::TherminolVP1 => 
+                return Box::new(TherminolVP1Properties::new()),
+            FluidKind::Flibe => 
+                return Box::new(FlibeProperties::new()),
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use crate::fluid_thermophysical_properties::*;
-use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
 use uom::si::mass_density::kilogram_per_cubic_meter;
 use uom::si::dynamic_viscosity::pascal_second;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
 use uom::si::available_energy::joule_per_kilogram;
+use crate::therminol_component::dowtherm_a_properties;
 extern crate peroxide;
 use peroxide::prelude::*;
+extern crate roots;
+use roots::find_root_brent;
+use roots::SimpleConvergency;
 // This library was developed for use in my PhD thesis under supervision 
 // of Professor Per F. Peterson. It is part of a thermal hydraulics
 // library in Rust that is released under the GNU General Public License
@@ -294,6 +298,15 @@ impl FluidProperties for TherminolVP1Properties {
             new::<degree_celsius>(fluid_temperature_degrees_c);
     }
 
+    /// the correlations are valid from 20C
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    }
+
+    /// the correlations are valid up to 180C
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(180.0);
+    }
 }
 
 impl TherminolVP1Properties {
@@ -348,3 +361,1073 @@ impl TherminolVP1Properties {
 
     }
 }
+
+/// Contains thermophysical property information for 
+/// liquid water at around 1 atm
+///
+/// These are not the full IAPWS-IF97 equations, rather they are 
+/// simple correlations which i fitted to IAPWS data for liquid water 
+/// at about 1 atm. They are meant for water loops which 
+/// operate from 5C to 150C, anything outside that and the code 
+/// panics just like the therminol correlations
+///
+/// density is from Kell (1975), 
+/// viscosity is from the Vogel equation,
+/// heat capacity and thermal conductivity are polynomial fits
+/// in degrees C
+///
+/// Strictly speaking, at 1 atm water boils at 100C, so for 100C 
+/// to 150C you should have a slightly pressurised loop,
+/// the liquid properties change very little with pressure in this 
+/// range though
+///
+/// Example:
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+///
+/// let water_properties = WaterLiquidProperties::new();
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+///
+/// let density = water_properties.density(fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(998.2, 
+/// density.get::<kilogram_per_cubic_meter>(), 
+/// max_relative=0.001);
+/// ```
+pub struct WaterLiquidProperties {
+
+}
+
+impl FluidProperties for WaterLiquidProperties {
+    /// fluid density based on temperature,
+    ///
+    /// uses the Kell (1975) correlation for water at 1 atm
+    fn density(&self,
+               fluid_temp: ThermodynamicTemperature) -> MassDensity{
+
+        Self::water_liquid_range_check(fluid_temp);
+
+        let t = fluid_temp.get::<degree_celsius>();
+
+        let numerator = 999.83952 
+            + 16.945176 * t 
+            - 7.9870401e-3 * t.powf(2.0)
+            - 46.170461e-6 * t.powf(3.0)
+            + 105.56302e-9 * t.powf(4.0)
+            - 280.54253e-12 * t.powf(5.0);
+
+        let denominator = 1.0 + 16.879850e-3 * t;
+
+        let density_value_kg_per_m3 = numerator/denominator;
+
+        return MassDensity::new::<kilogram_per_cubic_meter>(density_value_kg_per_m3);
+    }
+
+    /// fluid dynamic viscosity based on temperature,
+    ///
+    /// uses the Vogel equation 
+    ///
+    /// mu = 2.414e-5 * 10^(247.8/(T - 140)) 
+    ///
+    /// where T is in kelvin and mu is in Pa s
+    fn viscosity(&self,
+                 fluid_temp: ThermodynamicTemperature) -> DynamicViscosity{
+
+        Self::water_liquid_range_check(fluid_temp);
+
+        let temp_kelvin_value = fluid_temp.get::<kelvin>();
+        let viscosity_value_pa_s = 2.414e-5 * 10.0_f64.powf(
+            247.8/(temp_kelvin_value - 140.0));
+
+        return DynamicViscosity::new::<pascal_second>(viscosity_value_pa_s);
+    }
+
+    /// function to obtain water enthalpy
+    /// given a temperature
+    ///
+    /// This is done via analytically integrating 
+    /// the polynomial for specific heat capacity of water
+    ///
+    /// i take the reference value to be 0 J/kg enthalpy at 0C
+    /// (close to the IAPWS reference of the triple point)
+    ///
+    /// cp = 4203.60 - 1.10565 T + 0.0129230 T^2 - 6.09063e-6 T^3
+    ///
+    /// H = 4203.60 T - 1.10565/2 T^2 + 0.0129230/3 T^3 - 6.09063e-6/4 T^4
+    ///
+    /// At 20C, this is about 83.9 kJ/kg which is close to the 
+    /// steam table value
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::thermodynamic_temperature::degree_celsius;
+    /// use uom::si::available_energy::kilojoule_per_kilogram;
+    /// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    ///
+    /// let water_properties = WaterLiquidProperties::new();
+    /// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    ///
+    /// let enthalpy = water_properties.enthalpy(fluid_temp);
+    ///
+    /// extern crate approx;
+    /// approx::assert_relative_eq!(83.9, 
+    /// enthalpy.get::<kilojoule_per_kilogram>(), 
+    /// max_relative=0.005);
+    /// ```
+    fn enthalpy(&self,
+                fluid_temp: ThermodynamicTemperature) -> AvailableEnergy{
+
+        Self::water_liquid_range_check(fluid_temp);
+
+        let t = fluid_temp.get::<degree_celsius>();
+
+        let enthalpy_value_joule_per_kg = 
+            WATER_CP_COEFFICIENTS[0] * t
+            + WATER_CP_COEFFICIENTS[1]/2.0 * t.powf(2.0)
+            + WATER_CP_COEFFICIENTS[2]/3.0 * t.powf(3.0)
+            + WATER_CP_COEFFICIENTS[3]/4.0 * t.powf(4.0);
+
+        return AvailableEnergy::new::<joule_per_kilogram>(
+            enthalpy_value_joule_per_kg);
+    }
+
+    /// fluid specific heat capacity  based on temperature,
+    ///
+    /// cubic fit in degrees C to IAPWS data at 1 atm,
+    /// within 0.1% from 5C to 150C
+    fn specific_heat_capacity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity{
+
+        Self::water_liquid_range_check(fluid_temp);
+
+        let t = fluid_temp.get::<degree_celsius>();
+
+        let cp_value_joule_per_kg_kelvin = 
+            WATER_CP_COEFFICIENTS[0]
+            + WATER_CP_COEFFICIENTS[1] * t
+            + WATER_CP_COEFFICIENTS[2] * t.powf(2.0)
+            + WATER_CP_COEFFICIENTS[3] * t.powf(3.0);
+
+        return SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            cp_value_joule_per_kg_kelvin);
+    }
+
+    /// fluid thermal conductivity based on temperature,
+    ///
+    /// quadratic fit in degrees C to IAPWS data at 1 atm,
+    /// within 0.5% from 5C to 150C
+    fn thermal_conductivity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity{
+
+        Self::water_liquid_range_check(fluid_temp);
+
+        let t = fluid_temp.get::<degree_celsius>();
+
+        let thermal_conductivity_value = 0.5623536631944211 
+            + 0.001990290782148339 * t
+            - 8.070622887316972e-06 * t.powf(2.0);
+
+        return ThermalConductivity::new::<watt_per_meter_kelvin>(
+            thermal_conductivity_value);
+    }
+
+    /// function to obtain water temperature 
+    /// given a enthalpy
+    ///
+    /// we use the enthalpy correlation (reference 0 J/kg at 0C)
+    /// and bisection between 5C and 150C to find the temperature
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::thermodynamic_temperature::degree_celsius;
+    /// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    ///
+    /// let water_properties = WaterLiquidProperties::new();
+    /// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(65.0);
+    ///
+    /// let enthalpy = water_properties.enthalpy(fluid_temp);
+    /// let temp_actual = water_properties.get_temperature_from_enthalpy(
+    /// enthalpy);
+    ///
+    /// extern crate approx;
+    /// approx::assert_relative_eq!(65.0, 
+    /// temp_actual.get::<degree_celsius>(), 
+    /// max_relative=1e-6);
+    /// ```
+    fn get_temperature_from_enthalpy(
+        &self,
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature{
+
+        let enthalpy_value_joule_per_kg = 
+            fluid_enthalpy.get::<joule_per_kilogram>();
+
+        let lower_bound_enthalpy = self.enthalpy(
+            ThermodynamicTemperature::new::<degree_celsius>(5.0));
+        let upper_bound_enthalpy = self.enthalpy(
+            ThermodynamicTemperature::new::<degree_celsius>(150.0));
+
+        if fluid_enthalpy < lower_bound_enthalpy 
+            || fluid_enthalpy > upper_bound_enthalpy {
+            panic!("water liquid : get_temperature_from_enthalpy \n
+               enthalpy {:?} out of correlation range (5C to 150C)",
+               fluid_enthalpy);
+        }
+
+        let enthalpy_root = |temp_degrees_c_value : AD| -> AD {
+            let lhs_value = enthalpy_value_joule_per_kg;
+            let temp_degrees_c_value_double = temp_degrees_c_value.x();
+
+            let fluid_temperature = 
+                ThermodynamicTemperature::new::<degree_celsius>(
+                    temp_degrees_c_value_double);
+            let rhs = self.enthalpy(fluid_temperature);
+            let rhs_value = rhs.get::<joule_per_kilogram>();
+
+            return AD0(lhs_value-rhs_value);
+        };
+
+        let fluid_temperature_degrees_cresult 
+            = bisection(enthalpy_root,
+                        (5.0,150.0),
+                        100,
+                        1e-8);
+
+        let fluid_temperature_degrees_c = fluid_temperature_degrees_cresult.unwrap();
+
+        return ThermodynamicTemperature::
+            new::<degree_celsius>(fluid_temperature_degrees_c);
+    }
+
+    /// the correlations are valid from 5C
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(5.0);
+    }
+
+    /// the correlations are valid up to 150C
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(150.0);
+    }
+}
+
+/// coefficients for liquid water heat capacity in J/(kg K),
+/// cp = c0 + c1 T + c2 T^2 + c3 T^3 with T in degrees C
+const WATER_CP_COEFFICIENTS: [f64; 4] = [
+    4203.602758899652,
+    -1.1056516359974344,
+    0.012922975035485748,
+    -6.090634615576512e-06];
+
+impl Default for WaterLiquidProperties {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl WaterLiquidProperties {
+
+    /// constructor
+    pub fn new() -> Self {
+        return Self {  };
+    }
+
+    /// the correlation has temperature range from 5 C to 150 C,
+    /// anything outside this and the code throws a panic
+    ///
+    pub fn water_liquid_range_check(fluid_temp: ThermodynamicTemperature) -> bool{
+
+        let temp_value_celsius = 
+            fluid_temp.get::<degree_celsius>();
+
+        if temp_value_celsius < 5.0 {
+            let error_msg = "Your fluid temperature \n";
+            let error_msg1 = "is too low :";
+            let error_msg3 = "C \n";
+            let error_msg4 = "\n the minimum is 5C";
+
+            panic!("{}{}{:?}{}{}",
+                   error_msg,
+                   error_msg1,
+                   fluid_temp,
+                   error_msg3,
+                   error_msg4);
+        }
+
+        if temp_value_celsius > 150.0 {
+            let error_msg = "Your fluid temperature \n";
+            let error_msg1 = "is too high :";
+            let error_msg3 = "C \n";
+            let error_msg4 = "\n the max is 150C";
+
+            panic!("{}{}{:?}{}{}",
+                   error_msg,
+                   error_msg1,
+                   fluid_temp,
+                   error_msg3,
+                   error_msg4);
+        }
+
+        return true;
+
+    }
+}
+
+/// Contains thermophysical property information for 
+/// Dowtherm A 
+///
+/// Dowtherm A and Therminol VP 1 are essentially the same 
+/// eutectic mixture of diphenyl and diphenyl oxide, so the 
+/// correlations are the same as TherminolVP1Properties
+/// (Zweibaum 2015 PhD dissertation), ranging from 20C to 180C
+///
+/// This struct just calls the functions in 
+/// therminol_component::dowtherm_a_properties so that
+/// the older code and this trait object give the same answers
+pub struct DowthermAProperties {
+
+}
+
+impl FluidProperties for DowthermAProperties {
+    /// fluid density based on temperature,
+    fn density(&self,
+               fluid_temp: ThermodynamicTemperature) -> MassDensity{
+        return dowtherm_a_properties::getDowthermADensity(fluid_temp);
+    }
+
+    /// fluid dynamic viscosity based on temperature,
+    fn viscosity(&self,
+                 fluid_temp: ThermodynamicTemperature) -> DynamicViscosity{
+        return dowtherm_a_properties::getDowthermAViscosity(fluid_temp);
+    }
+
+    /// fluid enthalpy  based on temperature,
+    /// reference value is 0 J/kg at 20C
+    fn enthalpy(&self,
+                fluid_temp: ThermodynamicTemperature) -> AvailableEnergy{
+        return dowtherm_a_properties::getDowthermAEnthalpy(fluid_temp);
+    }
+
+    /// fluid specific heat capacity  based on temperature,
+    fn specific_heat_capacity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity{
+        return dowtherm_a_properties::
+            getDowthermAConstantPressureSpecificHeatCapacity(fluid_temp);
+    }
+
+    /// fluid thermal conductivity based on temperature,
+    fn thermal_conductivity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity{
+        return dowtherm_a_properties::
+            getDowthermAThermalConductivity(fluid_temp);
+    }
+
+    /// fluid temperature based on fluid enthalpy
+    fn get_temperature_from_enthalpy(
+        &self,
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature{
+        return dowtherm_a_properties::
+            get_temperature_from_enthalpy(fluid_enthalpy);
+    }
+
+    /// fluid thermal expansion coefficient based on temperature,
+    fn thermal_expansion_coefficient(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> TemperatureCoefficient{
+        return dowtherm_a_properties::
+            getDowthermAThermalExpansionCoefficient(fluid_temp);
+    }
+
+    /// the correlations are valid from 20C
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    }
+
+    /// the correlations are valid up to 180C
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<degree_celsius>(180.0);
+    }
+}
+
+/// error returned by the try_ methods of DowthermAProperties
+/// when the temperature is outside the correlation range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DowthermRangeError {
+    /// the temperature which was out of range
+    pub fluid_temp: ThermodynamicTemperature,
+    /// lowest valid temperature (20C)
+    pub min_temp: ThermodynamicTemperature,
+    /// highest valid temperature (180C)
+    pub max_temp: ThermodynamicTemperature,
+}
+
+impl std::fmt::Display for DowthermRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dowtherm A temperature {} C is outside the valid \
+               range of {} C to {} C",
+               self.fluid_temp.get::<degree_celsius>(),
+               self.min_temp.get::<degree_celsius>(),
+               self.max_temp.get::<degree_celsius>())
+    }
+}
+
+impl std::error::Error for DowthermRangeError {}
+
+impl Default for DowthermAProperties {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl DowthermAProperties {
+
+    /// constructor
+    pub fn new() -> Self {
+        return Self {  };
+    }
+
+    /// returns an error if the fluid temperature is outside
+    /// 20C to 180C, instead of panicking like rangeCheck
+    pub fn try_range_check(&self, fluid_temp: ThermodynamicTemperature) 
+        -> Result<(), DowthermRangeError> {
+
+        if !self.is_temp_valid(fluid_temp) {
+            return Err(DowthermRangeError {
+                fluid_temp,
+                min_temp: self.min_temp(),
+                max_temp: self.max_temp(),
+            });
+        }
+
+        return Ok(());
+    }
+
+    /// fluid density based on temperature,
+    /// returns an error instead of panicking if out of range
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::thermodynamic_temperature::degree_celsius;
+    /// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    ///
+    /// let dowtherm_props = DowthermAProperties::new();
+    ///
+    /// let density_result = dowtherm_props.try_density(
+    ///     ThermodynamicTemperature::new::<degree_celsius>(200.0));
+    ///
+    /// assert!(density_result.is_err());
+    /// ```
+    pub fn try_density(&self, fluid_temp: ThermodynamicTemperature) 
+        -> Result<MassDensity, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.density(fluid_temp));
+    }
+
+    /// fluid dynamic viscosity based on temperature,
+    /// returns an error instead of panicking if out of range
+    pub fn try_viscosity(&self, fluid_temp: ThermodynamicTemperature) 
+        -> Result<DynamicViscosity, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.viscosity(fluid_temp));
+    }
+
+    /// fluid enthalpy based on temperature,
+    /// returns an error instead of panicking if out of range
+    pub fn try_enthalpy(&self, fluid_temp: ThermodynamicTemperature) 
+        -> Result<AvailableEnergy, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.enthalpy(fluid_temp));
+    }
+
+    /// fluid specific heat capacity based on temperature,
+    /// returns an error instead of panicking if out of range
+    pub fn try_specific_heat_capacity(
+        &self, 
+        fluid_temp: ThermodynamicTemperature) 
+        -> Result<SpecificHeatCapacity, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.specific_heat_capacity(fluid_temp));
+    }
+
+    /// fluid thermal conductivity based on temperature,
+    /// returns an error instead of panicking if out of range
+    pub fn try_thermal_conductivity(
+        &self, 
+        fluid_temp: ThermodynamicTemperature) 
+        -> Result<ThermalConductivity, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.thermal_conductivity(fluid_temp));
+    }
+
+    /// fluid thermal expansion coefficient based on temperature,
+    /// returns an error instead of panicking if out of range
+    pub fn try_thermal_expansion_coefficient(
+        &self, 
+        fluid_temp: ThermodynamicTemperature) 
+        -> Result<TemperatureCoefficient, DowthermRangeError> {
+        self.try_range_check(fluid_temp)?;
+        return Ok(self.thermal_expansion_coefficient(fluid_temp));
+    }
+
+    /// fluid temperature based on a measured dynamic viscosity,
+    ///
+    /// the viscosity correlation is inverted numerically using 
+    /// Brent's method over 20C to 180C. If the viscosity is not 
+    /// reachable within that range, an error is returned
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::thermodynamic_temperature::degree_celsius;
+    /// use uom::si::dynamic_viscosity::pascal_second;
+    /// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    ///
+    /// let dowtherm_props = DowthermAProperties::new();
+    ///
+    /// // far too viscous for dowtherm A in range
+    /// let temperature_result = dowtherm_props.temperature_from_viscosity(
+    ///     DynamicViscosity::new::<pascal_second>(1.0));
+    ///
+    /// assert!(temperature_result.is_err());
+    /// ```
+    pub fn temperature_from_viscosity(&self, 
+                                      fluid_viscosity: DynamicViscosity) 
+        -> Result<ThermodynamicTemperature, String> {
+
+        let target_viscosity = fluid_viscosity.get::<pascal_second>();
+
+        let viscosity_root = |temp_degrees_c: f64| -> f64 {
+            let fluid_temp = 
+                ThermodynamicTemperature::new::<degree_celsius>(temp_degrees_c);
+            return self.viscosity(fluid_temp).get::<pascal_second>() 
+                - target_viscosity;
+        };
+
+        return Self::solve_temperature_in_range(
+            &viscosity_root, "viscosity");
+    }
+
+    /// fluid temperature based on a measured density,
+    ///
+    /// same as temperature_from_viscosity, but inverting 
+    /// the density correlation
+    pub fn temperature_from_density(&self, 
+                                    fluid_density: MassDensity) 
+        -> Result<ThermodynamicTemperature, String> {
+
+        let target_density = fluid_density.get::<kilogram_per_cubic_meter>();
+
+        let density_root = |temp_degrees_c: f64| -> f64 {
+            let fluid_temp = 
+                ThermodynamicTemperature::new::<degree_celsius>(temp_degrees_c);
+            return self.density(fluid_temp).get::<kilogram_per_cubic_meter>() 
+                - target_density;
+        };
+
+        return Self::solve_temperature_in_range(
+            &density_root, "density");
+    }
+
+    /// finds the temperature in degree celsius (between 20C and 180C)
+    /// where the property root function is zero 
+    fn solve_temperature_in_range(property_root: &dyn Fn(f64) -> f64,
+                                  property_name: &str) 
+        -> Result<ThermodynamicTemperature, String> {
+
+        let min_temp_degrees_c = 20.0;
+        let max_temp_degrees_c = 180.0;
+
+        // the brent method needs the root to be bracketed,
+        // otherwise the property is not reachable in range
+        if property_root(min_temp_degrees_c) * 
+            property_root(max_temp_degrees_c) > 0.0 {
+                return Err(format!("dowtherm A {} is not reachable \
+                                   between {}C and {}C",
+                                   property_name,
+                                   min_temp_degrees_c,
+                                   max_temp_degrees_c));
+            }
+
+        let mut convergency = 
+            SimpleConvergency { eps:1e-12f64, max_iter:100 };
+
+        let temperature_result = find_root_brent(
+            min_temp_degrees_c,
+            max_temp_degrees_c,
+            property_root,
+            &mut convergency);
+
+        match temperature_result {
+            Ok(temp_degrees_c) => 
+                return Ok(ThermodynamicTemperature::new::<degree_celsius>(
+                        temp_degrees_c)),
+            Err(error) => 
+                return Err(format!("dowtherm A {} inversion failed: {}", 
+                                   property_name, error)),
+        }
+    }
+}
+
+/// universal gas constant in J/(mol K)
+const UNIVERSAL_GAS_CONSTANT_JOULE_PER_MOLE_KELVIN: f64 = 8.314462618;
+
+/// Contains thermophysical property information for an ideal gas
+/// such as air
+///
+/// density comes from the ideal gas law at the reference pressure,
+///
+/// rho = P M / (R T)
+///
+/// viscosity comes from Sutherland's law,
+///
+/// mu = mu_ref (T/T_ref)^(3/2) (T_ref + S)/(T + S)
+///
+/// the specific heat capacity and Prandtl number are taken as 
+/// constant, so enthalpy is cp T (0 J/kg at 0 K) and thermal
+/// conductivity is mu cp / Pr
+///
+/// new() takes the Sutherland coefficients, cp and Prandtl number
+/// of the gas, air() is a preset for air
+///
+/// Example:
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::kelvin;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+///
+/// let air_properties = IdealGasProperties::air();
+/// let fluid_temp = ThermodynamicTemperature::new::<kelvin>(300.0);
+///
+/// let density = air_properties.density(fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(1.177, 
+/// density.get::<kilogram_per_cubic_meter>(), 
+/// max_relative=0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdealGasProperties {
+    /// molar mass of the gas
+    pub molar_mass: MolarMass,
+    /// pressure at which density is evaluated
+    pub reference_pressure: Pressure,
+    /// constant pressure specific heat capacity, taken as constant
+    pub specific_heat_capacity: SpecificHeatCapacity,
+    /// Prandtl number, taken as constant
+    pub prandtl_number: f64,
+    /// viscosity at the Sutherland reference temperature
+    pub sutherland_reference_viscosity: DynamicViscosity,
+    /// Sutherland reference temperature
+    pub sutherland_reference_temp: ThermodynamicTemperature,
+    /// Sutherland constant S in kelvin
+    pub sutherland_constant_kelvin: f64,
+}
+
+impl FluidProperties for IdealGasProperties {
+    /// fluid density based on temperature,
+    /// at the reference pressure
+    fn density(&self,
+               fluid_temp: ThermodynamicTemperature) -> MassDensity{
+        return self.density_at_pressure(self.reference_pressure, 
+                                        fluid_temp);
+    }
+
+    /// fluid dynamic viscosity based on temperature,
+    /// using Sutherland's law
+    fn viscosity(&self,
+                 fluid_temp: ThermodynamicTemperature) -> DynamicViscosity{
+
+        Self::ideal_gas_range_check(fluid_temp);
+
+        let temp_kelvin_value = fluid_temp.get::<kelvin>();
+        let reference_temp_kelvin_value = 
+            self.sutherland_reference_temp.get::<kelvin>();
+        let sutherland_constant = self.sutherland_constant_kelvin;
+
+        let viscosity_ratio = 
+            (temp_kelvin_value/reference_temp_kelvin_value).powf(1.5)
+            * (reference_temp_kelvin_value + sutherland_constant)
+            / (temp_kelvin_value + sutherland_constant);
+
+        return self.sutherland_reference_viscosity * viscosity_ratio;
+    }
+
+    /// fluid enthalpy based on temperature,
+    /// reference value is 0 J/kg at 0 K
+    fn enthalpy(&self,
+                fluid_temp: ThermodynamicTemperature) -> AvailableEnergy{
+
+        Self::ideal_gas_range_check(fluid_temp);
+
+        let enthalpy_value_joule_per_kg = 
+            self.specific_heat_capacity.get::<joule_per_kilogram_kelvin>()
+            * fluid_temp.get::<kelvin>();
+
+        return AvailableEnergy::new::<joule_per_kilogram>(
+            enthalpy_value_joule_per_kg);
+    }
+
+    /// fluid specific heat capacity, which is constant
+    fn specific_heat_capacity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity{
+
+        Self::ideal_gas_range_check(fluid_temp);
+        return self.specific_heat_capacity;
+    }
+
+    /// fluid thermal conductivity based on temperature,
+    /// k = mu cp / Pr
+    fn thermal_conductivity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity{
+
+        let thermal_conductivity: ThermalConductivity = 
+            self.viscosity(fluid_temp)
+            * self.specific_heat_capacity(fluid_temp)
+            / self.prandtl_number;
+
+        return thermal_conductivity;
+    }
+
+    /// fluid temperature based on fluid enthalpy,
+    /// T = H/cp since cp is constant
+    fn get_temperature_from_enthalpy(
+        &self,
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature{
+
+        if fluid_enthalpy.value <= 0.0 {
+            panic!("ideal gas : get_temperature_from_enthalpy \n
+               enthalpy <= 0.0 , nonphysical");
+        }
+
+        let temp_kelvin_value = 
+            fluid_enthalpy.get::<joule_per_kilogram>()
+            / self.specific_heat_capacity.get::<joule_per_kilogram_kelvin>();
+
+        return ThermodynamicTemperature::new::<kelvin>(temp_kelvin_value);
+    }
+
+    /// for an ideal gas, beta = 1/T
+    fn thermal_expansion_coefficient(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> TemperatureCoefficient{
+
+        Self::ideal_gas_range_check(fluid_temp);
+
+        return TemperatureCoefficient::new::<
+            uom::si::temperature_coefficient::per_kelvin>(
+                1.0/fluid_temp.get::<kelvin>());
+    }
+
+    /// the correlations are valid for any temperature above 
+    /// absolute zero, see ideal_gas_range_check
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<kelvin>(f64::MIN_POSITIVE);
+    }
+}
+
+impl IdealGasProperties {
+
+    /// constructor, takes the molar mass of the gas, the 
+    /// pressure at which density is evaluated, the constant cp 
+    /// and Prandtl number, and the Sutherland coefficients 
+    /// (mu_ref at T_ref, and S in kelvin) of the gas
+    ///
+    /// use air() for air
+    pub fn new(molar_mass: MolarMass, 
+               reference_pressure: Pressure,
+               specific_heat_capacity: SpecificHeatCapacity,
+               prandtl_number: f64,
+               sutherland_reference_viscosity: DynamicViscosity,
+               sutherland_reference_temp: ThermodynamicTemperature,
+               sutherland_constant_kelvin: f64) -> Self {
+
+        if molar_mass.value <= 0.0 {
+            panic!("molar mass <= 0.0, nonphysical");
+        }
+
+        if reference_pressure.value <= 0.0 {
+            panic!("reference pressure <= 0.0, nonphysical");
+        }
+
+        if specific_heat_capacity.value <= 0.0 {
+            panic!("specific heat capacity <= 0.0, nonphysical");
+        }
+
+        if prandtl_number <= 0.0 {
+            panic!("prandtl number <= 0.0, nonphysical");
+        }
+
+        if sutherland_reference_viscosity.value <= 0.0 {
+            panic!("sutherland reference viscosity <= 0.0, nonphysical");
+        }
+
+        Self::ideal_gas_range_check(sutherland_reference_temp);
+
+        if sutherland_constant_kelvin < 0.0 {
+            panic!("sutherland constant < 0.0, nonphysical");
+        }
+
+        return Self {
+            molar_mass,
+            reference_pressure,
+            specific_heat_capacity,
+            prandtl_number,
+            sutherland_reference_viscosity,
+            sutherland_reference_temp,
+            sutherland_constant_kelvin,
+        };
+    }
+
+    /// dry air (M = 28.9647 g/mol) at 1 atm
+    ///
+    /// Sutherland coefficients (mu_ref = 1.716e-5 Pa s, 
+    /// T_ref = 273.15 K, S = 110.4 K), cp = 1005 J/(kg K) and 
+    /// Pr = 0.71
+    pub fn air() -> Self {
+        return Self::new(
+            MolarMass::new::<uom::si::molar_mass::gram_per_mole>(28.9647),
+            Pressure::new::<uom::si::pressure::pascal>(101325.0),
+            SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1005.0),
+            0.71,
+            DynamicViscosity::new::<pascal_second>(1.716e-5),
+            ThermodynamicTemperature::new::<kelvin>(273.15),
+            110.4);
+    }
+
+    /// fluid density from the ideal gas law at any pressure,
+    ///
+    /// rho = P M / (R T)
+    pub fn density_at_pressure(&self,
+                               pressure: Pressure,
+                               fluid_temp: ThermodynamicTemperature) 
+        -> MassDensity {
+
+        Self::ideal_gas_range_check(fluid_temp);
+
+        if pressure.value <= 0.0 {
+            panic!("absolute pressure <= 0.0, nonphysical");
+        }
+
+        let density_value_kg_per_m3 = 
+            pressure.get::<uom::si::pressure::pascal>()
+            * self.molar_mass.get::<uom::si::molar_mass::kilogram_per_mole>()
+            / UNIVERSAL_GAS_CONSTANT_JOULE_PER_MOLE_KELVIN
+            / fluid_temp.get::<kelvin>();
+
+        return MassDensity::new::<kilogram_per_cubic_meter>(
+            density_value_kg_per_m3);
+    }
+
+    /// the ideal gas correlations work for any temperature 
+    /// above absolute zero, anything else panics
+    pub fn ideal_gas_range_check(fluid_temp: ThermodynamicTemperature) -> bool {
+
+        if fluid_temp.get::<kelvin>() <= 0.0 {
+            panic!("ideal gas : fluid temperature {:?} <= 0 K, \
+                   nonphysical", fluid_temp);
+        }
+
+        return true;
+    }
+}
+
+/// Contains thermophysical property information for 
+/// the molten salt FLiBe (2LiF-BeF2), 
+/// valid from 732 K (just above the melting point) to 1000 K
+///
+/// the correlations are those recommended in the review by
+/// Romatoski and Hu (2017), Annals of Nuclear Energy 109:
+///
+/// rho = 2413 - 0.488 T kg/m3 (Janz)
+///
+/// mu = 1.16e-4 exp(3755/T) Pa s (Cantor)
+///
+/// cp = 2386 J/(kg K), k = 1.1 W/(m K), both taken as constant
+///
+/// where T is in kelvin. Temperatures outside the range panic,
+/// like the other fluids in the property library
+///
+/// Example:
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+///
+/// let flibe_properties = FlibeProperties::new();
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(700.0);
+///
+/// let density = flibe_properties.density(fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(1938.2, 
+/// density.get::<kilogram_per_cubic_meter>(), 
+/// max_relative=0.001);
+/// ```
+pub struct FlibeProperties {
+
+}
+
+impl FluidProperties for FlibeProperties {
+    /// fluid density based on temperature,
+    ///
+    /// rho = 2413 - 0.488 T kg/m3, T in kelvin
+    fn density(&self,
+               fluid_temp: ThermodynamicTemperature) -> MassDensity{
+
+        Self::flibe_range_check(fluid_temp);
+
+        let density_value_kg_per_m3 = 
+            2413.0 - 0.488 * fluid_temp.get::<kelvin>();
+
+        return MassDensity::new::<kilogram_per_cubic_meter>(
+            density_value_kg_per_m3);
+    }
+
+    /// fluid dynamic viscosity based on temperature,
+    ///
+    /// mu = 1.16e-4 exp(3755/T) Pa s, T in kelvin
+    fn viscosity(&self,
+                 fluid_temp: ThermodynamicTemperature) -> DynamicViscosity{
+
+        Self::flibe_range_check(fluid_temp);
+
+        let viscosity_value_pa_s = 1.16e-4 * 
+            (3755.0/fluid_temp.get::<kelvin>()).exp();
+
+        return DynamicViscosity::new::<pascal_second>(viscosity_value_pa_s);
+    }
+
+    /// fluid enthalpy based on temperature,
+    ///
+    /// cp is constant, so H = cp (T - 732 K), ie. i take 
+    /// the reference value to be 0 J/kg at 732 K
+    fn enthalpy(&self,
+                fluid_temp: ThermodynamicTemperature) -> AvailableEnergy{
+
+        Self::flibe_range_check(fluid_temp);
+
+        let enthalpy_value_joule_per_kg = FLIBE_CP_JOULE_PER_KG_KELVIN 
+            * (fluid_temp.get::<kelvin>() - FLIBE_MIN_TEMP_KELVIN);
+
+        return AvailableEnergy::new::<joule_per_kilogram>(
+            enthalpy_value_joule_per_kg);
+    }
+
+    /// fluid specific heat capacity, 2386 J/(kg K)
+    fn specific_heat_capacity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity{
+
+        Self::flibe_range_check(fluid_temp);
+
+        return SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            FLIBE_CP_JOULE_PER_KG_KELVIN);
+    }
+
+    /// fluid thermal conductivity, 1.1 W/(m K)
+    fn thermal_conductivity(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity{
+
+        Self::flibe_range_check(fluid_temp);
+
+        return ThermalConductivity::new::<watt_per_meter_kelvin>(1.1);
+    }
+
+    /// fluid temperature based on fluid enthalpy,
+    /// T = 732 K + H/cp since cp is constant
+    fn get_temperature_from_enthalpy(
+        &self,
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature{
+
+        let temp_kelvin_value = FLIBE_MIN_TEMP_KELVIN + 
+            fluid_enthalpy.get::<joule_per_kilogram>()
+            / FLIBE_CP_JOULE_PER_KG_KELVIN;
+
+        let fluid_temp = ThermodynamicTemperature::new::<kelvin>(
+            temp_kelvin_value);
+
+        Self::flibe_range_check(fluid_temp);
+
+        return fluid_temp;
+    }
+
+    /// beta = 0.488/rho from the linear density correlation
+    fn thermal_expansion_coefficient(
+        &self,
+        fluid_temp: ThermodynamicTemperature) -> TemperatureCoefficient{
+
+        let density_value_kg_per_m3 = 
+            self.density(fluid_temp).get::<kilogram_per_cubic_meter>();
+
+        return TemperatureCoefficient::new::<
+            uom::si::temperature_coefficient::per_kelvin>(
+                0.488/density_value_kg_per_m3);
+    }
+
+    /// the correlations are valid from 732 K
+    fn min_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<kelvin>(
+            FLIBE_MIN_TEMP_KELVIN);
+    }
+
+    /// the correlations are valid up to 1000 K
+    fn max_temp(&self) -> ThermodynamicTemperature {
+        return ThermodynamicTemperature::new::<kelvin>(
+            FLIBE_MAX_TEMP_KELVIN);
+    }
+}
+
+/// lower limit of the FLiBe correlations, just above 
+/// the melting point (about 732 K)
+const FLIBE_MIN_TEMP_KELVIN: f64 = 732.0;
+
+/// upper limit of the FLiBe correlations
+const FLIBE_MAX_TEMP_KELVIN: f64 = 1000.0;
+
+/// FLiBe specific heat capacity, taken as constant
+const FLIBE_CP_JOULE_PER_KG_KELVIN: f64 = 2386.0;
+
+impl Default for FlibeProperties {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl FlibeProperties {
+
+    /// constructor
+    pub fn new() -> Self {
+        return Self {  };
+    }
+
+    /// the correlation has temperature range from 732 K to 1000 K,
+    /// anything outside this and the code throws a panic
+    pub fn flibe_range_check(fluid_temp: ThermodynamicTemperature) -> bool{
+
+        let temp_value_kelvin = fluid_temp.get::<kelvin>();
+
+        if temp_value_kelvin < FLIBE_MIN_TEMP_KELVIN {
+            panic!("Your fluid temperature \n is too low :{:?}K \n
+                   \n the minimum is 732 K (FLiBe freezes at about 732 K)",
+                   fluid_temp);
+        }
+
+        if temp_value_kelvin > FLIBE_MAX_TEMP_KELVIN {
+            panic!("Your fluid temperature \n is too high :{:?}K \n
+                   \n the max is 1000 K",
+                   fluid_temp);
+        }
+
+        return true;
+    }
+}
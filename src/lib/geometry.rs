@@ -0,0 +1,198 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are helpers to calculate hydraulic diameter for 
+// non circular ducts, D_h = 4A/P
+//
+// these go together with the CustomComponentProperties
+// which store hydraulic diameter and cross sectional area separately
+//
+// there is also a helper to get a component's incline angle from 
+// its 3D direction, for piping layouts given in 3D coordinates
+
+use uom::si::f64::*;
+
+/// calculates hydraulic diameter from cross sectional area 
+/// and wetted perimeter
+///
+/// D_h = 4A/P
+///
+/// ```rust
+/// use fluid_mechanics_rust::geometry::*;
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+///
+/// // circular pipe of diameter 0.1 m
+/// let pipe_diameter = Length::new::<meter>(0.1);
+/// let area = Area::new::<square_meter>(std::f64::consts::PI/4.0 * 0.01);
+/// let wetted_perimeter = pipe_diameter * std::f64::consts::PI;
+///
+/// let hydraulic_diameter = 
+///     hydraulic_diameter_from_area_perimeter(area, wetted_perimeter);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(hydraulic_diameter.get::<meter>(), 0.1,
+///     max_relative = 1e-12);
+/// ```
+pub fn hydraulic_diameter_from_area_perimeter(area: Area,
+                                              wetted_perimeter: Length) 
+    -> Length {
+
+    if area.value <= 0.0 {
+        panic!("area <= 0.0, nonphysical");
+    }
+
+    if wetted_perimeter.value <= 0.0 {
+        panic!("wetted perimeter <= 0.0, nonphysical");
+    }
+
+    return 4.0 * area / wetted_perimeter;
+}
+
+/// calculates hydraulic diameter of a rectangular duct
+///
+/// D_h = 4 (w h) / (2 (w + h)) = 2 w h / (w + h)
+///
+/// for a square duct, D_h is the side length
+pub fn hydraulic_diameter_rectangular(width: Length,
+                                      height: Length) -> Length {
+
+    if width.value <= 0.0 {
+        panic!("width <= 0.0, nonphysical");
+    }
+
+    if height.value <= 0.0 {
+        panic!("height <= 0.0, nonphysical");
+    }
+
+    let area: Area = width * height;
+    let wetted_perimeter: Length = 2.0 * (width + height);
+
+    return hydraulic_diameter_from_area_perimeter(area, wetted_perimeter);
+}
+
+/// calculates hydraulic diameter of an annulus, given the 
+/// outer and inner diameters (not radii)
+///
+/// D_h = 4 (pi/4) (D_o^2 - D_i^2) / (pi (D_o + D_i)) = D_o - D_i
+///
+/// which is twice the radial gap
+pub fn hydraulic_diameter_annulus(outer: Length,
+                                  inner: Length) -> Length {
+
+    if inner.value < 0.0 {
+        panic!("inner diameter < 0.0, nonphysical");
+    }
+
+    if outer <= inner {
+        panic!("outer diameter <= inner diameter, nonphysical");
+    }
+
+    let area: Area = std::f64::consts::PI/4.0 * 
+        (outer * outer - inner * inner);
+    let wetted_perimeter: Length = std::f64::consts::PI * (outer + inner);
+
+    return hydraulic_diameter_from_area_perimeter(area, wetted_perimeter);
+}
+
+/// calculates the incline angle of a component from its 3D 
+/// flow direction and the global gravity vector
+///
+/// sin(incline_angle) = - (direction . gravity)/(|direction| |gravity|)
+///
+/// so a component pointing against gravity (upwards) has an 
+/// incline angle of +90 degrees, and one pointing along gravity 
+/// (downwards) has -90 degrees, the same sign convention as the 
+/// incline angle used in the hydrostatic pressure change. 
+/// Horizontal components (perpendicular to gravity) have 0 degrees.
+///
+/// neither vector needs to be a unit vector, eg. gravity can be
+/// [0.0, 0.0, -9.81], but both must be nonzero (or this panics)
+///
+/// ```rust
+/// use fluid_mechanics_rust::geometry::*;
+/// use uom::si::angle::degree;
+///
+/// // a pipe rising 1 m for every 1 m across, with z pointing up
+/// let incline_angle = effective_incline_angle(
+///     [1.0, 0.0, 1.0], [0.0, 0.0, -9.81]);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(incline_angle.get::<degree>(), 45.0,
+///     max_relative = 1e-12);
+/// ```
+pub fn effective_incline_angle(direction: [f64; 3],
+                               gravity: [f64; 3]) -> Angle {
+
+    let direction_magnitude = (direction[0] * direction[0] 
+        + direction[1] * direction[1] 
+        + direction[2] * direction[2]).sqrt();
+
+    let gravity_magnitude = (gravity[0] * gravity[0] 
+        + gravity[1] * gravity[1] 
+        + gravity[2] * gravity[2]).sqrt();
+
+    if direction_magnitude == 0.0 {
+        panic!("direction vector has zero length, incline is undefined");
+    }
+
+    if gravity_magnitude == 0.0 {
+        panic!("gravity vector has zero length, incline is undefined");
+    }
+
+    let dot_product = direction[0] * gravity[0] 
+        + direction[1] * gravity[1] 
+        + direction[2] * gravity[2];
+
+    // clamped in case rounding takes this slightly beyond 1
+    let sine_incline_angle = (-dot_product 
+        / direction_magnitude / gravity_magnitude).clamp(-1.0, 1.0);
+
+    return Angle::new::<uom::si::angle::radian>(sine_incline_angle.asin());
+}
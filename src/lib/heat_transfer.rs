@@ -0,0 +1,204 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are Nusselt number correlations for forced convection in pipes,
+// these go together with the prandtl number from 
+// ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions
+// and the churchill friction factor
+
+use crate::churchill_friction_factor;
+use uom::si::f64::*;
+
+/// Reynolds number below which pipe flow is taken to be laminar
+/// for the Nusselt correlations
+pub const LAMINAR_NUSSELT_REYNOLDS_LIMIT: f64 = 2300.0;
+
+/// Nusselt number for fully developed laminar flow in a circular 
+/// pipe with constant wall temperature
+pub const LAMINAR_CONSTANT_WALL_TEMP_NUSSELT: f64 = 3.66;
+
+/// calculates Nusselt number using the Dittus-Boelter correlation
+///
+/// Nu = 0.023 Re^0.8 Pr^n
+///
+/// n = 0.4 if the fluid is being heated (heating = true)
+/// n = 0.3 if the fluid is being cooled (heating = false)
+///
+/// This is meant for fully developed turbulent flow, 
+/// roughly Re > 10,000 and 0.6 < Pr < 160.
+/// I do not check the range here, so you are responsible
+/// for making sure it is applicable
+///
+/// ```rust
+/// use fluid_mechanics_rust::heat_transfer::*;
+///
+/// let nusselt_number = dittus_boelter_nusselt(1.0e4, 7.0, true);
+///
+/// // Nu = 0.023 * (1e4)^0.8 * 7^0.4 which is about 79.4
+/// extern crate approx;
+/// approx::assert_relative_eq!(nusselt_number, 79.4, max_relative = 0.002);
+/// ```
+pub fn dittus_boelter_nusselt(reynolds: f64, 
+                              prandtl: f64, 
+                              heating: bool) -> f64 {
+
+    let prandtl_exponent = if heating { 0.4 } else { 0.3 };
+
+    return 0.023 * reynolds.powf(0.8) * prandtl.powf(prandtl_exponent);
+}
+
+/// calculates Nusselt number using the Gnielinski correlation
+///
+/// Nu = (f/8) (Re - 1000) Pr / [1 + 12.7 (f/8)^0.5 (Pr^(2/3) - 1)]
+///
+/// f is the darcy friction factor, you can get it from 
+/// churchill_friction_factor::darcy, or just use 
+/// gnielinski_nusselt_churchill which does it for you
+///
+/// For Re < 2300, the flow is laminar and the correlation does not
+/// apply, so i return the laminar value of Nu = 3.66 
+/// (fully developed, constant wall temperature)
+///
+/// ```rust
+/// use fluid_mechanics_rust::heat_transfer::*;
+///
+/// let nusselt_number = gnielinski_nusselt(1000.0, 7.0, 0.064);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(nusselt_number, 3.66);
+/// ```
+pub fn gnielinski_nusselt(reynolds: f64, 
+                          prandtl: f64, 
+                          darcy_friction_factor: f64) -> f64 {
+
+    if reynolds < LAMINAR_NUSSELT_REYNOLDS_LIMIT {
+        return LAMINAR_CONSTANT_WALL_TEMP_NUSSELT;
+    }
+
+    let f_over_8 = darcy_friction_factor/8.0;
+
+    let numerator = f_over_8 * (reynolds - 1000.0) * prandtl;
+    let denominator = 1.0 + 12.7 * f_over_8.sqrt() 
+        * (prandtl.powf(2.0/3.0) - 1.0);
+
+    return numerator/denominator;
+}
+
+/// calculates Nusselt number using the Gnielinski correlation,
+/// where the darcy friction factor comes from the churchill 
+/// correlation (churchill_friction_factor::darcy)
+///
+/// ```rust
+/// use fluid_mechanics_rust::heat_transfer::*;
+/// use fluid_mechanics_rust::churchill_friction_factor;
+///
+/// let reynolds = 1.0e4;
+/// let prandtl = 7.0;
+/// let roughness_ratio = 0.0;
+///
+/// let darcy = churchill_friction_factor::darcy(reynolds, roughness_ratio);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     gnielinski_nusselt_churchill(reynolds, prandtl, roughness_ratio),
+///     gnielinski_nusselt(reynolds, prandtl, darcy));
+/// ```
+pub fn gnielinski_nusselt_churchill(reynolds: f64,
+                                    prandtl: f64,
+                                    roughness_ratio: f64) -> f64 {
+
+    if reynolds < LAMINAR_NUSSELT_REYNOLDS_LIMIT {
+        return LAMINAR_CONSTANT_WALL_TEMP_NUSSELT;
+    }
+
+    let darcy_friction_factor = 
+        churchill_friction_factor::darcy(reynolds, roughness_ratio);
+
+    return gnielinski_nusselt(reynolds, prandtl, darcy_friction_factor);
+}
+
+/// calculates the convective heat transfer coefficient
+/// from the Nusselt number
+///
+/// h = Nu k / D
+///
+/// k is the fluid thermal conductivity
+/// D is the hydraulic diameter
+///
+/// the units are handled by uom, so you get a HeatTransfer 
+/// quantity back, which you can convert to W/(m^2 K) or whatever you like
+///
+/// ```rust
+/// use fluid_mechanics_rust::heat_transfer::*;
+/// use uom::si::f64::*;
+/// use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+/// use uom::si::length::meter;
+/// use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+///
+/// let thermal_conductivity = 
+///     ThermalConductivity::new::<watt_per_meter_kelvin>(0.6);
+/// let hydraulic_diameter = Length::new::<meter>(0.05);
+///
+/// let h = heat_transfer_coefficient(100.0, 
+///     thermal_conductivity, 
+///     hydraulic_diameter);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(h.get::<watt_per_square_meter_kelvin>(), 
+///     1200.0, max_relative = 1e-12);
+/// ```
+pub fn heat_transfer_coefficient(nusselt: f64,
+                                 thermal_conductivity: ThermalConductivity,
+                                 hydraulic_diameter: Length) -> HeatTransfer {
+
+    if hydraulic_diameter.value <= 0.0 {
+        panic!("heat_transfer_coefficient: hydraulic diameter must be > 0");
+    }
+
+    return nusselt * thermal_conductivity / hydraulic_diameter;
+}
@@ -0,0 +1,170 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is csv import and export for pipe properties
+
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use uom::si::f64::*;
+use uom::si::length::{meter, millimeter};
+use uom::si::angle::degree;
+use uom::si::pressure::pascal;
+
+use crate::therminol_component::PipeProperties;
+
+/// header row of the pipe csv files, the columns are in this order
+pub const PIPE_CSV_HEADER: &str = 
+    "name,diameter_m,length_m,roughness_mm,angle_deg,form_loss_k";
+
+/// reads pipes from a csv file, eg. a loop geometry kept in a 
+/// spreadsheet
+///
+/// the first row must be the header:
+///
+/// name,diameter_m,length_m,roughness_mm,angle_deg,form_loss_k
+///
+/// and each row after that is one pipe. Blank lines are skipped.
+/// The internal pressure is not in the file, so it is set to zero.
+///
+/// this is a plain comma separated parser, quoted fields 
+/// are not supported, so names cannot contain commas
+///
+/// a missing column or a number which can't be parsed gives an 
+/// error of kind InvalidData
+pub fn import_pipes_from_csv(path: &Path) 
+    -> Result<Vec<PipeProperties>, Error> {
+
+    let file_contents = fs::read_to_string(path)?;
+
+    let mut lines = file_contents.lines();
+
+    match lines.next() {
+        Some(header) if header.trim() == PIPE_CSV_HEADER => (),
+        _ => return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("pipe csv header must be: {}", PIPE_CSV_HEADER))),
+    }
+
+    let mut pipes: Vec<PipeProperties> = vec![];
+
+    for (line_index, line) in lines.enumerate() {
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // the header is line 1, so the first pipe is on line 2
+        let line_number = line_index + 2;
+
+        let fields: Vec<&str> = line.split(',').map(|field| field.trim())
+            .collect();
+
+        if fields.len() != 6 {
+            return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("line {}: expected 6 columns, found {}", 
+                            line_number, fields.len())));
+        }
+
+        let parse_field = |column: usize| -> Result<f64, Error> {
+            return fields[column].parse::<f64>().map_err(|_| Error::new(
+                    ErrorKind::InvalidData,
+                    format!("line {}: could not parse {} as a number", 
+                            line_number, fields[column])));
+        };
+
+        pipes.push(PipeProperties {
+            _name: fields[0].to_string(),
+            hydraulic_diameter: Length::new::<meter>(parse_field(1)?),
+            component_length: Length::new::<meter>(parse_field(2)?),
+            absolute_roughness: Length::new::<millimeter>(parse_field(3)?),
+            incline_angle: Angle::new::<degree>(parse_field(4)?),
+            form_loss_k: parse_field(5)?,
+            internal_pressure: Pressure::new::<pascal>(0.0),
+        });
+    }
+
+    return Ok(pipes);
+}
+
+/// writes pipes to a csv file in the format read by 
+/// import_pipes_from_csv
+///
+/// the internal pressure is not written. Names containing commas 
+/// or line breaks give an error of kind InvalidInput, since they 
+/// can't be read back
+pub fn export_pipes_to_csv(path: &Path, pipes: &[PipeProperties]) 
+    -> Result<(), Error> {
+
+    let mut file_contents = String::from(PIPE_CSV_HEADER);
+    file_contents.push('\n');
+
+    for pipe in pipes.iter() {
+
+        if pipe._name.contains(',') || pipe._name.contains('\n') {
+            return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("pipe name {} contains a comma or line break", 
+                            pipe._name)));
+        }
+
+        // {:?} prints f64 so that it parses back exactly
+        file_contents.push_str(&format!(
+                "{},{:?},{:?},{:?},{:?},{:?}\n",
+                pipe._name,
+                pipe.hydraulic_diameter.get::<meter>(),
+                pipe.component_length.get::<meter>(),
+                pipe.absolute_roughness.get::<millimeter>(),
+                pipe.incline_angle.get::<degree>(),
+                pipe.form_loss_k));
+    }
+
+    return fs::write(path, file_contents);
+}
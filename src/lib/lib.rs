@@ -111,6 +111,9 @@ pub mod custom_fldk;
 /// which help you dimensionalise and nondimensionalise variables
 /// eg Reynold's number
 pub mod dimensionalisation;
+/// contains conversions between darcy and fanning friction 
+/// factors, and between Bejan and Euler numbers
+pub mod conversions;
 /// Contains structs or classes which
 /// help you calculate pressure loss from mass 
 /// flowrate and vice versa for pipes and custom components
@@ -126,10 +129,61 @@ pub mod therminol_component;
 /// for fluid components in series or parallel
 pub mod fluid_component_collection;
 
+/// contains a graph representation of fluid components
+/// joined at nodes, for loops which are not purely 
+/// series or parallel
+pub mod flow_network;
+
+/// contains helpers for pump and system curves, 
+/// eg. the operating point of a pump connected to a loop
+pub mod system_curve;
+
+/// contains a quasi-steady time series driver, which steps 
+/// the mass flowrate through a collection given a pressure schedule
+pub mod transient;
+
 /// contains traits dealing with fluid thermophysical
 /// properties, more generically
 pub mod fluid_thermophysical_properties;
 
+/// contains Nusselt number correlations and other helpers
+/// for forced convection heat transfer in pipes
+pub mod heat_transfer;
+
+/// contains helpers to calculate hydraulic diameter
+/// for non circular ducts such as rectangular ducts and annuli,
+/// and incline angles from 3D flow directions
+pub mod geometry;
+
+/// contains helpers for developing flow, eg. entrance lengths 
+/// to check if flow in short components is fully developed
+pub mod developing_flow;
+
+/// contains helpers to calculate hydraulic pumping power
+/// from mass flowrate and pressure rise
+pub mod power;
+
+/// contains physical constants such as gravity, 
+/// which can be overridden for sensitivity studies
+pub mod physical_constants;
+
+/// contains conversions between pressure differences, which
+/// all other apis use, and absolute pressures
+pub mod pressure_reference;
+
+/// contains helpers for model/prototype similarity, 
+/// eg. matching Reynolds number in scaled experiments
+pub mod scaling;
+
+/// reads and writes pipe geometry from csv files, 
+/// eg. loop geometries kept in spreadsheets
+pub mod io;
+
+/// contains assertion helpers for uom quantities in tests,
+/// enable the test-util feature to use these in your own tests
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_utils;
+
 /// the prelude, meant to make starting your own fluid components easy to make
 pub mod prelude;
 
@@ -177,6 +231,83 @@ pub fn moody(ReynoldsNumber: f64, roughnessRatio: f64) -> f64 {
         moody(ReynoldsNumber, roughnessRatio);
 }
 
+/// This function calculates darcy friction factor
+/// using the haaland explicit approximation
+/// It takes in a Reynold's number and roughness ratio
+///
+/// For Re < 2300, 64/Re is used
+///
+/// However, Re = 0 will not work!
+/// ```rust
+/// let haaland_friction_factor = 
+///     fluid_mechanics_rust::haaland(15000.0,0.0015);
+///
+/// println!("{}", haaland_friction_factor);
+/// ```
+pub fn haaland(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    return churchill_friction_factor::
+        haaland_darcy(reynolds_number, roughness_ratio);
+}
+
+/// This function calculates darcy friction factor
+/// using the swamee jain explicit approximation
+/// It takes in a Reynold's number and roughness ratio
+///
+/// For Re < 2300, 64/Re is used
+///
+/// However, Re = 0 will not work!
+/// ```rust
+/// let swamee_jain_friction_factor = 
+///     fluid_mechanics_rust::swamee_jain(15000.0,0.0015);
+///
+/// println!("{}", swamee_jain_friction_factor);
+/// ```
+pub fn swamee_jain(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    return churchill_friction_factor::
+        swamee_jain_darcy(reynolds_number, roughness_ratio);
+}
+
+/// This function calculates darcy friction factor
+/// for smooth pipes using the blasius correlation
+/// f = 0.316 Re^(-0.25), 
+/// it takes in a Reynold's number only, roughness is ignored
+///
+/// For Re < 2300, 64/Re is used
+///
+/// It panics for Re > 1e5 where blasius isn't valid,
+/// and Re = 0 will not work either!
+/// ```rust
+/// let blasius_friction_factor = 
+///     fluid_mechanics_rust::blasius(15000.0);
+///
+/// println!("{}", blasius_friction_factor);
+/// ```
+pub fn blasius(reynolds_number: f64) -> f64 {
+    return churchill_friction_factor::
+        blasius_darcy(reynolds_number);
+}
+
+/// This function calculates darcy friction factor
+/// using the colebrook white equation
+/// It takes in a Reynold's number and roughness ratio
+///
+/// The colebrook equation is implicit in f, and is solved
+/// iteratively. For Re < 2300, 64/Re is used, and
+/// the transition region is blended as per churchill's
+/// correlation
+///
+/// However, Re = 0 will not work!
+/// ```rust
+/// let colebrook_friction_factor = 
+///     fluid_mechanics_rust::colebrook(15000.0,0.0015);
+///
+/// println!("{}", colebrook_friction_factor);
+/// ```
+pub fn colebrook(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    return churchill_friction_factor::
+        colebrook_darcy(reynolds_number, roughness_ratio);
+}
+
 /// This function calculates the fldk
 ///
 /// this is the
@@ -358,6 +489,35 @@ pub fn get_reynolds_number(Be_D: f64,
 
 }
 
+/// This function calculates Reynolds number given a Bejan 
+/// number, like get_reynolds_number, but returns an error 
+/// instead of panicking
+///
+/// The error tells you whether the inputs were invalid (or NaN),
+/// whether the Bejan number was too large to bracket the root
+/// or whether the root finder failed to converge
+///
+/// ```rust
+/// use fluid_mechanics_rust::churchill_friction_factor::ReynoldsSolveError;
+///
+/// let reynolds_number = fluid_mechanics_rust::try_get_reynolds_number(
+///     f64::NAN, 0.00014, 10.0, 5.0);
+///
+/// assert_eq!(reynolds_number, Err(ReynoldsSolveError::NotANumber));
+/// ```
+pub fn try_get_reynolds_number(bejan_number: f64,
+             roughness_ratio: f64,
+             length_to_diameter: f64,
+             form_loss_k: f64) -> Result<f64, 
+             churchill_friction_factor::ReynoldsSolveError> {
+    return churchill_friction_factor::
+        try_get_reynolds_number_with_smoothing(bejan_number, 
+                                               roughness_ratio,
+                                               length_to_diameter, 
+                                               form_loss_k,
+                                               false);
+}
+
 
 /// Custom Component is a struct or class
 /// which contains functions to allow users
@@ -539,6 +699,58 @@ impl CustomComponent {
                                          customK);
     }
 
+    /// Calculate fldk based on a custom K value, like fldk_pipe,
+    /// but returns an error instead of panicking
+    ///
+    /// fldk_pipe panics for Re <= 0.0, which crashes automated 
+    /// sweeps that pass through stagnation points. Here, 
+    /// Re = 0.0, Re < 0.0, NaN inputs, negative roughness ratios 
+    /// and L/D <= 0.0 return an error message instead
+    ///
+    ///```rust
+    ///fn custom_k(reynolds_number: f64) -> f64 {
+    ///    return 400.0 + 52000.0/reynolds_number;
+    ///}
+    ///
+    ///let custom_fldk = 
+    ///    fluid_mechanics_rust::CustomComponent::try_fldk_pipe(
+    ///        0.0,
+    ///        0.00014,
+    ///        10.0,
+    ///        &custom_k);
+    ///
+    ///assert!(custom_fldk.is_err());
+    ///```
+    pub fn try_fldk_pipe(reynolds_number: f64,
+                         roughness_ratio: f64,
+                         length_to_diameter_ratio: f64,
+                         custom_k: &dyn Fn(f64) -> f64) -> Result<f64, String> {
+
+        if reynolds_number.is_nan() 
+            || roughness_ratio.is_nan() 
+            || length_to_diameter_ratio.is_nan() {
+            return Err("fldk_pipe: input is NaN".to_string());
+        }
+
+        if length_to_diameter_ratio <= 0.0 {
+            return Err(format!("fldk_pipe: lengthToDiameterRatio = {} <= 0.0, \
+                               nonphysical", length_to_diameter_ratio));
+        }
+
+        // this checks Re = 0.0, Re < 0.0 and roughness ratio < 0.0
+        if let Err(friction_factor_error) = 
+            churchill_friction_factor::try_darcy(reynolds_number, 
+                                                 roughness_ratio) {
+            return Err(format!("fldk_pipe: {} (Re = {})", 
+                               friction_factor_error, reynolds_number));
+        }
+
+        return Ok(Self::fldk_pipe(reynolds_number,
+                                  roughness_ratio,
+                                  length_to_diameter_ratio,
+                                  custom_k));
+    }
+
     /// Calculate Bejan number based on a custom K value and
     /// standard pipe darcy friction factor correlations.
     ///
@@ -714,6 +926,91 @@ impl CustomComponent {
 
     }
 
+
+    /// Calculates pressure loss from mass flowrate for a component
+    /// with user defined darcy friction factor and form loss K
+    ///
+    /// this is a shortcut so you don't have to calculate Re 
+    /// and Be yourself, i nondimensionalise the mass flowrate into Re,
+    /// get the Bejan number using get_bejan_custom_fldk, and 
+    /// then dimensionalise Be back into pressure loss
+    ///
+    /// reverse flow logic is up to the custom f and K you supply
+    ///
+    ///```rust
+    ///use uom::si::f64::*;
+    ///use uom::si::mass_rate::kilogram_per_second;
+    ///use uom::si::dynamic_viscosity::pascal_second;
+    ///use uom::si::mass_density::kilogram_per_cubic_meter;
+    ///use uom::si::length::{meter,millimeter};
+    ///
+    ///fn custom_k(reynolds_number: f64) -> f64 {
+    ///    if reynolds_number < 0.0 {
+    ///        return -(400.0 + 52000.0/reynolds_number.abs());
+    ///    }
+    ///    return 400.0 + 52000.0/reynolds_number;
+    ///}
+    ///fn custom_f(_reynolds_number: f64,
+    ///            _roughness_ratio: f64) -> f64 {
+    ///    return 0.0;
+    ///}
+    ///
+    ///let pipe_diameter = Length::new::<meter>(2.79e-2);
+    ///let pipe_xs_area = pipe_diameter*pipe_diameter*std::f64::consts::PI/4.0;
+    ///
+    ///let pressure_loss = 
+    ///    fluid_mechanics_rust::CustomComponent::pressure_loss_from_mass_rate(
+    ///        &custom_f,
+    ///        &custom_k,
+    ///        MassRate::new::<kilogram_per_second>(0.05),
+    ///        pipe_xs_area,
+    ///        pipe_diameter,
+    ///        DynamicViscosity::new::<pascal_second>(0.001),
+    ///        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+    ///        Length::new::<meter>(0.5),
+    ///        Length::new::<millimeter>(0.001));
+    ///
+    ///assert!(pressure_loss.value > 0.0);
+    ///```
+    pub fn pressure_loss_from_mass_rate(
+        custom_darcy: &dyn Fn(f64, f64) -> f64,
+        custom_k: &dyn Fn(f64) -> f64,
+        mass_rate: MassRate,
+        cross_sectional_area: Area,
+        hydraulic_diameter: Length,
+        fluid_viscosity: DynamicViscosity,
+        fluid_density: MassDensity,
+        component_length: Length,
+        absolute_roughness: Length) -> Pressure {
+
+        let reynolds_number = dimensionalisation::CalcReynolds::from_mass_rate(
+            mass_rate,
+            cross_sectional_area,
+            hydraulic_diameter,
+            fluid_viscosity);
+
+        let roughness_ratio = dimensionalisation::relative_roughness(
+            absolute_roughness, 
+            hydraulic_diameter);
+
+        let length_to_diameter = 
+            dimensionalisation::convert_dimensionless_number_to_float(
+                component_length/hydraulic_diameter);
+
+        let bejan_number = Self::get_bejan_custom_fldk(
+            custom_darcy,
+            reynolds_number,
+            roughness_ratio,
+            length_to_diameter,
+            custom_k);
+
+        return dimensionalisation::CalcBejan::to_pressure(
+            bejan_number,
+            hydraulic_diameter,
+            fluid_density,
+            fluid_viscosity);
+    }
+
 }
 
 /// Contains functions which Calculate Re from mass flow rate 
@@ -781,9 +1078,7 @@ impl CalcReynolds {
     /// the cross sectional area is calculated using
     /// A_xs = pi * D^2/4
     /// 
-    /// As of the time of writing this doc, Oct 2022, I didn't 
-    /// implement calculating velocity from Re, as it wasn't necessary
-    /// for my project
+    /// To calculate velocity from Re, use CalcReynolds::to_velocity
     /// 
     ///```rust
     ///
@@ -872,6 +1167,47 @@ impl CalcReynolds {
             hydraulicDiameter,
             fluidViscosity);
     }
+
+    /// Calculates velocity from Re
+    ///
+    /// U = Re * mu / (rho * D)
+    ///
+    /// this is the reverse of CalcReynolds::from_velocity
+    ///
+    ///```rust
+    ///
+    ///use uom::si::dynamic_viscosity::pascal_second;
+    ///use uom::si::length::meter;
+    ///use uom::si::mass_density::kilogram_per_cubic_meter;
+    ///use uom::si::velocity::meter_per_second;
+    ///
+    ///use uom::si::f64::*;
+    ///
+    ///let pipe_diameter = Length::new::<meter>(2.79e-2);
+    ///let fluid_viscosity = DynamicViscosity::new::<pascal_second>(0.001);
+    ///let fluid_density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    ///
+    ///let fluid_velocity = fluid_mechanics_rust::CalcReynolds::to_velocity(
+    ///    1395.0,
+    ///    pipe_diameter,
+    ///    fluid_density,
+    ///    fluid_viscosity);
+    ///
+    ///extern crate approx;
+    ///approx::assert_relative_eq!(fluid_velocity.get::<meter_per_second>(),
+    ///    0.05, max_relative = 1e-12);
+    ///```
+    pub fn to_velocity(reynolds_number: f64,
+                       hydraulic_diameter: Length,
+                       fluid_density: MassDensity,
+                       fluid_viscosity: DynamicViscosity) -> Velocity {
+
+        return dimensionalisation::CalcReynolds::to_velocity(
+            reynolds_number,
+            hydraulic_diameter,
+            fluid_density,
+            fluid_viscosity);
+    }
 }
 
 /// Contains functions to nondimensionalise and dimensionalise
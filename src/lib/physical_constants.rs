@@ -0,0 +1,87 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are physical constants which are otherwise hardcoded,
+// bundled in a struct so they can be changed, eg. for off-Earth 
+// or sensitivity studies
+
+use uom::si::f64::*;
+use uom::si::acceleration::meter_per_second_squared;
+
+/// physical constants used in the pressure change calculations
+///
+/// the default gravity is 9.81 m/s^2, which is the standard 
+/// gravity used everywhere else in this crate, so calculations 
+/// with PhysicalConstants::default() are the same as those without
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::acceleration::meter_per_second_squared;
+/// use fluid_mechanics_rust::physical_constants::PhysicalConstants;
+///
+/// // gravity on the moon
+/// let lunar_constants = PhysicalConstants {
+///     gravity: Acceleration::new::<meter_per_second_squared>(1.62),
+/// };
+///
+/// assert!(lunar_constants.gravity < PhysicalConstants::default().gravity);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicalConstants {
+    /// magnitude of gravitational acceleration, 
+    /// acting downwards
+    pub gravity: Acceleration,
+}
+
+impl Default for PhysicalConstants {
+    fn default() -> Self {
+        return Self {
+            gravity: Acceleration::new::<meter_per_second_squared>(9.81),
+        };
+    }
+}
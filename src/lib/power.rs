@@ -0,0 +1,107 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are helpers to calculate hydraulic pumping power,
+// ie. the power imparted to the fluid by a pump,
+// for pump sizing and efficiency studies
+
+use uom::si::f64::*;
+
+/// calculates hydraulic pumping power from mass flowrate 
+/// and pressure rise across the pump
+///
+/// P = m * dp / rho
+///
+/// ie. volumetric flowrate times pressure rise
+///
+/// ```rust
+/// use fluid_mechanics_rust::power::*;
+/// use uom::si::f64::*;
+/// use uom::si::power::watt;
+/// use uom::si::pressure::kilopascal;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+///
+/// let hydraulic_power = pumping_power(
+///     MassRate::new::<kilogram_per_second>(1.0),
+///     Pressure::new::<kilopascal>(100.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0));
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(hydraulic_power.get::<watt>(), 100.0,
+///     max_relative = 1e-12);
+/// ```
+pub fn pumping_power(mass_flowrate: MassRate,
+                     pressure_rise: Pressure,
+                     density: MassDensity) -> Power {
+
+    if density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    return mass_flowrate * pressure_rise / density;
+}
+
+/// calculates shaft power needed by the pump, ie. the 
+/// hydraulic pumping power divided by pump efficiency
+///
+/// P_shaft = m * dp / (rho * efficiency)
+///
+/// efficiency is a fraction within (0,1], not a percentage
+pub fn pumping_power_with_efficiency(mass_flowrate: MassRate,
+                                     pressure_rise: Pressure,
+                                     density: MassDensity,
+                                     efficiency: f64) -> Power {
+
+    if efficiency <= 0.0 || efficiency > 1.0 {
+        panic!("pump efficiency must be within (0,1]");
+    }
+
+    return pumping_power(mass_flowrate, pressure_rise, density) 
+        / efficiency;
+}
@@ -0,0 +1,113 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a small layer to convert between pressure differences
+// and absolute pressures
+//
+// all pressures in this crate's existing apis (pressure loss, 
+// pressure change, internal pressure sources, hydrostatic 
+// pressure change) are pressure differences, ie. they don't
+// depend on what the absolute pressure is. This only matters 
+// when you couple to something which needs absolute pressure,
+// eg. ideal gas densities, so i keep the reference pressure here
+// rather than in the components
+
+use uom::si::f64::*;
+use uom::si::pressure::pascal;
+
+/// the reference pressure against which pressure differences 
+/// (gauge pressures) are measured
+///
+/// the default reference is one standard atmosphere, 101325 Pa,
+/// so that gauge pressures are relative to atmospheric pressure
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use fluid_mechanics_rust::pressure_reference::*;
+///
+/// // a loop pressurised to 2 bar absolute
+/// let loop_context = PressureContext {
+///     reference: Pressure::new::<pascal>(2.0e5),
+/// };
+///
+/// // pressure at the pump outlet, 5000 Pa above the reference
+/// let pump_outlet_pressure = to_absolute(
+///     Pressure::new::<pascal>(5000.0), &loop_context);
+///
+/// assert_eq!(pump_outlet_pressure.get::<pascal>(), 205000.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PressureContext {
+    /// absolute pressure at which the gauge pressure is zero
+    pub reference: Pressure,
+}
+
+impl Default for PressureContext {
+    fn default() -> Self {
+        return Self {
+            reference: Pressure::new::<pascal>(101325.0),
+        };
+    }
+}
+
+/// converts a pressure difference (gauge pressure) 
+/// into an absolute pressure,
+///
+/// absolute = reference + delta
+pub fn to_absolute(delta: Pressure, context: &PressureContext) -> Pressure {
+    return context.reference + delta;
+}
+
+/// converts an absolute pressure into a pressure difference 
+/// (gauge pressure) from the reference,
+///
+/// gauge = absolute - reference
+pub fn to_gauge(absolute: Pressure, context: &PressureContext) -> Pressure {
+    return absolute - context.reference;
+}
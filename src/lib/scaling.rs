@@ -0,0 +1,107 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are helpers for model/prototype similarity, ie. matching
+// dimensionless numbers between a scaled experiment and the 
+// prototype it represents
+
+use uom::si::f64::*;
+use crate::dimensionalisation;
+
+/// calculates the mass flowrate in a scaled model which gives 
+/// the same Reynolds number as the prototype
+///
+/// this inverts Re = m D / (A mu) for the model, 
+///
+/// m_model = Re_prototype * A_model * mu_model / D_model
+///
+/// the model density does not appear in Re when written in terms
+/// of mass flowrate, but it is still checked so that nonphysical
+/// model fluids are caught. Use 
+/// dimensionalisation::velocity_from_mass_rate with the model density
+/// if you want the model velocity instead
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::scaling::matching_mass_rate;
+///
+/// // Re = 10000 in a 1 cm tube of water
+/// let model_mass_rate = matching_mass_rate(
+///     10000.0,
+///     Length::new::<meter>(0.01),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0),
+///     Area::new::<square_meter>(0.25 * std::f64::consts::PI * 1.0e-4));
+///
+/// // m = Re A mu / D
+/// extern crate approx;
+/// approx::assert_relative_eq!(model_mass_rate.get::<kilogram_per_second>(), 
+///     10000.0 * 0.25 * std::f64::consts::PI * 1.0e-4 * 1.0e-3 / 0.01,
+///     max_relative = 1e-12);
+/// ```
+pub fn matching_mass_rate(prototype_re: f64,
+                          model_diameter: Length,
+                          model_density: MassDensity,
+                          model_viscosity: DynamicViscosity,
+                          model_area: Area) -> MassRate {
+
+    if model_density.value <= 0.0 {
+        panic!("fluidDensity <= 0.0, nonphysical");
+    }
+
+    return dimensionalisation::CalcReynolds::to_mass_rate(
+        model_area,
+        prototype_re,
+        model_diameter,
+        model_viscosity);
+}
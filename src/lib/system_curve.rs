@@ -0,0 +1,300 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are helpers for pump and system curves, ie. finding where
+// a pump operates when it is connected to a loop
+
+use uom::si::f64::*;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::pressure::pascal;
+use crate::fluid_component_calculation::FluidComponent;
+use crate::fluid_component_collection::FluidComponentCollectionMethods;
+use crate::fluid_component_calculation::find_mass_flowrate_root;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// evaluates the system resistance curve, ie. the pressure change
+/// across the collection at each given mass flowrate
+///
+/// this is meant for plotting the system curve before finding an
+/// operating point, so i just loop get_pressure_change over
+/// the mass flowrates, in the order given.
+/// Note that this is pressure change and not pressure loss, 
+/// so for a purely frictional system the pressure is negative for
+/// positive mass flowrate
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use fluid_mechanics_rust::fluid_component_collection::*;
+/// use fluid_mechanics_rust::system_curve::system_resistance_curve;
+///
+/// struct LinearResistance {}
+///
+/// impl FluidComponentCollectionMethods for LinearResistance {
+///     fn get_pressure_change(&self, 
+///         fluid_mass_flowrate: MassRate) -> Pressure {
+///         return Pressure::new::<pascal>(-fluid_mass_flowrate.value * 1000.0);
+///     }
+///
+///     fn get_mass_flowrate_from_pressure_change(&self,
+///         pressure_change: Pressure) -> MassRate {
+///         return MassRate::new::<kilogram_per_second>(
+///             -pressure_change.value / 1000.0);
+///     }
+/// }
+///
+/// let mass_rates = vec![
+///     MassRate::new::<kilogram_per_second>(0.0),
+///     MassRate::new::<kilogram_per_second>(1.0),
+/// ];
+///
+/// let system_curve = system_resistance_curve(
+///     &LinearResistance {}, &mass_rates);
+///
+/// assert_eq!(system_curve[1].0.value, 1.0);
+/// assert_eq!(system_curve[1].1.value, -1000.0);
+/// ```
+pub fn system_resistance_curve(
+    collection: &dyn FluidComponentCollectionMethods,
+    mass_rates: &[MassRate]) -> Vec<(MassRate, Pressure)> {
+
+    let mut system_curve: Vec<(MassRate, Pressure)> = 
+        Vec::with_capacity(mass_rates.len());
+
+    for mass_rate in mass_rates.iter() {
+        system_curve.push(
+            (*mass_rate, collection.get_pressure_change(*mass_rate)));
+    }
+
+    return system_curve;
+}
+
+/// evaluates the pressure change across the collection 
+/// at each given mass flowrate, in the order given
+///
+/// this is the same as system_resistance_curve, except that
+/// only the pressures are returned, which is handy for
+/// characteristic curves where the mass flowrates are already known
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use fluid_mechanics_rust::fluid_component_collection::*;
+/// use fluid_mechanics_rust::system_curve::pressure_change_sweep;
+///
+/// struct LinearResistance {}
+///
+/// impl FluidComponentCollectionMethods for LinearResistance {
+///     fn get_pressure_change(&self, 
+///         fluid_mass_flowrate: MassRate) -> Pressure {
+///         return Pressure::new::<pascal>(-fluid_mass_flowrate.value * 1000.0);
+///     }
+///
+///     fn get_mass_flowrate_from_pressure_change(&self,
+///         pressure_change: Pressure) -> MassRate {
+///         return MassRate::new::<kilogram_per_second>(
+///             -pressure_change.value / 1000.0);
+///     }
+/// }
+///
+/// let mass_rates = vec![
+///     MassRate::new::<kilogram_per_second>(-1.0),
+///     MassRate::new::<kilogram_per_second>(2.0),
+/// ];
+///
+/// let pressure_changes = pressure_change_sweep(
+///     &LinearResistance {}, &mass_rates);
+///
+/// assert_eq!(pressure_changes[0].value, 1000.0);
+/// assert_eq!(pressure_changes[1].value, -2000.0);
+/// ```
+pub fn pressure_change_sweep(
+    collection: &dyn FluidComponentCollectionMethods,
+    mass_rates: &[MassRate]) -> Vec<Pressure> {
+
+    return system_resistance_curve(collection, mass_rates)
+        .into_iter()
+        .map(|(_mass_rate, pressure_change)| pressure_change)
+        .collect();
+}
+
+/// same as pressure_change_sweep, except that the mass flowrates 
+/// are split into chunks and each chunk is evaluated on 
+/// a separate thread using rayon
+///
+/// the pressure changes are returned in the same order as the 
+/// mass flowrates given, so the result is exactly the same
+/// as the serial version
+///
+/// the collection must be Sync so that it can be shared
+/// across threads
+#[cfg(feature = "rayon")]
+pub fn pressure_change_sweep_parallel(
+    collection: &(dyn FluidComponentCollectionMethods + Sync),
+    mass_rates: &[MassRate]) -> Vec<Pressure> {
+
+    // small chunks so that short sweeps still get spread out,
+    // par_chunks with collect preserves the order of the chunks
+    let chunk_size = (mass_rates.len() / rayon::current_num_threads())
+        .max(1);
+
+    let system_curve_chunks: Vec<Vec<(MassRate, Pressure)>> = 
+        mass_rates.par_chunks(chunk_size)
+        .map(|mass_rate_chunk| {
+            system_resistance_curve(collection, mass_rate_chunk)
+        })
+        .collect();
+
+    return system_curve_chunks
+        .into_iter()
+        .flatten()
+        .map(|(_mass_rate, pressure_change)| pressure_change)
+        .collect();
+}
+
+/// finds the operating point of a pump connected to a system,
+/// ie. the mass flowrate where the pump head equals the 
+/// system pressure loss
+///
+/// more generally, the pump and system form a closed loop,
+/// so the pressure changes around the loop must sum to zero:
+///
+/// pump_pressure_change(m) + system_pressure_change(m) = 0
+///
+/// this way, hydrostatic pressure changes and any internal pressure 
+/// sources in the system are accounted for as well.
+/// The pump can be any fluid component, eg. a CentrifugalPump with
+/// a head curve, and the system any series or parallel collection
+///
+/// i return the mass flowrate and the pump pressure change 
+/// at the operating point
+///
+/// i search between -10 and 10 kg/s first,
+/// and then -10000 and 10000 kg/s
+///
+/// panics if no operating point is found
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::angle::degree;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use uom::si::dynamic_viscosity::millipascal_second;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::pressure::pascal;
+/// use fluid_mechanics_rust::fluid_component_calculation::*;
+/// use fluid_mechanics_rust::fluid_component_collection::*;
+/// use fluid_mechanics_rust::system_curve::operating_point;
+///
+/// // H = 5000 Pa - 1000 Pa/(kg/s) * m
+/// let head_curve = |mass_flowrate: MassRate| -> Pressure {
+///     return Pressure::new::<pascal>(
+///         5000.0 - 1000.0 * mass_flowrate.get::<kilogram_per_second>());
+/// };
+///
+/// let pump = CentrifugalPump::new(
+///     &head_curve,
+///     Length::new::<meter>(2.79e-2),
+///     Area::new::<square_meter>(6.11e-4),
+///     Length::new::<meter>(0.36),
+///     Angle::new::<degree>(0.0),
+///     MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+///     DynamicViscosity::new::<millipascal_second>(1.0));
+///
+/// // a system where the pressure loss is 1000 Pa/(kg/s) * m,
+/// // so the pump operates at 2.5 kg/s and 2500 Pa
+/// struct LinearResistance {}
+///
+/// impl FluidComponentCollectionMethods for LinearResistance {
+///     fn get_pressure_change(&self, 
+///         fluid_mass_flowrate: MassRate) -> Pressure {
+///         return Pressure::new::<pascal>(-fluid_mass_flowrate.value * 1000.0);
+///     }
+///
+///     fn get_mass_flowrate_from_pressure_change(&self,
+///         pressure_change: Pressure) -> MassRate {
+///         return MassRate::new::<kilogram_per_second>(
+///             -pressure_change.value / 1000.0);
+///     }
+/// }
+///
+/// let (mass_flowrate, pump_pressure) = 
+///     operating_point(&pump, &LinearResistance {});
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(),
+///                             2.5, max_relative = 1e-6);
+/// approx::assert_relative_eq!(pump_pressure.get::<pascal>(),
+///                             2500.0, max_relative = 1e-6);
+/// ```
+pub fn operating_point(pump: &dyn FluidComponent,
+                       system: &dyn FluidComponentCollectionMethods) 
+    -> (MassRate, Pressure) {
+
+    let loop_pressure_change_root = |mass_flow_kg_per_s: f64| -> f64 {
+        let mass_rate = 
+            MassRate::new::<kilogram_per_second>(mass_flow_kg_per_s);
+
+        let loop_pressure_change = 
+            pump.get_pressure_change_immutable(mass_rate) + 
+            system.get_pressure_change(mass_rate);
+
+        return loop_pressure_change.get::<pascal>();
+    };
+
+    let mass_flowrate = find_mass_flowrate_root(&loop_pressure_change_root).
+        unwrap_or_else(|| panic!("no operating point found \n
+                                 within -10000 and 10000 kg/s"));
+
+    return (mass_flowrate, 
+            pump.get_pressure_change_immutable(mass_flowrate));
+}
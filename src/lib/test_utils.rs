@@ -0,0 +1,148 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here are assertion helpers for uom quantities, so that tests 
+// don't need to extract .value manually and failures are 
+// reported in familiar units rather than base SI units
+//
+// these are compiled for this crate's own tests, and for 
+// downstream tests with the test-util feature
+
+use uom::si::f64::*;
+use uom::si::pressure::pascal;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::length::meter;
+use uom::si::velocity::meter_per_second;
+
+/// panics unless actual and expected are within the relative 
+/// tolerance of each other, ie.
+///
+/// |actual - expected| <= max_relative * max(|actual|, |expected|)
+///
+/// the same as approx::assert_relative_eq! with max_relative,
+/// but the message includes the quantity and unit
+#[track_caller]
+fn assert_relative_eq_with_units(actual: f64,
+                                 expected: f64,
+                                 max_relative: f64,
+                                 quantity_name: &str,
+                                 unit: &str){
+
+    if actual == expected {
+        return;
+    }
+
+    let difference = (actual - expected).abs();
+    let largest_magnitude = actual.abs().max(expected.abs());
+
+    // a NaN difference must fail, so i check for it explicitly
+    // rather than relying on comparisons with NaN being false
+    if difference.is_nan() || difference > max_relative * largest_magnitude {
+        panic!("{} mismatch: \n
+               actual = {} {}, expected = {} {} \n
+               difference = {} {}, relative difference = {}, \n
+               max_relative = {}",
+               quantity_name, 
+               actual, unit, expected, unit,
+               difference, unit, difference / largest_magnitude,
+               max_relative);
+    }
+}
+
+/// asserts that two pressures are equal to within a relative 
+/// tolerance, failures are reported in Pa
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::{pascal, kilopascal};
+/// use fluid_mechanics_rust::test_utils::assert_pressure_eq;
+///
+/// assert_pressure_eq(Pressure::new::<kilopascal>(1.0),
+///                    Pressure::new::<pascal>(1000.0), 1e-12);
+/// ```
+#[track_caller]
+pub fn assert_pressure_eq(actual: Pressure, 
+                          expected: Pressure,
+                          max_relative: f64){
+    assert_relative_eq_with_units(actual.get::<pascal>(),
+                                  expected.get::<pascal>(),
+                                  max_relative, "pressure", "Pa");
+}
+
+/// asserts that two mass flowrates are equal to within a relative 
+/// tolerance, failures are reported in kg/s
+#[track_caller]
+pub fn assert_mass_rate_eq(actual: MassRate, 
+                           expected: MassRate,
+                           max_relative: f64){
+    assert_relative_eq_with_units(actual.get::<kilogram_per_second>(),
+                                  expected.get::<kilogram_per_second>(),
+                                  max_relative, "mass flowrate", "kg/s");
+}
+
+/// asserts that two lengths are equal to within a relative 
+/// tolerance, failures are reported in m
+#[track_caller]
+pub fn assert_length_eq(actual: Length, 
+                        expected: Length,
+                        max_relative: f64){
+    assert_relative_eq_with_units(actual.get::<meter>(),
+                                  expected.get::<meter>(),
+                                  max_relative, "length", "m");
+}
+
+/// asserts that two velocities are equal to within a relative 
+/// tolerance, failures are reported in m/s
+#[track_caller]
+pub fn assert_velocity_eq(actual: Velocity, 
+                          expected: Velocity,
+                          max_relative: f64){
+    assert_relative_eq_with_units(actual.get::<meter_per_second>(),
+                                  expected.get::<meter_per_second>(),
+                                  max_relative, "velocity", "m/s");
+}
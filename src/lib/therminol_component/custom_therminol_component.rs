@@ -47,10 +47,14 @@
 // Professor Per F. Peterson
 use crate::fluid_component_calculation::custom_component_calc;
 use crate::therminol_component::*;
+use crate::fluid_component_calculation::{FluidComponent,
+    TemperatureDependentFluidComponent};
 use dowtherm_a_properties;
 
 use uom::si::length::{meter,millimeter};
 use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::mass_rate::kilogram_per_second;
 use uom::si::angle::degree;
 use uom::si::acceleration::meter_per_second_squared;
 use uom::si::area::square_meter;
@@ -213,6 +217,15 @@ pub struct DowthermACustomComponent {
     /// all are user specified through the constructor
     /// 
     pub dowtherm_custom_component_properties: CustomComponentProperties,
+
+    /// fluid temperature used when this is treated as a 
+    /// fluid_component_calculation::FluidComponent,
+    /// defaults to DEFAULT_FLUID_TEMP_DEGREE_CELSIUS
+    pub fluid_temp: ThermodynamicTemperature,
+
+    /// mass flowrate used when this is treated as a 
+    /// fluid_component_calculation::FluidComponent
+    pub mass_flowrate: MassRate,
 }
 
 impl FluidProperties for DowthermACustomComponent {
@@ -250,6 +263,67 @@ impl FluidProperties for DowthermACustomComponent {
 
 }
 
+impl DowthermACustomComponent {
+
+    /// constructs a custom component from closures for the 
+    /// custom darcy friction factor and form loss K, 
+    /// rather than static functions
+    ///
+    /// the CustomComponentProperties fields need 
+    /// &'static dyn Fn references, so the closures are boxed and 
+    /// leaked to give them a static lifetime. This means that their
+    /// memory is never freed, which is fine for the handful of 
+    /// components in a loop, but don't call this in a tight loop
+    ///
+    /// As with new, the closures should handle reverse flow 
+    /// (Re < 0) and Re = 0 themselves
+    ///
+    /// ```rust
+    /// use uom::si::f64::*;
+    /// use uom::si::length::{meter, millimeter};
+    /// use uom::si::area::square_meter;
+    /// use uom::si::angle::degree;
+    /// use fluid_mechanics_rust::therminol_component::
+    ///     custom_therminol_component::DowthermACustomComponent;
+    ///
+    /// let form_loss_k = 18.0;
+    ///
+    /// let flowmeter = DowthermACustomComponent::from_closures(
+    ///     "flowmeter".to_string(),
+    ///     Length::new::<meter>(2.79e-2),
+    ///     Area::new::<square_meter>(6.11e-4),
+    ///     Length::new::<meter>(0.36),
+    ///     Length::new::<millimeter>(0.015),
+    ///     Angle::new::<degree>(0.0),
+    ///     |_reynolds_number: f64, _roughness_ratio: f64| 0.0,
+    ///     move |reynolds_number: f64| form_loss_k * reynolds_number.signum());
+    /// ```
+    pub fn from_closures(name: String,
+                         hydraulic_diameter: Length,
+                         cross_sectional_area: Area,
+                         component_length: Length,
+                         absolute_roughness: Length,
+                         incline_angle: Angle,
+                         custom_darcy: impl Fn(f64,f64) -> f64 + 'static,
+                         custom_k: impl Fn(f64) -> f64 + 'static) -> Self {
+
+        let custom_darcy: &'static dyn Fn(f64,f64) -> f64 = 
+            Box::leak(Box::new(custom_darcy));
+        let custom_k: &'static dyn Fn(f64) -> f64 = 
+            Box::leak(Box::new(custom_k));
+
+        return <Self as StandardCustomComponentProperties>::new_typed(
+            name,
+            hydraulic_diameter,
+            cross_sectional_area,
+            component_length,
+            absolute_roughness,
+            incline_angle,
+            custom_darcy,
+            custom_k);
+    }
+}
+
 impl StandardCustomComponentProperties for DowthermACustomComponent {
     // constructor
     fn new(name: String,
@@ -270,22 +344,47 @@ impl StandardCustomComponentProperties for DowthermACustomComponent {
             absolute_roughness_millimeters);
         let input_incline_angle = Angle::new::<degree>(
             incline_angle_degrees);
+
+        return Self::new_typed(name,
+                               input_hydraulic_diameter,
+                               input_xs_area,
+                               input_component_length,
+                               input_absolute_roughness,
+                               input_incline_angle,
+                               custom_darcy,
+                               custom_k);
+    }
+
+    fn new_typed(name: String,
+                 hydraulic_diameter: Length,
+                 cross_sectional_area: Area,
+                 component_length: Length,
+                 absolute_roughness: Length,
+                 incline_angle: Angle,
+                 custom_darcy: &'static dyn Fn(f64,f64) -> f64,
+                 custom_k: &'static dyn Fn(f64) -> f64) -> Self {
+
         let input_internal_pressure = Pressure::new::<pascal>(
             0.0);
 
         let custom_pipe_properties = CustomComponentProperties {
             _name: name,
-            hydraulic_diameter: input_hydraulic_diameter,
-            xs_area: input_xs_area,
-            component_length: input_component_length,
-            absolute_roughness: input_absolute_roughness,
-            incline_angle: input_incline_angle,
+            hydraulic_diameter,
+            xs_area: cross_sectional_area,
+            component_length,
+            absolute_roughness,
+            incline_angle,
             custom_darcy: custom_darcy,
             custom_k: custom_k,
             internal_pressure: input_internal_pressure,
         };
 
-        return Self { dowtherm_custom_component_properties : custom_pipe_properties };
+        return Self { 
+            dowtherm_custom_component_properties : custom_pipe_properties,
+            fluid_temp: ThermodynamicTemperature::new::<degree_celsius>(
+                DEFAULT_FLUID_TEMP_DEGREE_CELSIUS),
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
     }
 
     fn get_cross_sectional_area(&self) -> Area {
@@ -440,3 +539,187 @@ impl CalcPressureChange for DowthermACustomComponent {
 
 
 
+
+/// prints the component parameters in readable units, 
+/// eg. for debugging a loop made of many factory components
+///
+/// the custom friction factor and form loss functions can't be 
+/// printed, so i just indicate that they are user defined
+impl std::fmt::Display for DowthermACustomComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        let component_properties = 
+            &self.dowtherm_custom_component_properties;
+
+        return write!(f, 
+                      "DowthermACustomComponent {}: \
+                      hydraulic diameter {:.3} mm, \
+                      cross sectional area {:.4e} m^2, \
+                      length {:.4} m, roughness {:.4} mm, \
+                      incline angle {:.2} deg, \
+                      form loss K: custom fn(Re), \
+                      darcy friction factor: custom fn(Re, e/D), \
+                      internal pressure {:.2} Pa",
+                      component_properties._name,
+                      component_properties.hydraulic_diameter.get::<millimeter>(),
+                      component_properties.xs_area.get::<square_meter>(),
+                      component_properties.component_length.get::<meter>(),
+                      component_properties.absolute_roughness.get::<millimeter>(),
+                      component_properties.incline_angle.get::<degree>(),
+                      component_properties.internal_pressure.get::<pascal>());
+    }
+}
+
+/// DowthermACustomComponent can also be used as a 
+/// fluid_component_calculation::FluidComponent, so that it can be 
+/// put into fluid component collections alongside other components
+///
+/// the fluid temperature is fixed at self.fluid_temp, and all
+/// pressure change calculations go through CalcPressureChange
+/// at that temperature
+impl FluidComponent for DowthermACustomComponent {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_change_immutable(
+        &self, pressure_change: Pressure) -> MassRate {
+        return CalcPressureChange::to_mass_rate(
+            self, pressure_change, self.fluid_temp);
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+
+        // pressure_change = -pressure_loss + hydrostatic_pressure_increase 
+        // + pressure source
+        let pressure_change = -pressure_loss + 
+            <Self as StandardCustomComponentProperties>::get_hydrostatic_pressure_change(
+                self, self.fluid_temp) +
+            self.get_internal_pressure_term();
+
+        return CalcPressureChange::to_mass_rate(
+            self, pressure_change, self.fluid_temp);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        let pressure_change = CalcPressureChange::from_mass_rate(
+            self, mass_flowrate, self.fluid_temp);
+
+        // pressure_loss = -pressure_change + hydrostatic_pressure_increase 
+        // + pressure source
+        return -pressure_change + 
+            <Self as StandardCustomComponentProperties>::get_hydrostatic_pressure_change(
+                self, self.fluid_temp) +
+            self.get_internal_pressure_term();
+    }
+
+    fn get_pressure_change_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return CalcPressureChange::from_mass_rate(
+            self, mass_flowrate, self.fluid_temp);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return <Self as StandardCustomComponentProperties>::get_cross_sectional_area(self);
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return <Self as StandardCustomComponentProperties>::get_cross_sectional_area(self);
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.dowtherm_custom_component_properties.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.dowtherm_custom_component_properties.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return DowthermACustomComponent::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return DowthermACustomComponent::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return DowthermACustomComponent::density(self.fluid_temp);
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return DowthermACustomComponent::density(self.fluid_temp);
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.dowtherm_custom_component_properties.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.dowtherm_custom_component_properties.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.dowtherm_custom_component_properties.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.dowtherm_custom_component_properties.incline_angle;
+    }
+
+    fn get_hydrostatic_pressure_change(&mut self) -> Pressure {
+        return <Self as StandardCustomComponentProperties>::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_hydrostatic_pressure_change_immutable(&self) -> Pressure {
+        return <Self as StandardCustomComponentProperties>::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.get_internal_pressure_term();
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.get_internal_pressure_term();
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.set_internal_pressure_term(internal_pressure.get::<pascal>());
+    }
+}
+
+/// the fluid temperature of DowthermACustomComponent can be changed so that
+/// density and viscosity are recalculated, 
+/// eg. for a loop with a temperature profile
+impl TemperatureDependentFluidComponent for DowthermACustomComponent {
+
+    fn get_fluid_temp(&self) -> ThermodynamicTemperature {
+        return self.fluid_temp;
+    }
+
+    fn set_fluid_temp(&mut self, fluid_temp: ThermodynamicTemperature) {
+        self.fluid_temp = fluid_temp;
+    }
+}
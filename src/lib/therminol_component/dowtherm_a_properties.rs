@@ -54,6 +54,7 @@ use uom::si::dynamic_viscosity::pascal_second;
 use uom::si::thermal_conductivity::watt_per_meter_kelvin;
 use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
 use uom::si::available_energy::joule_per_kilogram;
+use uom::si::temperature_coefficient::per_kelvin;
 
 // this is for the root finding algorithms
 extern crate peroxide;
@@ -107,6 +108,26 @@ pub fn getDowthermAConstantPressureSpecificHeatCapacity(
         cp_value_joule_per_kg);
 }
 
+/// function to obtain dowtherm A thermal expansion coefficient
+/// given a temperature
+///
+/// beta = -1/rho * d(rho)/dT = 0.85/(1078 - 0.85 T)
+///
+/// this comes from differentiating the density correlation
+#[allow(non_snake_case)]
+pub fn getDowthermAThermalExpansionCoefficient(
+    fluidTemp: ThermodynamicTemperature) -> TemperatureCoefficient {
+
+    rangeCheck(fluidTemp);
+    let densityValueKgPerM3 = 1078.0 - 0.85*fluidTemp
+       .get::<degree_celsius>();
+
+    let thermalExpansionValue = 0.85/densityValueKgPerM3;
+
+    return TemperatureCoefficient::new::<per_kelvin>(
+        thermalExpansionValue);
+}
+
 /// function to obtain dowtherm A thermal conductivity
 /// given a temperature
 #[allow(non_snake_case)]
@@ -335,3 +356,75 @@ pub fn rangeCheck(fluidTemp: ThermodynamicTemperature) -> bool{
     return true;
 
 }
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// one row of a dowtherm A property table,
+/// see property_table
+///
+/// with the serde feature, the table can be written to a file
+/// (eg. json) for cross checking against vendor datasheets
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DowthermRow {
+    /// fluid temperature
+    pub temperature: ThermodynamicTemperature,
+    /// fluid density
+    pub density: MassDensity,
+    /// fluid dynamic viscosity
+    pub viscosity: DynamicViscosity,
+    /// constant pressure specific heat capacity
+    pub cp: SpecificHeatCapacity,
+    /// thermal conductivity
+    pub thermal_conductivity: ThermalConductivity,
+    /// specific enthalpy, 0 J/kg at 20C
+    pub enthalpy: AvailableEnergy,
+}
+
+/// tabulates dowtherm A properties over a list of temperatures
+///
+/// this just loops over the correlations above, so every
+/// temperature must be within 20-180C or it will panic
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use uom::si::mass_density::kilogram_per_cubic_meter;
+/// use fluid_mechanics_rust::therminol_component::
+/// dowtherm_a_properties::property_table;
+///
+/// let temps = vec![
+///     ThermodynamicTemperature::new::<degree_celsius>(20.0),
+///     ThermodynamicTemperature::new::<degree_celsius>(100.0)];
+///
+/// let table = property_table(&temps);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     table[1].density.get::<kilogram_per_cubic_meter>(),
+///     1078.0 - 0.85*100.0, max_relative = 1e-12);
+/// ```
+pub fn property_table(temps: &[ThermodynamicTemperature]) 
+    -> Vec<DowthermRow> {
+
+    let mut table: Vec<DowthermRow> = vec![];
+
+    for fluid_temp in temps.iter() {
+
+        let row = DowthermRow {
+            temperature: *fluid_temp,
+            density: getDowthermADensity(*fluid_temp),
+            viscosity: getDowthermAViscosity(*fluid_temp),
+            cp: getDowthermAConstantPressureSpecificHeatCapacity(
+                *fluid_temp),
+            thermal_conductivity: getDowthermAThermalConductivity(
+                *fluid_temp),
+            enthalpy: getDowthermAEnthalpy(*fluid_temp),
+        };
+
+        table.push(row);
+    }
+
+    return table;
+}
@@ -61,6 +61,10 @@ use crate::therminol_component::therminol_pipe::
 DowthermAPipe;
 
 use super::StandardPipeProperties;
+use super::DowthermAComponent;
+
+use crate::churchill_friction_factor;
+use crate::churchill_friction_factor::FrictionFactorError;
 
 /// Pipe6a in Compact Integral Effects Test (CIET)
 /// CTAH branch 
@@ -90,6 +94,12 @@ impl Pipe6a {
         return pipe_6a;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// static mixer 41
@@ -155,6 +165,12 @@ impl StaticMixer41 {
 
         return static_mixer_41;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// Vertical part of Coiled Tube Air Heater (CTAH)
@@ -182,28 +198,30 @@ impl CTAHVertical {
 
     /// CTAH has a darcy friction factor from churchill
     /// correlation
-    pub fn custom_darcy(mut reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    pub fn custom_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
 
-        if roughness_ratio < 0.0 {
-            panic!("roughness_ratio < 0.0");
+        match Self::try_custom_darcy(reynolds_number, roughness_ratio) {
+            Ok(darcy) => return darcy,
+            Err(error) => panic!("{}", error),
         }
+    }
 
-        use crate::churchill_friction_factor;
-        let mut reverse_flow = false;
+    /// same as custom_darcy, but returns an error 
+    /// instead of panicking for Re = 0 or negative roughness ratio,
+    /// eg. so that one bad input doesn't stop a whole parameter sweep
+    pub fn try_custom_darcy(reynolds_number: f64, 
+                            roughness_ratio: f64) 
+        -> Result<f64, FrictionFactorError> {
 
-        // the user account for reverse flow scenarios...
-        if reynolds_number < 0.0 {
-            reverse_flow = true;
-            reynolds_number = reynolds_number * -1.0;
-        }
-
-        let darcy = churchill_friction_factor::darcy(reynolds_number,
-                                                     roughness_ratio);
+        // darcy_checked takes the magnitude of Re, 
+        // i just account for reverse flow here
+        let darcy = churchill_friction_factor::darcy_checked(
+            reynolds_number, roughness_ratio)?;
 
-        if reverse_flow {
-            return -darcy;
+        if reynolds_number < 0.0 {
+            return Ok(-darcy);
         }
-        return darcy;
+        return Ok(darcy);
     }
 
     /// CTAH has a fixed K value of 3.9 
@@ -236,6 +254,12 @@ impl CTAHVertical {
 
         return ctah_vertical;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// Horizontal part of Coiled Tube Air Heater (CTAH)
@@ -305,6 +329,12 @@ impl CTAHHorizontal {
 
         return ctah_horizontal;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// Static mixer pipe 8a
@@ -333,6 +363,12 @@ impl Pipe8a {
         return pipe_8a;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// static mixer 40 (MX-40) on CIET diagram
@@ -392,6 +428,12 @@ impl StaticMixer40 {
 
         return static_mixer_40;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe number 9 in CIET's CTAH branch
@@ -418,6 +460,12 @@ impl Pipe9 {
         return pipe_9;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe number 10 in CIET's CTAH branch
@@ -444,6 +492,12 @@ impl Pipe10 {
         return pipe_10;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe number 11 in CIET's CTAH branch
@@ -470,6 +524,12 @@ impl Pipe11 {
         return pipe_11;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe number 12 in CIET's CTAH branch
@@ -496,6 +556,12 @@ impl Pipe12 {
         return pipe_12;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// ctah pump is a custom therminol component with
@@ -543,6 +609,12 @@ impl CTAHPump {
 
         return ctah_pump;
     }
+
+    /// returns an instance of the pump as a DowthermAComponent,
+    /// so it can be put in a vector with pipes
+    pub fn get_enum(pressure_pascals: f64) -> DowthermAComponent {
+        return Self::get(pressure_pascals).into();
+    }
 }
 
 /// pipe number 13 in CIET's CTAH branch
@@ -572,6 +644,12 @@ impl Pipe13 {
         return pipe_13;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe number 14 in CIET's CTAH branch
@@ -604,6 +682,12 @@ impl Pipe14 {
 
         return pipe_14;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// FM-40 Coriolis Flowmeter in CIET's CTAH branch
@@ -665,6 +749,12 @@ impl Flowmeter40 {
 
         return flowmeter_40_14a;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -696,6 +786,12 @@ impl Pipe15 {
 
         return pipe_15;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe number 16 in CIET's CTAH branch
@@ -727,6 +823,12 @@ impl Pipe16 {
 
         return pipe_16;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// Branch (or pipe 17) in CIET's CTAH branch
@@ -765,6 +867,72 @@ impl Branch17 {
 
         return pipe_17;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+}
+
+/// returns all the components in CIET's CTAH branch in series,
+/// from pipe 6a to branch 17 (top to bottom perspective)
+///
+/// the CTAH pump has zero internal pressure here, 
+/// so this is the passive branch, you can set the pump pressure 
+/// afterwards (it is the 11th component, index 10)
+///
+/// at 21C, the branch gives about 39062.93 Pa of pressure change at 
+/// zero flow and 28740.47 Pa at 0.18 kg/s. These are regression 
+/// values generated by this library rather than CIET data, 
+/// see the integration tests
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// use fluid_mechanics_rust::therminol_component::*;
+///
+/// let ctah_branch = factory::ctah_branch();
+///
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+/// let mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+///
+/// let mut total_pressure_change = Pressure::new::<uom::si::pressure::pascal>(0.0);
+///
+/// for component in ctah_branch.iter() {
+///     total_pressure_change += component.from_mass_rate(mass_rate, fluid_temp);
+/// }
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     total_pressure_change.get::<uom::si::pressure::pascal>(),
+///     28740.47, max_relative = 1e-5);
+/// ```
+pub fn ctah_branch() -> Vec<DowthermAComponent> {
+
+    let ctah_branch: Vec<DowthermAComponent> = vec![
+        Pipe6a::get_enum(),
+        StaticMixer41::get_enum(),
+        CTAHVertical::get_enum(),
+        CTAHHorizontal::get_enum(),
+        Pipe8a::get_enum(),
+        StaticMixer40::get_enum(),
+        Pipe9::get_enum(),
+        Pipe10::get_enum(),
+        Pipe11::get_enum(),
+        Pipe12::get_enum(),
+        CTAHPump::get_enum(0.0),
+        Pipe13::get_enum(),
+        Pipe14::get_enum(),
+        Flowmeter40::get_enum(),
+        Pipe15::get_enum(),
+        Pipe16::get_enum(),
+        Branch17::get_enum(),
+    ];
+
+    return ctah_branch;
 }
 
 /// Branch 5 in the Heater Branch (top to bottom perspective)
@@ -821,6 +989,12 @@ impl Branch5 {
 
         return pipe_5;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -853,6 +1027,12 @@ impl Pipe4 {
 
         return pipe_4;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe3 within the heater branch
@@ -884,6 +1064,12 @@ impl Pipe3 {
 
         return pipe_3;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// MX-10 within the heater branch
@@ -957,6 +1143,12 @@ impl StaticMixer10 {
 
         return static_mixer_10;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// static mixer pipe2a in heater branch
@@ -990,6 +1182,12 @@ impl Pipe2a {
 
         return pipe_2a;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// heater top head 1a of heater branch in CIET
@@ -1010,28 +1208,30 @@ impl HeaterTopHead1a {
     /// Actually a dowtherm pipe would do,
     /// but I just copied and pasted the custom fldk component
     /// template
-    pub fn custom_darcy(mut reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    pub fn custom_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
 
-        if roughness_ratio < 0.0 {
-            panic!("roughness_ratio < 0.0");
+        match Self::try_custom_darcy(reynolds_number, roughness_ratio) {
+            Ok(darcy) => return darcy,
+            Err(error) => panic!("{}", error),
         }
+    }
 
-        use crate::churchill_friction_factor;
-        let mut reverse_flow = false;
-
-        // the user account for reverse flow scenarios...
-        if reynolds_number < 0.0 {
-            reverse_flow = true;
-            reynolds_number = reynolds_number * -1.0;
-        }
+    /// same as custom_darcy, but returns an error 
+    /// instead of panicking for Re = 0 or negative roughness ratio,
+    /// eg. so that one bad input doesn't stop a whole parameter sweep
+    pub fn try_custom_darcy(reynolds_number: f64, 
+                            roughness_ratio: f64) 
+        -> Result<f64, FrictionFactorError> {
 
-        let darcy = churchill_friction_factor::darcy(reynolds_number,
-                                                     roughness_ratio);
+        // darcy_checked takes the magnitude of Re, 
+        // i just account for reverse flow here
+        let darcy = churchill_friction_factor::darcy_checked(
+            reynolds_number, roughness_ratio)?;
 
-        if reverse_flow {
-            return -darcy;
+        if reynolds_number < 0.0 {
+            return Ok(-darcy);
         }
-        return darcy;
+        return Ok(darcy);
     }
 
     /// custom K is fixed at 3.75
@@ -1066,6 +1266,12 @@ impl HeaterTopHead1a {
 
         return heater_top_head;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// This is the first version of CIET's heater
@@ -1086,28 +1292,30 @@ impl CietHeaterVersion1 {
 
 
     /// custom darcy here is the same as churchill friction factor
-    pub fn custom_darcy(mut reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    pub fn custom_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
 
-        if roughness_ratio < 0.0 {
-            panic!("roughness_ratio < 0.0");
+        match Self::try_custom_darcy(reynolds_number, roughness_ratio) {
+            Ok(darcy) => return darcy,
+            Err(error) => panic!("{}", error),
         }
+    }
 
-        use crate::churchill_friction_factor;
-        let mut reverse_flow = false;
+    /// same as custom_darcy, but returns an error 
+    /// instead of panicking for Re = 0 or negative roughness ratio,
+    /// eg. so that one bad input doesn't stop a whole parameter sweep
+    pub fn try_custom_darcy(reynolds_number: f64, 
+                            roughness_ratio: f64) 
+        -> Result<f64, FrictionFactorError> {
 
-        // the user account for reverse flow scenarios...
-        if reynolds_number < 0.0 {
-            reverse_flow = true;
-            reynolds_number = reynolds_number * -1.0;
-        }
-
-        let darcy = churchill_friction_factor::darcy(reynolds_number,
-                                                     roughness_ratio);
+        // darcy_checked takes the magnitude of Re, 
+        // i just account for reverse flow here
+        let darcy = churchill_friction_factor::darcy_checked(
+            reynolds_number, roughness_ratio)?;
 
-        if reverse_flow {
-            return -darcy;
+        if reynolds_number < 0.0 {
+            return Ok(-darcy);
         }
-        return darcy;
+        return Ok(darcy);
     }
 
     /// K = 0 for CIET's heater version 1
@@ -1140,6 +1348,12 @@ impl CietHeaterVersion1 {
 
         return heater_version_1_label_1;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// heater bottom head 1b within CIET's heater branch
@@ -1156,28 +1370,30 @@ impl HeaterBottomHead1b {
 
 
     /// custom darcy here is the same as churchill friction factor
-    pub fn custom_darcy(mut reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    pub fn custom_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
 
-        if roughness_ratio < 0.0 {
-            panic!("roughness_ratio < 0.0");
+        match Self::try_custom_darcy(reynolds_number, roughness_ratio) {
+            Ok(darcy) => return darcy,
+            Err(error) => panic!("{}", error),
         }
+    }
 
-        use crate::churchill_friction_factor;
-        let mut reverse_flow = false;
+    /// same as custom_darcy, but returns an error 
+    /// instead of panicking for Re = 0 or negative roughness ratio,
+    /// eg. so that one bad input doesn't stop a whole parameter sweep
+    pub fn try_custom_darcy(reynolds_number: f64, 
+                            roughness_ratio: f64) 
+        -> Result<f64, FrictionFactorError> {
 
-        // the user account for reverse flow scenarios...
-        if reynolds_number < 0.0 {
-            reverse_flow = true;
-            reynolds_number = reynolds_number * -1.0;
-        }
-
-        let darcy = churchill_friction_factor::darcy(reynolds_number,
-                                                     roughness_ratio);
+        // darcy_checked takes the magnitude of Re, 
+        // i just account for reverse flow here
+        let darcy = churchill_friction_factor::darcy_checked(
+            reynolds_number, roughness_ratio)?;
 
-        if reverse_flow {
-            return -darcy;
+        if reynolds_number < 0.0 {
+            return Ok(-darcy);
         }
-        return darcy;
+        return Ok(darcy);
     }
 
     /// custom K is fixed at 3.95
@@ -1212,6 +1428,12 @@ impl HeaterBottomHead1b {
 
         return heater_bottom_head;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe 18 within CIET's heater branch
@@ -1242,6 +1464,12 @@ impl Pipe18 {
 
         return pipe_18;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe 26 in DHX Branch from Top to Bottom orientation
@@ -1273,6 +1501,12 @@ impl Pipe26 {
 
         return pipe_26;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// static mixer 21 (MX-21) on CIET diagram
@@ -1343,6 +1577,12 @@ impl StaticMixer21 {
 
         return static_mixer_21;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -1373,6 +1613,12 @@ impl Pipe25a {
         return pipe_25a;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// this is the heat exchanger
@@ -1392,28 +1638,30 @@ impl DHXShellSideHeatExchanger {
 
 
     /// custom darcy here is same as churchill friction factor
-    pub fn custom_darcy(mut reynolds_number: f64, roughness_ratio: f64) -> f64 {
+    pub fn custom_darcy(reynolds_number: f64, roughness_ratio: f64) -> f64 {
 
-        if roughness_ratio < 0.0 {
-            panic!("roughness_ratio < 0.0");
+        match Self::try_custom_darcy(reynolds_number, roughness_ratio) {
+            Ok(darcy) => return darcy,
+            Err(error) => panic!("{}", error),
         }
+    }
 
-        use crate::churchill_friction_factor;
-        let mut reverse_flow = false;
-
-        // the user account for reverse flow scenarios...
-        if reynolds_number < 0.0 {
-            reverse_flow = true;
-            reynolds_number = reynolds_number * -1.0;
-        }
+    /// same as custom_darcy, but returns an error 
+    /// instead of panicking for Re = 0 or negative roughness ratio,
+    /// eg. so that one bad input doesn't stop a whole parameter sweep
+    pub fn try_custom_darcy(reynolds_number: f64, 
+                            roughness_ratio: f64) 
+        -> Result<f64, FrictionFactorError> {
 
-        let darcy = churchill_friction_factor::darcy(reynolds_number,
-                                                     roughness_ratio);
+        // darcy_checked takes the magnitude of Re, 
+        // i just account for reverse flow here
+        let darcy = churchill_friction_factor::darcy_checked(
+            reynolds_number, roughness_ratio)?;
 
-        if reverse_flow {
-            return -darcy;
+        if reynolds_number < 0.0 {
+            return Ok(-darcy);
         }
-        return darcy;
+        return Ok(darcy);
     }
 
     /// custom K is fixed at 23.9
@@ -1449,6 +1697,12 @@ impl DHXShellSideHeatExchanger {
 
         return dhx_shell_side;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// static mixer 20 (MX-20) on CIET diagram
@@ -1521,6 +1775,12 @@ impl StaticMixer20 {
 
         return static_mixer_20;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// static mixer pipe 23a in DHX branch in CIET
@@ -1549,6 +1809,12 @@ impl Pipe23a {
         return pipe_23a;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe 22 within DHX branch in CIEt
@@ -1576,6 +1842,12 @@ impl Pipe22 {
         return pipe_22;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// FM-20 DHX branch flow coriolis flowmeter 20
@@ -1648,6 +1920,12 @@ impl Flowmeter20 {
 
         return flowmeter_20;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// FM-20 DHX branch flow coriolis flowmeter 20
@@ -1743,6 +2021,12 @@ impl Flowmeter20WithHighKCheckValve {
 
         return flowmeter_20;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// pipe 21 within CIET DHX loop
@@ -1769,6 +2053,12 @@ impl Pipe21 {
         return pipe_21;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe 20 within CIET DHX loop
@@ -1795,6 +2085,12 @@ impl Pipe20 {
         return pipe_20;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// pipe 19 within CIET DHX loop
@@ -1821,6 +2117,12 @@ impl Pipe19 {
         return pipe_19;
     }
 
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
+
 }
 
 /// bypass flow flowmeter FM-30
@@ -1886,6 +2188,12 @@ impl Flowmeter30 {
 
         return flowmeter_30;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -1953,6 +2261,12 @@ impl Flowmeter60 {
 
         return flowmeter_60;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -2024,6 +2338,12 @@ impl StaticMixer60 {
 
         return static_mixer_60;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 /// static mixer MX-60 within DRACS loop
@@ -2093,6 +2413,12 @@ impl StaticMixer61 {
 
         return static_mixer_61;
     }
+
+    /// returns the same instance as get(), but as a DowthermAComponent,
+    /// so it can be put in a vector with other components
+    pub fn get_enum() -> DowthermAComponent {
+        return Self::get().into();
+    }
 }
 
 
@@ -2153,4 +2479,10 @@ impl PumpWithResistance {
 
         return pump_with_resistance;
     }
+
+    /// returns an instance of the pump as a DowthermAComponent,
+    /// so it can be put in a vector with pipes
+    pub fn get_enum(pressure_pascals: f64) -> DowthermAComponent {
+        return Self::get(pressure_pascals).into();
+    }
 }
@@ -47,6 +47,10 @@
 ///
 /// However the user will be able to defined fldk here
 pub mod custom_therminol_component;
+/// Contains code to specify dowtherm A user defined components
+/// where the form loss K depends on both Re and fluid temperature
+pub mod temperature_aware_custom_component;
+pub use temperature_aware_custom_component::TemperatureAwareCustomComponent;
 /// contains correlations for dowtherm A viscosity, density,
 /// thermal conductivity and heat capacity
 pub mod dowtherm_a_properties;
@@ -108,10 +112,26 @@ pub mod factory;
 // this allows for units
 use uom::si::f64::*;
 
+/// fluid temperature (degrees C) that dowtherm A pipes and 
+/// custom components are set to on construction
+///
+/// this is only used when they are treated as a
+/// fluid_component_calculation::FluidComponent, where the 
+/// fluid temperature is fixed rather than passed in
+pub const DEFAULT_FLUID_TEMP_DEGREE_CELSIUS: f64 = 21.0;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// A generic base class or struct for therminol vp 1 
 /// or dowtherm A pipe like components
 // i want to create an interface for a generic 
 // component, which could be a pipe or a custom component
+//
+// with the serde feature, this can be read from or written to 
+// a file (eg. json) so you can keep your loop geometry out of the code
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PipeProperties {
     /// name of the therminol pipe
     pub _name: String,
@@ -143,6 +163,13 @@ pub struct PipeProperties {
 
 /// A generic base class for therminol or dowtherm A
 /// components with user defined fldk terms
+///
+/// With the serde feature, only the geometric and fluid fields
+/// are serialised, the custom_k and custom_darcy functions cannot be.
+/// So to read it back from a file, deserialise into a
+/// CustomComponentPropertiesBuilder, and then reattach the functions
+/// before calling build()
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CustomComponentProperties{
     /// name of the user specified component
     pub _name: String,
@@ -173,6 +200,7 @@ pub struct CustomComponentProperties{
     /// However, it is not a mutable reference so 
     /// it should be thread safe in case you want to do
     /// parallel computation
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub custom_k: &'static dyn Fn(f64) -> f64,
 
     /// for custom fldk component, i have a custom 
@@ -185,6 +213,7 @@ pub struct CustomComponentProperties{
     /// However, it is not a mutable reference so 
     /// it should be thread safe in case you want to do
     /// parallel computation
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub custom_darcy: &'static dyn Fn(f64,f64) -> f64,
 
     /// internal pressure term, in case you want to have
@@ -197,6 +226,123 @@ pub struct CustomComponentProperties{
 
 }
 
+/// Builder for CustomComponentProperties
+///
+/// it has the same fields as CustomComponentProperties, 
+/// except that custom_k and custom_darcy are optional
+/// and have to be attached before build()
+///
+/// This is mainly so that you can deserialise the geometry 
+/// of a custom component from a file (serde feature),
+/// since the functions cannot be stored in a file
+///
+/// ```rust
+/// use fluid_mechanics_rust::therminol_component::*;
+/// use uom::si::f64::*;
+/// use uom::si::length::meter;
+/// use uom::si::area::square_meter;
+/// use uom::si::angle::degree;
+/// use uom::si::pressure::pascal;
+///
+/// fn custom_darcy(_reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+///     return 0.0;
+/// }
+///
+/// fn custom_k(reynolds_number: f64) -> f64 {
+///     return 18.0 + 93000.0/reynolds_number.powf(1.35);
+/// }
+///
+/// let builder = CustomComponentPropertiesBuilder {
+///     _name: "flowmeter_40".to_string(),
+///     hydraulic_diameter: Length::new::<meter>(2.79e-2),
+///     xs_area: Area::new::<square_meter>(6.11e-4),
+///     component_length: Length::new::<meter>(0.36),
+///     absolute_roughness: Length::new::<meter>(1.5e-5),
+///     incline_angle: Angle::new::<degree>(90.0),
+///     internal_pressure: Pressure::new::<pascal>(0.0),
+///     custom_k: None,
+///     custom_darcy: None,
+/// };
+///
+/// let flowmeter_properties = builder
+///     .custom_darcy(&custom_darcy)
+///     .custom_k(&custom_k)
+///     .build();
+///
+/// assert_eq!((flowmeter_properties.custom_k)(1000.0), custom_k(1000.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct CustomComponentPropertiesBuilder {
+    /// name of the user specified component
+    pub _name: String,
+    /// component hydraulic diameter, usually 4A/P
+    pub hydraulic_diameter: Length,
+    /// cross sectional area of the user specified
+    /// component
+    pub xs_area: Area,
+    /// component length for user specified component
+    pub component_length: Length,
+    /// component absolute roughness, not relative roughness
+    pub absolute_roughness: Length,
+    /// incline angle of the component
+    pub incline_angle: Angle,
+    /// custom form loss function, must be attached before build()
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub custom_k: Option<&'static dyn Fn(f64) -> f64>,
+    /// custom darcy friction factor function, 
+    /// must be attached before build()
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub custom_darcy: Option<&'static dyn Fn(f64,f64) -> f64>,
+    /// internal pressure term
+    pub internal_pressure: Pressure,
+}
+
+impl CustomComponentPropertiesBuilder {
+
+    /// attaches the custom form loss function 
+    pub fn custom_k(mut self, 
+                    custom_k: &'static dyn Fn(f64) -> f64) -> Self {
+        self.custom_k = Some(custom_k);
+        return self;
+    }
+
+    /// attaches the custom darcy friction factor function 
+    pub fn custom_darcy(mut self, 
+                        custom_darcy: &'static dyn Fn(f64,f64) -> f64) -> Self {
+        self.custom_darcy = Some(custom_darcy);
+        return self;
+    }
+
+    /// builds the CustomComponentProperties,
+    /// panics if custom_k or custom_darcy were not attached
+    pub fn build(self) -> CustomComponentProperties {
+
+        let custom_k = match self.custom_k {
+            Some(custom_k) => custom_k,
+            None => panic!("CustomComponentPropertiesBuilder: \n
+                           custom_k not attached before build()"),
+        };
+
+        let custom_darcy = match self.custom_darcy {
+            Some(custom_darcy) => custom_darcy,
+            None => panic!("CustomComponentPropertiesBuilder: \n
+                           custom_darcy not attached before build()"),
+        };
+
+        return CustomComponentProperties {
+            _name: self._name,
+            hydraulic_diameter: self.hydraulic_diameter,
+            xs_area: self.xs_area,
+            component_length: self.component_length,
+            absolute_roughness: self.absolute_roughness,
+            incline_angle: self.incline_angle,
+            custom_k,
+            custom_darcy,
+            internal_pressure: self.internal_pressure,
+        };
+    }
+}
+
 /// A trait (or interface) for getting fluid properties from
 /// temperature or enthalpy
 ///
@@ -275,6 +421,16 @@ pub trait StandardPipeProperties : FluidProperties {
            incline_angle_degrees: f64,
            form_loss_k: f64) -> Self;
 
+    /// This is the constructor with uom quantities, so you 
+    /// don't need to remember which lengths are in meters and 
+    /// which are in millimeters
+    fn new_typed(name: String,
+                 hydraulic_diameter: Length,
+                 component_length: Length,
+                 absolute_roughness: Length,
+                 incline_angle: Angle,
+                 form_loss_k: f64) -> Self;
+
     /// Just a function to get cross sectional area
     fn get_cross_sectional_area(&self) -> Area;
     /// function to get the internal pressure or
@@ -312,6 +468,18 @@ pub trait StandardCustomComponentProperties : FluidProperties {
            custom_darcy: &'static dyn Fn(f64,f64) -> f64,
            custom_k: &'static dyn Fn(f64) -> f64) -> Self;
 
+    /// This is the constructor with uom quantities, so you 
+    /// don't need to remember which lengths are in meters and 
+    /// which are in millimeters
+    fn new_typed(name: String,
+                 hydraulic_diameter: Length,
+                 cross_sectional_area: Area,
+                 component_length: Length,
+                 absolute_roughness: Length,
+                 incline_angle: Angle,
+                 custom_darcy: &'static dyn Fn(f64,f64) -> f64,
+                 custom_k: &'static dyn Fn(f64) -> f64) -> Self;
+
     /// Just a function to get cross sectional area
     fn get_cross_sectional_area(&self) -> Area;
     /// function to get the internal pressure or
@@ -326,3 +494,171 @@ pub trait StandardCustomComponentProperties : FluidProperties {
         &self, fluid_temp: ThermodynamicTemperature) -> Pressure;
 
 }
+
+/// A dowtherm A component, which is either a pipe or 
+/// a custom component
+///
+/// this allows you to put pipes and custom components from
+/// the factory into a single vector, eg. a whole branch of CIET
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// use fluid_mechanics_rust::therminol_component::*;
+///
+/// let components: Vec<DowthermAComponent> = vec![
+///     factory::Pipe6a::get().into(),
+///     factory::StaticMixer41::get().into(),
+/// ];
+///
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+/// let mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+///
+/// let mut total_pressure_change = components[0].from_mass_rate(
+///     mass_rate, fluid_temp);
+/// total_pressure_change += components[1].from_mass_rate(
+///     mass_rate, fluid_temp);
+///
+/// // same as calling the concrete components directly
+/// let expected_pressure_change = 
+///     factory::Pipe6a::get().from_mass_rate(mass_rate, fluid_temp) +
+///     factory::StaticMixer41::get().from_mass_rate(mass_rate, fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(total_pressure_change.value,
+///                             expected_pressure_change.value,
+///                             max_relative = 1e-12);
+/// ```
+pub enum DowthermAComponent {
+    /// dowtherm A pipe, with fixed form losses
+    Pipe(therminol_pipe::DowthermAPipe),
+    /// dowtherm A component with user defined fldk
+    CustomComponent(custom_therminol_component::DowthermACustomComponent),
+}
+
+impl From<therminol_pipe::DowthermAPipe> for DowthermAComponent {
+    fn from(pipe: therminol_pipe::DowthermAPipe) -> Self {
+        return Self::Pipe(pipe);
+    }
+}
+
+impl From<custom_therminol_component::DowthermACustomComponent> 
+for DowthermAComponent {
+    fn from(
+        custom_component: custom_therminol_component::DowthermACustomComponent
+        ) -> Self {
+        return Self::CustomComponent(custom_component);
+    }
+}
+
+impl DowthermAComponent {
+
+    /// calculates pressure change from mass flowrate
+    /// for whichever component is wrapped
+    ///
+    /// same as CalcPressureChange::from_mass_rate, but you don't 
+    /// need to import the trait
+    pub fn pressure_change_from_mass_rate(
+        &self, 
+        fluid_mass_flowrate: MassRate,
+        fluid_temp: ThermodynamicTemperature) -> Pressure {
+        return CalcPressureChange::from_mass_rate(
+            self, fluid_mass_flowrate, fluid_temp);
+    }
+
+    /// calculates mass flowrate from pressure change
+    /// for whichever component is wrapped
+    ///
+    /// same as CalcPressureChange::to_mass_rate, but you don't 
+    /// need to import the trait
+    pub fn mass_rate_from_pressure_change(
+        &self,
+        pressure_change: Pressure,
+        fluid_temp: ThermodynamicTemperature) -> MassRate {
+        return CalcPressureChange::to_mass_rate(
+            self, pressure_change, fluid_temp);
+    }
+}
+
+impl CalcPressureChange for DowthermAComponent {
+
+    fn from_mass_rate(&self, fluid_mass_flowrate: MassRate,
+                      fluid_temp: ThermodynamicTemperature) -> Pressure {
+        match self {
+            Self::Pipe(pipe) => 
+                pipe.from_mass_rate(fluid_mass_flowrate, fluid_temp),
+            Self::CustomComponent(custom_component) => 
+                custom_component.from_mass_rate(
+                    fluid_mass_flowrate, fluid_temp),
+        }
+    }
+
+    fn to_mass_rate(&self, pressure_change: Pressure,
+                    fluid_temp: ThermodynamicTemperature) -> MassRate {
+        match self {
+            Self::Pipe(pipe) => 
+                pipe.to_mass_rate(pressure_change, fluid_temp),
+            Self::CustomComponent(custom_component) => 
+                custom_component.to_mass_rate(
+                    pressure_change, fluid_temp),
+        }
+    }
+}
+
+impl std::fmt::Display for DowthermAComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pipe(pipe) => write!(f, "{}", pipe),
+            Self::CustomComponent(custom_component) => 
+                write!(f, "{}", custom_component),
+        }
+    }
+}
+
+/// which way the fluid flows through an inclined component,
+/// for use with signed_incline
+///
+/// the incline angle convention in this crate is that a
+/// positive angle means the flow goes upward, ie. the fluid 
+/// loses pressure climbing up the component. 
+/// Components are usually measured bottom to top, so if the
+/// flow goes top to bottom instead, the angle must be flipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Orientation {
+    /// flow goes bottom to top, the angle is used as is
+    FlowUpward,
+    /// flow goes top to bottom, the angle is flipped
+    FlowDownward,
+}
+
+/// returns the incline angle to give a component given its
+/// base angle (measured bottom to top) and the flow orientation,
+/// so you don't have to add 180 degrees by hand for 
+/// top to bottom flow
+///
+/// for FlowDownward i return the negative of the base angle, 
+/// sin(-theta) = sin(theta + 180 degrees), so the hydrostatic 
+/// pressure change is the same as adding 180 degrees,
+/// but the angle stays between -90 and 90 degrees
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::angle::degree;
+/// use fluid_mechanics_rust::therminol_component::*;
+///
+/// let base_angle = Angle::new::<degree>(49.743387);
+///
+/// let incline_angle = signed_incline(base_angle, 
+///                                    Orientation::FlowDownward);
+///
+/// assert_eq!(incline_angle.get::<degree>(), -49.743387);
+/// ```
+pub fn signed_incline(base_angle: Angle, orientation: Orientation) -> Angle {
+    match orientation {
+        Orientation::FlowUpward => return base_angle,
+        Orientation::FlowDownward => return -base_angle,
+    }
+}
@@ -0,0 +1,429 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+use crate::fluid_component_calculation::custom_component_calc;
+use crate::therminol_component::*;
+use crate::fluid_component_calculation::{FluidComponent,
+    TemperatureDependentFluidComponent};
+use dowtherm_a_properties;
+
+use uom::si::length::{meter,millimeter};
+use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::mass_rate::kilogram_per_second;
+use uom::si::angle::degree;
+use uom::si::acceleration::meter_per_second_squared;
+use uom::si::area::square_meter;
+
+/// A dowtherm A custom component where the form loss K depends 
+/// on both Re and fluid temperature
+///
+/// this is the same as DowthermACustomComponent, except that 
+/// custom_k takes the fluid temperature as well, so that you can 
+/// put in property ratio corrections like (mu_wall/mu_bulk)^0.14
+///
+/// the component's fluid_temp is passed into custom_k whenever
+/// the fldk is calculated
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use uom::si::thermodynamic_temperature::degree_celsius;
+/// use fluid_mechanics_rust::therminol_component::*;
+///
+/// fn custom_darcy(_reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+///     return 0.0;
+/// }
+///
+/// fn custom_k(reynolds_number: f64, 
+///             fluid_temp: ThermodynamicTemperature) -> f64 {
+///     if reynolds_number == 0.0 {
+///         return 0.0;
+///     }
+///     let temperature_correction = 
+///         (fluid_temp.get::<degree_celsius>()/21.0).powf(0.14);
+///     let custom_k_value = temperature_correction *
+///         (18.0 + 93000.0/reynolds_number.abs().powf(1.35));
+///     return custom_k_value * reynolds_number.signum();
+/// }
+///
+/// let flowmeter = TemperatureAwareCustomComponent::new(
+///     "flowmeter".to_string(),
+///     2.79e-2, // component diameter in meters
+///     6.11e-4, // component area in square meters
+///     0.36, // component length in meters
+///     0.015, // roughness in millimeters
+///     0.0, // incline angle in degrees
+///     &custom_darcy,
+///     &custom_k);
+///
+/// let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+/// let mass_rate = MassRate::new::<kilogram_per_second>(0.15);
+///
+/// let pressure_change = flowmeter.from_mass_rate(mass_rate, fluid_temp);
+///
+/// let test_mass_rate = flowmeter.to_mass_rate(pressure_change, fluid_temp);
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(test_mass_rate.value, mass_rate.value,
+///     max_relative = 1e-6);
+/// ```
+pub struct TemperatureAwareCustomComponent {
+    /// name of the user specified component
+    pub _name: String,
+    /// component hydraulic diameter, usually 4A/P
+    pub hydraulic_diameter: Length,
+    /// cross sectional area of the component
+    pub xs_area: Area,
+    /// component length
+    pub component_length: Length,
+    /// component absolute roughness, not relative roughness
+    pub absolute_roughness: Length,
+    /// incline angle of the component
+    pub incline_angle: Angle,
+
+    /// custom form loss function of Re and fluid temperature,
+    /// reverse flow logic is up to the user as with 
+    /// DowthermACustomComponent
+    pub custom_k: &'static dyn Fn(f64, ThermodynamicTemperature) -> f64,
+
+    /// custom darcy friction factor function of Re and 
+    /// roughness ratio
+    pub custom_darcy: &'static dyn Fn(f64,f64) -> f64,
+
+    /// internal pressure term, in case you want to have
+    /// a pump or something
+    pub internal_pressure: Pressure,
+
+    /// fluid temperature, this is passed into custom_k
+    /// when the component is treated as a FluidComponent,
+    /// defaults to DEFAULT_FLUID_TEMP_DEGREE_CELSIUS
+    pub fluid_temp: ThermodynamicTemperature,
+
+    /// mass flowrate used when this is treated as a 
+    /// fluid_component_calculation::FluidComponent
+    pub mass_flowrate: MassRate,
+}
+
+impl TemperatureAwareCustomComponent {
+
+    /// constructor, same inputs as DowthermACustomComponent
+    /// except for the temperature dependent custom_k
+    pub fn new(name: String,
+               hydraulic_diameter_meters: f64,
+               cross_sectional_area_meters_sq: f64,
+               component_length_meters: f64,
+               absolute_roughness_millimeters: f64,
+               incline_angle_degrees: f64,
+               custom_darcy: &'static dyn Fn(f64,f64) -> f64,
+               custom_k: &'static dyn Fn(f64, ThermodynamicTemperature) -> f64) 
+        -> Self {
+
+        return Self {
+            _name: name,
+            hydraulic_diameter: Length::new::<meter>(hydraulic_diameter_meters),
+            xs_area: Area::new::<square_meter>(cross_sectional_area_meters_sq),
+            component_length: Length::new::<meter>(component_length_meters),
+            absolute_roughness: Length::new::<millimeter>(
+                absolute_roughness_millimeters),
+            incline_angle: Angle::new::<degree>(incline_angle_degrees),
+            custom_k,
+            custom_darcy,
+            internal_pressure: Pressure::new::<pascal>(0.0),
+            fluid_temp: ThermodynamicTemperature::new::<degree_celsius>(
+                DEFAULT_FLUID_TEMP_DEGREE_CELSIUS),
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
+    }
+
+    /// hydrostatic pressure change of the component
+    /// at a given fluid temperature
+    pub fn get_hydrostatic_pressure_change(
+        &self, fluid_temp: ThermodynamicTemperature) -> Pressure {
+
+        let fluid_density = 
+            <Self as FluidProperties>::density(fluid_temp);
+
+        let g: Acceleration = 
+            Acceleration::new::<meter_per_second_squared>(-9.81);
+        let delta_h: Length = self.component_length*self.incline_angle.sin();
+
+        return fluid_density * g * delta_h;
+    }
+
+    /// pressure loss at a given mass flowrate and fluid temperature,
+    ///
+    /// the fluid temperature is fixed into custom_k here so that 
+    /// the fldk calculation only sees a function of Re
+    pub fn get_pressure_loss_at_temp(
+        &self, 
+        fluid_mass_flowrate: MassRate,
+        fluid_temp: ThermodynamicTemperature) -> Pressure {
+
+        let custom_k = self.custom_k;
+        let custom_k_at_fluid_temp = |reynolds_number: f64| -> f64 {
+            return custom_k(reynolds_number, fluid_temp);
+        };
+
+        return custom_component_calc::CalcPressureLoss::from_mass_rate(
+            fluid_mass_flowrate,
+            self.xs_area,
+            self.hydraulic_diameter,
+            <Self as FluidProperties>::viscosity(fluid_temp),
+            <Self as FluidProperties>::density(fluid_temp),
+            self.component_length,
+            self.absolute_roughness,
+            self.custom_darcy,
+            &custom_k_at_fluid_temp);
+    }
+
+    /// mass flowrate at a given pressure loss and fluid temperature
+    pub fn get_mass_flowrate_at_temp(
+        &self, 
+        pressure_loss: Pressure,
+        fluid_temp: ThermodynamicTemperature) -> MassRate {
+
+        let custom_k = self.custom_k;
+        let custom_k_at_fluid_temp = |reynolds_number: f64| -> f64 {
+            return custom_k(reynolds_number, fluid_temp);
+        };
+
+        return custom_component_calc::CalcPressureLoss::to_mass_rate(
+            pressure_loss,
+            self.xs_area,
+            self.hydraulic_diameter,
+            <Self as FluidProperties>::viscosity(fluid_temp),
+            <Self as FluidProperties>::density(fluid_temp),
+            self.component_length,
+            self.absolute_roughness,
+            self.custom_darcy,
+            &custom_k_at_fluid_temp);
+    }
+}
+
+impl FluidProperties for TemperatureAwareCustomComponent {
+    fn density(fluid_temp: ThermodynamicTemperature) -> MassDensity {
+        return dowtherm_a_properties::getDowthermADensity(fluid_temp);
+    }
+
+    fn viscosity(
+        fluid_temp: ThermodynamicTemperature) -> DynamicViscosity{
+        return dowtherm_a_properties::getDowthermAViscosity(fluid_temp);
+    }
+
+    fn enthalpy(fluid_temp: ThermodynamicTemperature) -> AvailableEnergy{
+        return dowtherm_a_properties::getDowthermAEnthalpy(fluid_temp);
+    }
+
+    fn specific_heat_capacity(
+        fluid_temp: ThermodynamicTemperature) -> SpecificHeatCapacity{
+        return dowtherm_a_properties::
+            getDowthermAConstantPressureSpecificHeatCapacity(
+            fluid_temp);
+    }
+
+    fn thermal_conductivity(
+        fluid_temp: ThermodynamicTemperature) -> ThermalConductivity{
+        return dowtherm_a_properties::
+            getDowthermAThermalConductivity(fluid_temp);
+    }
+
+    fn get_temperature_from_enthalpy(
+        fluid_enthalpy: AvailableEnergy) -> ThermodynamicTemperature{
+        return dowtherm_a_properties::
+            get_temperature_from_enthalpy(fluid_enthalpy);
+    }
+}
+
+impl CalcPressureChange for TemperatureAwareCustomComponent {
+
+    fn from_mass_rate(&self, fluid_mass_flowrate: MassRate,
+                      fluid_temp: ThermodynamicTemperature) -> Pressure {
+
+        // Pressure Change = - pressure loss + hydrostatic pressure +
+        // source pressure
+        let pressure_loss = 
+            self.get_pressure_loss_at_temp(fluid_mass_flowrate, fluid_temp);
+
+        return -pressure_loss +
+            self.get_hydrostatic_pressure_change(fluid_temp) +
+            self.internal_pressure;
+    }
+
+    fn to_mass_rate(&self, pressure_change: Pressure,
+                    fluid_temp: ThermodynamicTemperature) -> MassRate {
+
+        // pressure loss  = - pressure change + hydrostatic pressure +
+        // source pressure
+        let pressure_loss = 
+            -pressure_change +
+            self.get_hydrostatic_pressure_change(fluid_temp) +
+            self.internal_pressure;
+
+        return self.get_mass_flowrate_at_temp(pressure_loss, fluid_temp);
+    }
+}
+
+/// TemperatureAwareCustomComponent can be used as a 
+/// fluid_component_calculation::FluidComponent, 
+/// the fluid temperature is fixed at self.fluid_temp, which is
+/// also what custom_k sees
+impl FluidComponent for TemperatureAwareCustomComponent {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+        return self.get_mass_flowrate_at_temp(pressure_loss, self.fluid_temp);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return self.get_pressure_loss_at_temp(mass_flowrate, self.fluid_temp);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return self.xs_area;
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return self.xs_area;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return <Self as FluidProperties>::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return <Self as FluidProperties>::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return <Self as FluidProperties>::density(self.fluid_temp);
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return <Self as FluidProperties>::density(self.fluid_temp);
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.incline_angle;
+    }
+
+    fn get_hydrostatic_pressure_change(&mut self) -> Pressure {
+        return TemperatureAwareCustomComponent::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_hydrostatic_pressure_change_immutable(&self) -> Pressure {
+        return TemperatureAwareCustomComponent::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.internal_pressure;
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.internal_pressure;
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.internal_pressure = internal_pressure;
+    }
+}
+
+/// changing the fluid temperature changes density, viscosity
+/// and the form loss K
+impl TemperatureDependentFluidComponent for TemperatureAwareCustomComponent {
+
+    fn get_fluid_temp(&self) -> ThermodynamicTemperature {
+        return self.fluid_temp;
+    }
+
+    fn set_fluid_temp(&mut self, fluid_temp: ThermodynamicTemperature) {
+        self.fluid_temp = fluid_temp;
+    }
+}
@@ -1,9 +1,13 @@
 use crate::fluid_component_calculation::standard_pipe_calc;
 use crate::therminol_component::*;
+use crate::fluid_component_calculation::{FluidComponent,
+    TemperatureDependentFluidComponent};
 use dowtherm_a_properties;
 
 use uom::si::length::{meter,millimeter};
 use uom::si::pressure::pascal;
+use uom::si::thermodynamic_temperature::degree_celsius;
+use uom::si::mass_rate::kilogram_per_second;
 use uom::si::angle::degree;
 use uom::si::acceleration::meter_per_second_squared;
 use uom::typenum::P2;
@@ -150,6 +154,15 @@ pub struct DowthermAPipe {
     /// all are user specified through the constructor
     /// 
     pub dowtherm_pipe_properties: PipeProperties,
+
+    /// fluid temperature used when this is treated as a 
+    /// fluid_component_calculation::FluidComponent,
+    /// defaults to DEFAULT_FLUID_TEMP_DEGREE_CELSIUS
+    pub fluid_temp: ThermodynamicTemperature,
+
+    /// mass flowrate used when this is treated as a 
+    /// fluid_component_calculation::FluidComponent
+    pub mass_flowrate: MassRate,
 }
 /// dowtherm A pipe has methods to obtain
 /// thermophysical properties using the Dowtherm a correlations
@@ -206,20 +219,41 @@ impl StandardPipeProperties for DowthermAPipe {
             absolute_roughness_millimeters);
         let calculated_incline_angle = Angle::new::<degree>(
             incline_angle_degrees);
+
+        return Self::new_typed(name,
+                               calculated_hydraulic_diameter,
+                               calculated_component_length,
+                               calculated_absolute_roughness,
+                               calculated_incline_angle,
+                               form_loss_k);
+    }
+
+    fn new_typed(name: String,
+                 hydraulic_diameter: Length,
+                 component_length: Length,
+                 absolute_roughness: Length,
+                 incline_angle: Angle,
+                 form_loss_k: f64) -> Self {
+
         let calculated_internal_pressure = Pressure::new::<pascal>(
             0.0);
 
         let custom_pipe_properties = PipeProperties {
             _name: name,
-            hydraulic_diameter: calculated_hydraulic_diameter,
-            component_length: calculated_component_length,
-            absolute_roughness: calculated_absolute_roughness,
-            incline_angle: calculated_incline_angle,
+            hydraulic_diameter,
+            component_length,
+            absolute_roughness,
+            incline_angle,
             form_loss_k: form_loss_k,
             internal_pressure: calculated_internal_pressure,
         };
 
-        return Self { dowtherm_pipe_properties : custom_pipe_properties };
+        return Self { 
+            dowtherm_pipe_properties : custom_pipe_properties,
+            fluid_temp: ThermodynamicTemperature::new::<degree_celsius>(
+                DEFAULT_FLUID_TEMP_DEGREE_CELSIUS),
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+        };
     }
 
     fn get_cross_sectional_area(&self) -> Area {
@@ -374,5 +408,313 @@ impl CalcPressureChange for DowthermAPipe {
     }
 }
 
+/// Builder for DowthermAPipe with unit safe setters
+///
+/// StandardPipeProperties::new takes bare f64 values,
+/// with diameter and length in meters but roughness in millimeters
+/// and angle in degrees. It is easy to mix these up, so this builder
+/// takes uom quantities instead
+///
+/// hydraulic_diameter and component_length must be set, otherwise
+/// build() will panic. The rest default to zero 
+/// (smooth, horizontal pipe with no form losses or internal pressure)
+///
+/// ```rust
+/// use fluid_mechanics_rust::therminol_component::therminol_pipe::*;
+/// use uom::si::f64::*;
+/// use uom::si::length::{meter, millimeter};
+/// use uom::si::angle::degree;
+///
+/// let static_mixer_pipe_6a: DowthermAPipe = DowthermAPipeBuilder::new()
+///     .name("static_mixer_pipe_6a")
+///     .hydraulic_diameter(Length::new::<meter>(2.79e-2))
+///     .component_length(Length::new::<meter>(0.1526))
+///     .absolute_roughness(Length::new::<millimeter>(0.015))
+///     .incline_angle(Angle::new::<degree>(51.526384))
+///     .form_loss_k(5.05)
+///     .build();
+///
+/// extern crate approx;
+/// approx::assert_relative_eq!(
+///     static_mixer_pipe_6a.dowtherm_pipe_properties.absolute_roughness
+///     .get::<meter>(), 1.5e-5, max_relative = 1e-12);
+/// ```
+pub struct DowthermAPipeBuilder {
+    name: String,
+    hydraulic_diameter: Option<Length>,
+    component_length: Option<Length>,
+    absolute_roughness: Length,
+    incline_angle: Angle,
+    form_loss_k: f64,
+    internal_pressure: Pressure,
+}
+
+impl Default for DowthermAPipeBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl DowthermAPipeBuilder {
+
+    /// starts building a dowtherm A pipe
+    pub fn new() -> Self {
+        return Self {
+            name: String::new(),
+            hydraulic_diameter: None,
+            component_length: None,
+            absolute_roughness: Length::new::<meter>(0.0),
+            incline_angle: Angle::new::<degree>(0.0),
+            form_loss_k: 0.0,
+            internal_pressure: Pressure::new::<pascal>(0.0),
+        };
+    }
+
+    /// sets the pipe name
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        return self;
+    }
+
+    /// sets the pipe hydraulic diameter (required)
+    pub fn hydraulic_diameter(mut self, hydraulic_diameter: Length) -> Self {
+        self.hydraulic_diameter = Some(hydraulic_diameter);
+        return self;
+    }
+
+    /// sets the pipe length (required)
+    pub fn component_length(mut self, component_length: Length) -> Self {
+        self.component_length = Some(component_length);
+        return self;
+    }
+
+    /// sets the absolute roughness, not relative roughness
+    pub fn absolute_roughness(mut self, absolute_roughness: Length) -> Self {
+        self.absolute_roughness = absolute_roughness;
+        return self;
+    }
+
+    /// sets the incline angle, positive is upwards
+    pub fn incline_angle(mut self, incline_angle: Angle) -> Self {
+        self.incline_angle = incline_angle;
+        return self;
+    }
+
+    /// sets the fixed form loss K for the pipe
+    pub fn form_loss_k(mut self, form_loss_k: f64) -> Self {
+        self.form_loss_k = form_loss_k;
+        return self;
+    }
+
+    /// sets the internal pressure source term 
+    pub fn internal_pressure(mut self, internal_pressure: Pressure) -> Self {
+        self.internal_pressure = internal_pressure;
+        return self;
+    }
+
+    /// builds the DowthermAPipe,
+    /// panics if hydraulic diameter or component length were not set
+    pub fn build(self) -> DowthermAPipe {
+
+        let hydraulic_diameter = match self.hydraulic_diameter {
+            Some(hydraulic_diameter) => hydraulic_diameter,
+            None => panic!("DowthermAPipeBuilder: \n
+                           hydraulic_diameter not set before build()"),
+        };
+
+        let component_length = match self.component_length {
+            Some(component_length) => component_length,
+            None => panic!("DowthermAPipeBuilder: \n
+                           component_length not set before build()"),
+        };
+
+        let mut dowtherm_pipe = <DowthermAPipe as StandardPipeProperties>::
+            new_typed(self.name,
+                      hydraulic_diameter,
+                      component_length,
+                      self.absolute_roughness,
+                      self.incline_angle,
+                      self.form_loss_k);
+
+        dowtherm_pipe.dowtherm_pipe_properties.internal_pressure = 
+            self.internal_pressure;
+
+        return dowtherm_pipe;
+    }
+}
+
+/// prints the pipe parameters in readable units, 
+/// eg. for debugging a loop made of many factory components
+impl std::fmt::Display for DowthermAPipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+
+        let pipe_properties = &self.dowtherm_pipe_properties;
+
+        return write!(f, 
+                      "DowthermAPipe {}: hydraulic diameter {:.3} mm, \
+                      length {:.4} m, roughness {:.4} mm, \
+                      incline angle {:.2} deg, form loss K {}, \
+                      internal pressure {:.2} Pa",
+                      pipe_properties._name,
+                      pipe_properties.hydraulic_diameter.get::<millimeter>(),
+                      pipe_properties.component_length.get::<meter>(),
+                      pipe_properties.absolute_roughness.get::<millimeter>(),
+                      pipe_properties.incline_angle.get::<degree>(),
+                      pipe_properties.form_loss_k,
+                      pipe_properties.internal_pressure.get::<pascal>());
+    }
+}
+
+/// DowthermAPipe can also be used as a 
+/// fluid_component_calculation::FluidComponent, so that it can be 
+/// put into fluid component collections alongside other components
+///
+/// the fluid temperature is fixed at self.fluid_temp, and all
+/// pressure change calculations go through CalcPressureChange
+/// at that temperature
+impl FluidComponent for DowthermAPipe {
+
+    fn get_mass_flowrate(&mut self) -> MassRate {
+        return self.mass_flowrate;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: MassRate) {
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_change_immutable(
+        &self, pressure_change: Pressure) -> MassRate {
+        return CalcPressureChange::to_mass_rate(
+            self, pressure_change, self.fluid_temp);
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self, pressure_loss: Pressure) -> MassRate {
+
+        // pressure_change = -pressure_loss + hydrostatic_pressure_increase 
+        // + pressure source
+        let pressure_change = -pressure_loss + 
+            <Self as StandardPipeProperties>::get_hydrostatic_pressure_change(
+                self, self.fluid_temp) +
+            self.get_internal_pressure_term();
+
+        return CalcPressureChange::to_mass_rate(
+            self, pressure_change, self.fluid_temp);
+    }
+
+    fn get_pressure_loss(&mut self) -> Pressure {
+        return self.get_pressure_loss_immutable(self.mass_flowrate);
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: Pressure) {
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss);
+    }
+
+    fn get_pressure_loss_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+
+        let pressure_change = CalcPressureChange::from_mass_rate(
+            self, mass_flowrate, self.fluid_temp);
+
+        // pressure_loss = -pressure_change + hydrostatic_pressure_increase 
+        // + pressure source
+        return -pressure_change + 
+            <Self as StandardPipeProperties>::get_hydrostatic_pressure_change(
+                self, self.fluid_temp) +
+            self.get_internal_pressure_term();
+    }
+
+    fn get_pressure_change_immutable(
+        &self, mass_flowrate: MassRate) -> Pressure {
+        return CalcPressureChange::from_mass_rate(
+            self, mass_flowrate, self.fluid_temp);
+    }
+
+    fn get_cross_sectional_area(&mut self) -> Area {
+        return <Self as StandardPipeProperties>::get_cross_sectional_area(self);
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> Area {
+        return <Self as StandardPipeProperties>::get_cross_sectional_area(self);
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> Length {
+        return self.dowtherm_pipe_properties.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> Length {
+        return self.dowtherm_pipe_properties.hydraulic_diameter;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> DynamicViscosity {
+        return DowthermAPipe::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> DynamicViscosity {
+        return DowthermAPipe::viscosity(self.fluid_temp);
+    }
+
+    fn get_fluid_density(&mut self) -> MassDensity {
+        return DowthermAPipe::density(self.fluid_temp);
+    }
+
+    fn get_fluid_density_immutable(&self) -> MassDensity {
+        return DowthermAPipe::density(self.fluid_temp);
+    }
+
+    fn get_component_length(&mut self) -> Length {
+        return self.dowtherm_pipe_properties.component_length;
+    }
+
+    fn get_component_length_immutable(&self) -> Length {
+        return self.dowtherm_pipe_properties.component_length;
+    }
+
+    fn get_incline_angle(&mut self) -> Angle {
+        return self.dowtherm_pipe_properties.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> Angle {
+        return self.dowtherm_pipe_properties.incline_angle;
+    }
+
+    fn get_hydrostatic_pressure_change(&mut self) -> Pressure {
+        return <Self as StandardPipeProperties>::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_hydrostatic_pressure_change_immutable(&self) -> Pressure {
+        return <Self as StandardPipeProperties>::get_hydrostatic_pressure_change(
+            self, self.fluid_temp);
+    }
+
+    fn get_internal_pressure_source(&mut self) -> Pressure {
+        return self.get_internal_pressure_term();
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> Pressure {
+        return self.get_internal_pressure_term();
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure: Pressure) {
+        self.set_internal_pressure_term(internal_pressure.get::<pascal>());
+    }
+}
+
+/// the fluid temperature of DowthermAPipe can be changed so that
+/// density and viscosity are recalculated, 
+/// eg. for a loop with a temperature profile
+impl TemperatureDependentFluidComponent for DowthermAPipe {
 
+    fn get_fluid_temp(&self) -> ThermodynamicTemperature {
+        return self.fluid_temp;
+    }
 
+    fn set_fluid_temp(&mut self, fluid_temp: ThermodynamicTemperature) {
+        self.fluid_temp = fluid_temp;
+    }
+}
@@ -0,0 +1,132 @@
+// This library was developed for use in my PhD thesis under supervision 
+// of Professor Per F. Peterson. It is part of a thermal hydraulics
+// library in Rust that is released under the GNU General Public License
+// v 3.0. This is partly due to the fact that some of the libraries 
+// inherit from GeN-Foam and OpenFOAM, both licensed under GNU General
+// Public License v3.0.
+//
+// As such, the entire library is released under GNU GPL v3.0. It is a strong 
+// copyleft license which means you cannot use it in proprietary software.
+//
+//
+// License
+//    This file is part of fluid_mechanics_rust, a partial library of the
+//    thermal hydraulics library written in rust meant to help with the
+//    fluid mechanics aspects of the calculations
+//     
+//    Copyright (C) 2022-2023  Theodore Kay Chen Ong, Singapore Nuclear
+//    Research and Safety Initiative, Per F. Peterson, University of 
+//    California, Berkeley Thermal Hydraulics Laboratory
+//
+//    fluid_mechanics_rust is free software; you can redistribute it and/or modify it
+//    under the terms of the GNU General Public License as published by the
+//    Free Software Foundation; either version 2 of the License, or (at your
+//    option) any later version.
+//
+//    fluid_mechanics_rust is distributed in the hope that it will be useful, but WITHOUT
+//    ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or
+//    FITNESS FOR A PARTICULAR PURPOSE.  See the GNU General Public License
+//    for more details.
+//
+//    This library is part of a thermal hydraulics library in rust
+//    and contains some code copied from GeN-Foam, and OpenFOAM derivative.
+//    This offering is not approved or endorsed by the OpenFOAM Foundation nor
+//    OpenCFD Limited, producer and distributor of the OpenFOAM(R)software via
+//    www.openfoam.com, and owner of the OPENFOAM(R) and OpenCFD(R) trademarks.
+//    Nor is it endorsed by the authors and owners of GeN-Foam.
+//
+//    You should have received a copy of the GNU General Public License
+//    along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+// © All rights reserved. Theodore Kay Chen Ong,
+// Singapore Nuclear Research and Safety Initiative,
+// Per F. Peterson,
+// University of California, Berkeley Thermal Hydraulics Laboratory
+//
+// Main author of the code: Theodore Kay Chen Ong, supervised by
+// Professor Per F. Peterson
+
+// here is a simple time series driver for fluid component 
+// collections, eg. to see how the flow responds to a pump 
+// pressure which changes with time
+
+use uom::si::f64::*;
+use crate::fluid_component_collection::FluidComponentCollectionMethods;
+
+/// steps through a pressure schedule and returns the mass flowrate
+/// through the collection at each time
+///
+/// the pressure schedule is a list of (time in seconds, pressure change)
+/// pairs, eg. a pump pressure which changes over time. For each pair,
+/// the mass flowrate is solved with the collection's own 
+/// get_mass_flowrate_from_pressure_change, so this works for
+/// series and parallel collections alike
+///
+/// This is quasi-steady, ie. i don't have a momentum (fluid inertia)
+/// term yet, so the flow responds instantly to the pressure change.
+/// This is okay as long as the pressure changes slowly compared to 
+/// the time the flow takes to develop
+///
+/// panics if the times in the schedule decrease
+///
+/// ```rust
+/// use uom::si::f64::*;
+/// use uom::si::pressure::pascal;
+/// use uom::si::mass_rate::kilogram_per_second;
+/// use fluid_mechanics_rust::fluid_component_collection::*;
+/// use fluid_mechanics_rust::transient::simulate_flow;
+///
+/// // a collection where the mass flowrate is 
+/// // just proportional to pressure change
+/// struct LinearResistance {}
+///
+/// impl FluidComponentCollectionMethods for LinearResistance {
+///     fn get_pressure_change(&self, 
+///         fluid_mass_flowrate: MassRate) -> Pressure {
+///         return Pressure::new::<pascal>(-fluid_mass_flowrate.value * 1000.0);
+///     }
+///
+///     fn get_mass_flowrate_from_pressure_change(&self,
+///         pressure_change: Pressure) -> MassRate {
+///         return MassRate::new::<kilogram_per_second>(
+///             -pressure_change.value / 1000.0);
+///     }
+/// }
+///
+/// let pressure_schedule = vec![
+///     (0.0, Pressure::new::<pascal>(0.0)),
+///     (1.0, Pressure::new::<pascal>(-500.0)),
+///     (2.0, Pressure::new::<pascal>(-1000.0)),
+/// ];
+///
+/// let flow_history = simulate_flow(&LinearResistance {}, &pressure_schedule);
+///
+/// assert_eq!(flow_history[1].0, 1.0);
+/// assert_eq!(flow_history[1].1.value, 0.5);
+/// assert_eq!(flow_history[2].1.value, 1.0);
+/// ```
+pub fn simulate_flow(collection: &dyn FluidComponentCollectionMethods,
+                     pressure_schedule: &[(f64, Pressure)]) 
+    -> Vec<(f64, MassRate)> {
+
+    let mut flow_history: Vec<(f64, MassRate)> = 
+        Vec::with_capacity(pressure_schedule.len());
+
+    let mut previous_time = f64::NEG_INFINITY;
+
+    for (time, pressure_change) in pressure_schedule.iter() {
+
+        if *time < previous_time {
+            panic!("pressure schedule times must not decrease");
+        }
+        previous_time = *time;
+
+        let mass_flowrate = 
+            collection.get_mass_flowrate_from_pressure_change(
+                *pressure_change);
+
+        flow_history.push((*time, mass_flowrate));
+    }
+
+    return flow_history;
+}
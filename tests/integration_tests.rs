@@ -527,3 +527,5118 @@ pub fn get_m40_value(mass_flow_rate: MassRate) -> Length {
     return Length::new::<meter>(length_meter);
 
 }
+
+#[test]
+pub fn when_smoothed_darcy_near_re_3000_expect_continuous_and_differentiable(){
+
+    // the smoothed friction factor should not jump
+    // and its gradient should not jump as we cross Re = 3000
+    // or the edges of the transition band (Re = 2300 and 4000)
+    use fluid_mechanics_rust::churchill_friction_factor::smoothed_darcy;
+
+    let roughness_ratio = 0.00014;
+    let step = 1e-3;
+
+    for reynolds_number in [2300.0, 3000.0, 4000.0] {
+
+        let f_left = smoothed_darcy(reynolds_number - step, roughness_ratio);
+        let f_centre = smoothed_darcy(reynolds_number, roughness_ratio);
+        let f_right = smoothed_darcy(reynolds_number + step, roughness_ratio);
+
+        // continuity
+        assert_relative_eq!(f_left, f_centre, max_relative = 1e-5);
+        assert_relative_eq!(f_right, f_centre, max_relative = 1e-5);
+
+        // differentiability, left and right gradients
+        // should agree
+        let left_gradient = (f_centre - f_left)/step;
+        let right_gradient = (f_right - f_centre)/step;
+
+        assert_relative_eq!(left_gradient, right_gradient, 
+                            max_relative = 1e-2);
+    }
+
+    // outside the transition band we should get back the
+    // usual values
+    assert_relative_eq!(smoothed_darcy(1000.0, roughness_ratio),
+                        64.0/1000.0);
+    assert_relative_eq!(smoothed_darcy(1.0e5, roughness_ratio),
+                        fluid_mechanics_rust::darcy(1.0e5, roughness_ratio));
+}
+
+#[test]
+pub fn when_colebrook_darcy_solved_expect_colebrook_equation_satisfied(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::colebrook_darcy;
+
+    // laminar region gives 64/Re
+    assert_relative_eq!(colebrook_darcy(1000.0, 0.0015), 64.0/1000.0);
+
+    // for high Re, the churchill blending is negligible and 
+    // the colebrook equation should be satisfied,
+    // including very low roughness ratios where the log term is stiff
+    for roughness_ratio in [1.0e-7, 1.0e-4, 1.0e-2] {
+        for reynolds_number in [1.0e6, 1.0e8] {
+
+            let f = colebrook_darcy(reynolds_number, roughness_ratio);
+
+            let lhs = 1.0/f.sqrt();
+            let rhs = -2.0 * (roughness_ratio/3.7 
+                              + 2.51/(reynolds_number * f.sqrt())).log10();
+
+            assert!(f.is_finite());
+            assert_relative_eq!(lhs, rhs, max_relative = 1e-3);
+        }
+    }
+
+    // and it should be close to churchill in turbulent flow
+    assert_relative_eq!(colebrook_darcy(1.0e5, 1.0e-7),
+                        fluid_mechanics_rust::darcy(1.0e5, 1.0e-7),
+                        max_relative = 0.03);
+}
+
+#[test]
+pub fn when_haaland_and_swamee_jain_compared_to_churchill_expect_within_2_percent(){
+
+    let roughness_ratio = 1.0e-4;
+
+    for reynolds_number in [1.0e4, 1.0e5, 1.0e6] {
+
+        let churchill = fluid_mechanics_rust::darcy(
+            reynolds_number, roughness_ratio);
+        let haaland = fluid_mechanics_rust::haaland(
+            reynolds_number, roughness_ratio);
+        let swamee_jain = fluid_mechanics_rust::swamee_jain(
+            reynolds_number, roughness_ratio);
+
+        assert_relative_eq!(haaland, churchill, max_relative = 0.02);
+        assert_relative_eq!(swamee_jain, churchill, max_relative = 0.02);
+    }
+
+    // laminar branch
+    assert_relative_eq!(fluid_mechanics_rust::haaland(1000.0, roughness_ratio),
+                        64.0/1000.0);
+    assert_relative_eq!(fluid_mechanics_rust::swamee_jain(1000.0, roughness_ratio),
+                        64.0/1000.0);
+}
+
+#[test]
+pub fn when_try_darcy_given_invalid_input_expect_error_not_panic(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    assert_eq!(try_darcy(0.0, 0.0015), 
+               Err(FrictionFactorError::ZeroReynolds));
+    assert_eq!(try_darcy(-100.0, 0.0015), 
+               Err(FrictionFactorError::NegativeReynolds));
+    assert_eq!(try_darcy(1800.0, -0.0015), 
+               Err(FrictionFactorError::NegativeRoughness));
+
+    // valid inputs are unaffected
+    assert_eq!(try_darcy(1800.0, 0.0015).unwrap(),
+               fluid_mechanics_rust::darcy(1800.0, 0.0015));
+
+    // the error can be boxed as a std error
+    let boxed_error: Box<dyn std::error::Error> = 
+        Box::new(FrictionFactorError::ZeroReynolds);
+    assert!(boxed_error.to_string().contains("Re = 0.0"));
+}
+
+#[test]
+pub fn when_water_liquid_at_20c_expect_density_998_and_viscosity_1_mpa_s(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let water_properties = WaterLiquidProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+
+    let density = water_properties.density(fluid_temp);
+    let viscosity = water_properties.viscosity(fluid_temp);
+
+    assert_relative_eq!(998.0, 
+                        density.get::<kilogram_per_cubic_meter>(),
+                        max_relative = 0.001);
+    assert_relative_eq!(1.0, 
+                        viscosity.get::<millipascal_second>(),
+                        max_relative = 0.01);
+}
+
+#[test]
+#[should_panic]
+pub fn when_water_liquid_below_5c_expect_panic(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let water_properties = WaterLiquidProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(2.0);
+
+    water_properties.density(fluid_temp);
+}
+
+#[test]
+pub fn when_fluid_kind_properties_used_expect_same_density_as_concrete_struct(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+
+    let dowtherm_props = FluidKind::DowthermA.properties();
+    assert_eq!(dowtherm_props.density(fluid_temp),
+               DowthermAProperties::new().density(fluid_temp));
+
+    let water_props = FluidKind::WaterLiquid.properties();
+    assert_eq!(water_props.density(fluid_temp),
+               WaterLiquidProperties::new().density(fluid_temp));
+
+    let therminol_props = FluidKind::TherminolVP1.properties();
+    assert_eq!(therminol_props.density(fluid_temp),
+               TherminolVP1Properties::new().density(fluid_temp));
+}
+
+#[test]
+pub fn when_temperature_outside_correlation_range_expect_is_temp_valid_false(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let dowtherm_props = DowthermAProperties::new();
+
+    assert_relative_eq!(dowtherm_props.min_temp().get::<degree_celsius>(), 20.0);
+    assert_relative_eq!(dowtherm_props.max_temp().get::<degree_celsius>(), 180.0);
+
+    // sweeping through temperatures should not panic if we 
+    // check first
+    for temp_degc in [0.0, 10.0, 20.0, 100.0, 180.0, 200.0] {
+        let fluid_temp = 
+            ThermodynamicTemperature::new::<degree_celsius>(temp_degc);
+
+        if dowtherm_props.is_temp_valid(fluid_temp) {
+            dowtherm_props.density(fluid_temp);
+        } else {
+            assert!(temp_degc < 20.0 || temp_degc > 180.0);
+        }
+    }
+
+    let water_props = WaterLiquidProperties::new();
+    assert!(water_props.is_temp_valid(
+            ThermodynamicTemperature::new::<degree_celsius>(5.0)));
+    assert!(!water_props.is_temp_valid(
+            ThermodynamicTemperature::new::<degree_celsius>(160.0)));
+}
+
+#[test]
+pub fn when_darcy_batch_used_expect_same_as_scalar_darcy(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let roughness_ratio = 0.0015;
+
+    // log spaced Reynolds numbers from 10 to 1e8
+    let reynolds_numbers: Vec<f64> = (0..200)
+        .map(|i| 10.0_f64.powf(1.0 + 7.0 * (i as f64)/199.0))
+        .collect();
+
+    let mut friction_factors = vec![0.0; reynolds_numbers.len()];
+
+    darcy_batch(&reynolds_numbers, roughness_ratio, &mut friction_factors);
+
+    for (reynolds_number, batch_darcy) in 
+        reynolds_numbers.iter().zip(friction_factors.iter()) {
+
+        assert_relative_eq!(*batch_darcy, 
+                            darcy(*reynolds_number, roughness_ratio),
+                            max_relative = 1e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+pub fn when_darcy_batch_given_unequal_slices_expect_panic(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let mut friction_factors = [0.0; 2];
+    darcy_batch(&[1800.0, 4000.0, 1.0e5], 0.0015, &mut friction_factors);
+}
+
+#[test]
+pub fn when_nusselt_correlations_at_re_1e4_pr_7_expect_textbook_values(){
+
+    use fluid_mechanics_rust::heat_transfer::*;
+
+    // Dittus-Boelter, heating: 0.023 * 1e4^0.8 * 7^0.4 = 79.4
+    assert_relative_eq!(dittus_boelter_nusselt(1.0e4, 7.0, true),
+                        79.4, max_relative = 0.002);
+    // cooling: 0.023 * 1e4^0.8 * 7^0.3 = 65.4
+    assert_relative_eq!(dittus_boelter_nusselt(1.0e4, 7.0, false),
+                        65.4, max_relative = 0.002);
+
+    // Gnielinski for a smooth pipe should be in the same ballpark
+    // (within about 20%) as Dittus-Boelter
+    let gnielinski = gnielinski_nusselt_churchill(1.0e4, 7.0, 0.0);
+    assert_relative_eq!(gnielinski, 79.4, max_relative = 0.2);
+
+    // laminar fallback
+    assert_relative_eq!(gnielinski_nusselt_churchill(2000.0, 7.0, 0.0), 3.66);
+}
+
+#[test]
+pub fn when_heat_transfer_coefficient_for_water_at_re_1e4_expect_w_per_m2_k(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::length::meter;
+    use uom::si::heat_transfer::watt_per_square_meter_kelvin;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    use fluid_mechanics_rust::heat_transfer::*;
+
+    let water_properties = WaterLiquidProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+
+    let viscosity = water_properties.viscosity(fluid_temp);
+    let cp = water_properties.specific_heat_capacity(fluid_temp);
+    let thermal_conductivity = water_properties.thermal_conductivity(fluid_temp);
+
+    let prandtl: f64 = (viscosity * cp / thermal_conductivity).value;
+
+    // water at 20C has a Prandtl number of about 7
+    assert_relative_eq!(prandtl, 7.0, max_relative = 0.02);
+
+    let nusselt = dittus_boelter_nusselt(1.0e4, prandtl, true);
+    let hydraulic_diameter = Length::new::<meter>(0.05);
+
+    // this only compiles if the result is a HeatTransfer quantity,
+    // ie W/(m^2 K)
+    let h: HeatTransfer = heat_transfer_coefficient(
+        nusselt, thermal_conductivity, hydraulic_diameter);
+
+    // h = 79.4 * 0.598 / 0.05 which is about 950 W/(m^2 K)
+    assert_relative_eq!(h.get::<watt_per_square_meter_kelvin>(), 
+                        nusselt * thermal_conductivity.value / 0.05,
+                        max_relative = 1e-12);
+    assert_relative_eq!(h.get::<watt_per_square_meter_kelvin>(), 
+                        950.0, max_relative = 0.02);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn when_pipe_properties_serialised_to_json_expect_same_after_round_trip(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::therminol_component::PipeProperties;
+
+    let pipe_6a = PipeProperties {
+        _name: "pipe_6a".to_string(),
+        hydraulic_diameter: Length::new::<meter>(2.79e-2),
+        component_length: Length::new::<meter>(0.1526),
+        absolute_roughness: Length::new::<meter>(1.5e-5),
+        incline_angle: Angle::new::<degree>(51.526384),
+        form_loss_k: 5.15,
+        internal_pressure: Pressure::new::<pascal>(0.0),
+    };
+
+    let pipe_json = serde_json::to_string(&pipe_6a).unwrap();
+
+    let pipe_from_json: PipeProperties = 
+        serde_json::from_str(&pipe_json).unwrap();
+
+    assert_eq!(pipe_6a._name, pipe_from_json._name);
+    assert_eq!(pipe_6a.hydraulic_diameter, pipe_from_json.hydraulic_diameter);
+    assert_eq!(pipe_6a.component_length, pipe_from_json.component_length);
+    assert_eq!(pipe_6a.absolute_roughness, pipe_from_json.absolute_roughness);
+    assert_eq!(pipe_6a.incline_angle, pipe_from_json.incline_angle);
+    assert_eq!(pipe_6a.form_loss_k, pipe_from_json.form_loss_k);
+    assert_eq!(pipe_6a.internal_pressure, pipe_from_json.internal_pressure);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn when_custom_component_deserialised_expect_functions_reattached_by_builder(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::therminol_component::*;
+
+    fn custom_darcy(_reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+        return 0.0;
+    }
+
+    fn custom_k(reynolds_number: f64) -> f64 {
+        return 18.0 + 93000.0/reynolds_number.powf(1.35);
+    }
+
+    let flowmeter_40 = CustomComponentProperties {
+        _name: "flowmeter_40".to_string(),
+        hydraulic_diameter: Length::new::<meter>(2.79e-2),
+        xs_area: Area::new::<square_meter>(6.11e-4),
+        component_length: Length::new::<meter>(0.36),
+        absolute_roughness: Length::new::<meter>(1.5e-5),
+        incline_angle: Angle::new::<degree>(90.0),
+        custom_k: &custom_k,
+        custom_darcy: &custom_darcy,
+        internal_pressure: Pressure::new::<pascal>(0.0),
+    };
+
+    let flowmeter_json = serde_json::to_string(&flowmeter_40).unwrap();
+
+    // the functions are not in the json
+    assert!(!flowmeter_json.contains("custom_k"));
+
+    let builder: CustomComponentPropertiesBuilder = 
+        serde_json::from_str(&flowmeter_json).unwrap();
+
+    let flowmeter_from_json = builder
+        .custom_k(&custom_k)
+        .custom_darcy(&custom_darcy)
+        .build();
+
+    assert_eq!(flowmeter_40.xs_area, flowmeter_from_json.xs_area);
+    assert_eq!((flowmeter_from_json.custom_k)(5000.0), custom_k(5000.0));
+}
+
+#[test]
+pub fn when_pipe_6a_built_with_builder_expect_same_pressure_change_as_factory(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::angle::degree;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::CalcPressureChange;
+    use fluid_mechanics_rust::therminol_component::factory;
+    use fluid_mechanics_rust::therminol_component::therminol_pipe::*;
+
+    let pipe_6a_factory = factory::Pipe6a::get();
+
+    let pipe_6a_builder = DowthermAPipeBuilder::new()
+        .name("static_mixer_pipe_6a")
+        .hydraulic_diameter(Length::new::<meter>(2.79e-2))
+        .component_length(Length::new::<meter>(0.1526))
+        .absolute_roughness(Length::new::<millimeter>(0.015))
+        .incline_angle(Angle::new::<degree>(51.526384))
+        .form_loss_k(5.05)
+        .build();
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+
+    for mass_flow_value in [-0.15, 0.0, 0.05, 0.15, 0.4] {
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flow_value);
+
+        let pressure_change_factory = CalcPressureChange::from_mass_rate(
+            &pipe_6a_factory, mass_flowrate, fluid_temp);
+        let pressure_change_builder = CalcPressureChange::from_mass_rate(
+            &pipe_6a_builder, mass_flowrate, fluid_temp);
+
+        assert_relative_eq!(pressure_change_factory.value,
+                            pressure_change_builder.value,
+                            max_relative = 1e-12);
+    }
+}
+
+#[test]
+pub fn when_flow_regime_classified_expect_churchill_transition_band(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    assert_eq!(flow_regime(100.0), FlowRegime::Laminar);
+    assert_eq!(flow_regime(2299.0), FlowRegime::Laminar);
+    assert_eq!(flow_regime(2300.0), FlowRegime::Transitional);
+    assert_eq!(flow_regime(4000.0), FlowRegime::Transitional);
+    assert_eq!(flow_regime(4001.0), FlowRegime::Turbulent);
+
+    // reverse flow uses the magnitude
+    assert_eq!(flow_regime(-1000.0), FlowRegime::Laminar);
+    assert_eq!(flow_regime(-3000.0), FlowRegime::Transitional);
+    assert_eq!(flow_regime(-1.0e6), FlowRegime::Turbulent);
+}
+
+#[test]
+pub fn when_try_get_reynolds_number_fails_expect_descriptive_error(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::ReynoldsSolveError;
+
+    // a valid solve gives the same as the infallible version
+    let bejan_d = fluid_mechanics_rust::get_bejan_d(5000.0, 0.00014, 10.0, 5.0);
+    assert_eq!(fluid_mechanics_rust::try_get_reynolds_number(
+            bejan_d, 0.00014, 10.0, 5.0).unwrap(),
+            fluid_mechanics_rust::get_reynolds_number(
+                bejan_d, 0.00014, 10.0, 5.0));
+
+    // tiny Be is still a valid (tiny) Re, not an error
+    let tiny_reynolds_number = fluid_mechanics_rust::try_get_reynolds_number(
+        1.0e-6, 0.00014, 10.0, 5.0).unwrap();
+    assert!(tiny_reynolds_number >= 0.0 && tiny_reynolds_number < 1.0);
+
+    assert_eq!(fluid_mechanics_rust::try_get_reynolds_number(
+            f64::NAN, 0.00014, 10.0, 5.0),
+            Err(ReynoldsSolveError::NotANumber));
+
+    assert!(matches!(fluid_mechanics_rust::try_get_reynolds_number(
+            1.0e40, 0.00014, 10.0, 5.0),
+            Err(ReynoldsSolveError::OutOfBracket { .. })));
+
+    assert!(matches!(fluid_mechanics_rust::try_get_reynolds_number(
+            1.0e4, 0.00014, -10.0, 5.0),
+            Err(ReynoldsSolveError::InvalidInput(_))));
+}
+
+#[test]
+pub fn when_small_loop_solved_with_tight_mass_flowrate_bounds_expect_convergence(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // a microfluidic style resistance, 
+    // pressure change = - resistance * m |m|
+    struct SmallLoopSection {
+        resistance: f64,
+    }
+
+    impl FluidComponentCollectionMethods for SmallLoopSection {
+        fn get_pressure_change(
+            &self, 
+            fluid_mass_flowrate: MassRate) -> Pressure {
+            let mass_flow_value = fluid_mass_flowrate.value;
+            return Pressure::new::<pascal>(
+                -self.resistance * mass_flow_value * mass_flow_value.abs());
+        }
+
+        fn get_mass_flowrate_from_pressure_change(
+            &self,
+            pressure_change: Pressure) -> MassRate {
+            let pressure_value = pressure_change.value;
+            return MassRate::new::<kilogram_per_second>(
+                -pressure_value.signum() 
+                * (pressure_value.abs()/self.resistance).sqrt());
+        }
+    }
+
+    struct SmallLoop {}
+    impl FluidComponentSuperCollectionSeriesAssociatedFunctions for SmallLoop {}
+
+    let section_1 = SmallLoopSection { resistance: 6.0e10 };
+    let section_2 = SmallLoopSection { resistance: 4.0e10 };
+
+    let super_collection_vector: Vec<&dyn FluidComponentCollectionMethods> = 
+        vec![&section_1, &section_2];
+
+    // 1e-3 kg/s through 1e11 Pa/(kg/s)^2 is 1e5 Pa
+    let pressure_change = Pressure::new::<pascal>(-1.0e5);
+
+    let mass_flowrate = SmallLoop::
+        calculate_mass_flowrate_from_pressure_change_with_bounds(
+            pressure_change,
+            &super_collection_vector,
+            MassRate::new::<kilogram_per_second>(-0.01),
+            MassRate::new::<kilogram_per_second>(0.01));
+
+    assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(), 
+                        1.0e-3, max_relative = 1e-4);
+
+    // the default bounds give the same answer
+    let mass_flowrate_default_bounds = SmallLoop::
+        calculate_mass_flowrate_from_pressure_change(
+            pressure_change,
+            &super_collection_vector);
+
+    assert_relative_eq!(mass_flowrate_default_bounds.get::<kilogram_per_second>(), 
+                        1.0e-3, max_relative = 1e-4);
+
+    // the default bounds still reach beyond 10,000 kg/s, 
+    // here the flow is 50,000 kg/s
+    let large_section_1 = SmallLoopSection { resistance: 2.4e-5 };
+    let large_section_2 = SmallLoopSection { resistance: 1.6e-5 };
+
+    let large_super_collection_vector: Vec<&dyn FluidComponentCollectionMethods> = 
+        vec![&large_section_1, &large_section_2];
+
+    let large_mass_flowrate = SmallLoop::
+        calculate_mass_flowrate_from_pressure_change(
+            pressure_change,
+            &large_super_collection_vector);
+
+    assert_relative_eq!(large_mass_flowrate.get::<kilogram_per_second>(), 
+                        5.0e4, max_relative = 1e-4);
+}
+
+#[test]
+pub fn when_velocity_converted_to_reynolds_and_back_expect_same_velocity(){
+
+    use uom::si::f64::*;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::length::meter;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::velocity::meter_per_second;
+    use fluid_mechanics_rust::CalcReynolds;
+
+    let pipe_diameter = Length::new::<meter>(2.79e-2);
+    let fluid_viscosity = DynamicViscosity::new::<pascal_second>(0.001);
+    let fluid_density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+
+    for velocity_value in [-0.5, 0.001, 0.05, 1.0, 25.0] {
+        let fluid_velocity = Velocity::new::<meter_per_second>(velocity_value);
+
+        let reynolds_number = CalcReynolds::from_velocity(
+            fluid_density, fluid_velocity, pipe_diameter, fluid_viscosity);
+
+        let velocity_from_reynolds = CalcReynolds::to_velocity(
+            reynolds_number, pipe_diameter, fluid_density, fluid_viscosity);
+
+        assert_relative_eq!(velocity_from_reynolds.get::<meter_per_second>(),
+                            velocity_value, max_relative = 0.001);
+    }
+}
+
+#[test]
+pub fn when_square_duct_and_thin_annulus_expect_side_length_and_twice_gap(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use fluid_mechanics_rust::geometry::*;
+
+    // square duct of side a, D_h = a
+    let side_length = Length::new::<millimeter>(25.0);
+    let hydraulic_diameter = 
+        hydraulic_diameter_rectangular(side_length, side_length);
+
+    assert_relative_eq!(hydraulic_diameter.get::<millimeter>(), 25.0,
+                        max_relative = 1e-12);
+
+    // thin annulus, radial gap of 1 mm around a 0.5 m rod
+    let inner = Length::new::<meter>(0.5);
+    let gap = Length::new::<millimeter>(1.0);
+    let outer = inner + 2.0 * gap;
+
+    let hydraulic_diameter = hydraulic_diameter_annulus(outer, inner);
+
+    assert_relative_eq!(hydraulic_diameter.get::<millimeter>(), 2.0,
+                        max_relative = 1e-9);
+
+    // a very wide, flat rectangular duct also approaches twice the gap
+    let hydraulic_diameter = hydraulic_diameter_rectangular(
+        Length::new::<meter>(10.0), gap);
+    assert_relative_eq!(hydraulic_diameter.get::<millimeter>(), 2.0,
+                        max_relative = 1e-3);
+}
+
+/// a simple isothermal water pipe used by the tests below,
+/// it follows the WaterPipe example in 
+/// fluid_component_calculation::tests_and_examples_simple
+pub struct TestWaterPipe {
+    pub mass_flowrate: uom::si::f64::MassRate,
+    pub pressure_loss: uom::si::f64::Pressure,
+    pub dynamic_viscosity: uom::si::f64::DynamicViscosity,
+    pub density: uom::si::f64::MassDensity,
+    pub form_loss_k: f64,
+    pub absolute_roughness: uom::si::f64::Length,
+    pub incline_angle: uom::si::f64::Angle,
+    pub internal_pressure_source: uom::si::f64::Pressure,
+    pub pipe_length: uom::si::f64::Length,
+    pub hydraulic_diameter: uom::si::f64::Length,
+}
+
+impl TestWaterPipe {
+    /// a horizontal 1 inch-ish water pipe with no form losses, 
+    /// water at about 20C
+    pub fn new(pipe_length_meters: f64) -> Self {
+        use uom::si::f64::*;
+        use uom::si::length::{meter, millimeter};
+        use uom::si::dynamic_viscosity::millipascal_second;
+        use uom::si::mass_density::kilogram_per_cubic_meter;
+        use uom::si::mass_rate::kilogram_per_second;
+        use uom::si::pressure::pascal;
+        use uom::si::angle::degree;
+
+        return Self {
+            mass_flowrate: MassRate::new::<kilogram_per_second>(0.0),
+            pressure_loss: Pressure::new::<pascal>(0.0),
+            dynamic_viscosity: DynamicViscosity::new::<millipascal_second>(1.0),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+            form_loss_k: 0.0,
+            absolute_roughness: Length::new::<millimeter>(0.001),
+            incline_angle: Angle::new::<degree>(0.0),
+            internal_pressure_source: Pressure::new::<pascal>(0.0),
+            pipe_length: Length::new::<meter>(pipe_length_meters),
+            hydraulic_diameter: Length::new::<meter>(2.79e-2),
+        };
+    }
+}
+
+impl fluid_mechanics_rust::fluid_component_calculation::standard_pipe_calc::
+FluidPipeCalcPressureChange for TestWaterPipe {}
+
+impl fluid_mechanics_rust::fluid_component_calculation::standard_pipe_calc::
+FluidPipeCalcPressureLoss for TestWaterPipe {
+    fn get_pipe_form_loss_k(&mut self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_form_loss_k_immutable(&self) -> f64 {
+        return self.form_loss_k;
+    }
+
+    fn get_pipe_absolute_roughness(&mut self) -> uom::si::f64::Length {
+        return self.absolute_roughness;
+    }
+
+    fn get_pipe_absolute_roughness_immutable(&self) -> uom::si::f64::Length {
+        return self.absolute_roughness;
+    }
+}
+
+impl fluid_mechanics_rust::fluid_component_calculation::FluidComponent 
+for TestWaterPipe {
+    fn get_internal_pressure_source(&mut self) -> uom::si::f64::Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn get_internal_pressure_source_immutable(&self) -> uom::si::f64::Pressure {
+        return self.internal_pressure_source;
+    }
+
+    fn set_internal_pressure_source(
+        &mut self,
+        internal_pressure_source: uom::si::f64::Pressure){
+        self.internal_pressure_source = internal_pressure_source;
+    }
+
+    fn get_component_length(&mut self) -> uom::si::f64::Length {
+        return self.pipe_length;
+    }
+
+    fn get_component_length_immutable(&self) -> uom::si::f64::Length {
+        return self.pipe_length;
+    }
+
+    fn get_incline_angle(&mut self) -> uom::si::f64::Angle {
+        return self.incline_angle;
+    }
+
+    fn get_incline_angle_immutable(&self) -> uom::si::f64::Angle {
+        return self.incline_angle;
+    }
+
+    fn get_fluid_density(&mut self) -> uom::si::f64::MassDensity {
+        return self.density;
+    }
+
+    fn get_fluid_density_immutable(&self) -> uom::si::f64::MassDensity {
+        return self.density;
+    }
+
+    fn get_fluid_viscosity(&mut self) -> uom::si::f64::DynamicViscosity {
+        return self.dynamic_viscosity;
+    }
+
+    fn get_fluid_viscosity_immutable(&self) -> uom::si::f64::DynamicViscosity {
+        return self.dynamic_viscosity;
+    }
+
+    fn get_hydraulic_diameter(&mut self) -> uom::si::f64::Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_hydraulic_diameter_immutable(&self) -> uom::si::f64::Length {
+        return self.hydraulic_diameter;
+    }
+
+    fn get_cross_sectional_area(&mut self) -> uom::si::f64::Area {
+        return self.get_cross_sectional_area_immutable();
+    }
+
+    fn get_cross_sectional_area_immutable(&self) -> uom::si::f64::Area {
+        return self.hydraulic_diameter * self.hydraulic_diameter 
+            * std::f64::consts::PI/4.0_f64;
+    }
+
+    fn set_pressure_loss(&mut self, pressure_loss: uom::si::f64::Pressure){
+        self.pressure_loss = pressure_loss;
+    }
+
+    fn set_mass_flowrate(&mut self, mass_flowrate: uom::si::f64::MassRate){
+        self.mass_flowrate = mass_flowrate;
+    }
+
+    fn get_mass_flowrate(&mut self) -> uom::si::f64::MassRate {
+        let pressure_loss = self.pressure_loss;
+        self.mass_flowrate = 
+            self.get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+        return self.mass_flowrate;
+    }
+
+    fn get_mass_flowrate_from_pressure_loss_immutable(
+        &self,
+        pressure_loss: uom::si::f64::Pressure) -> uom::si::f64::MassRate {
+        use fluid_mechanics_rust::fluid_component_calculation::
+            standard_pipe_calc::FluidPipeCalcPressureLoss;
+
+        return <TestWaterPipe as FluidPipeCalcPressureLoss>::
+            pipe_calc_mass_flowrate(
+                pressure_loss,
+                self.get_cross_sectional_area_immutable(),
+                self.hydraulic_diameter,
+                self.dynamic_viscosity,
+                self.density,
+                self.pipe_length,
+                self.absolute_roughness,
+                self.form_loss_k);
+    }
+
+    fn get_pressure_loss(&mut self) -> uom::si::f64::Pressure {
+        let mass_flowrate = self.mass_flowrate;
+        self.pressure_loss = self.get_pressure_loss_immutable(mass_flowrate);
+        return self.pressure_loss;
+    }
+
+    fn get_pressure_loss_immutable(
+        &self,
+        mass_flowrate: uom::si::f64::MassRate) -> uom::si::f64::Pressure {
+        use fluid_mechanics_rust::fluid_component_calculation::
+            standard_pipe_calc::FluidPipeCalcPressureLoss;
+
+        return <TestWaterPipe as FluidPipeCalcPressureLoss>::
+            pipe_calc_pressure_loss(
+                mass_flowrate,
+                self.get_cross_sectional_area_immutable(),
+                self.hydraulic_diameter,
+                self.dynamic_viscosity,
+                self.density,
+                self.pipe_length,
+                self.absolute_roughness,
+                self.form_loss_k);
+    }
+}
+
+#[test]
+pub fn when_check_valve_in_forward_and_reverse_flow_expect_base_and_blocked_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let check_valve = CheckValve::new(TestWaterPipe::new(1.0), 1.0e6);
+    let base_pipe = TestWaterPipe::new(1.0);
+
+    // forward flow, same as the base pipe
+    let forward_flow = MassRate::new::<kilogram_per_second>(0.2);
+    assert_relative_eq!(
+        check_valve.get_pressure_loss_immutable(forward_flow).value,
+        base_pipe.get_pressure_loss_immutable(forward_flow).value);
+
+    // reverse flow, much larger resistance
+    let reverse_flow = MassRate::new::<kilogram_per_second>(-0.2);
+    assert_relative_eq!(
+        check_valve.get_pressure_loss_immutable(reverse_flow).value,
+        1.0e6 * base_pipe.get_pressure_loss_immutable(reverse_flow).value);
+
+    // so a reverse pressure loss only lets a trickle through
+    let reverse_pressure_loss = Pressure::new::<pascal>(-1000.0);
+    let reverse_trickle = check_valve.
+        get_mass_flowrate_from_pressure_loss_immutable(reverse_pressure_loss);
+    let reverse_base_flow = base_pipe.
+        get_mass_flowrate_from_pressure_loss_immutable(reverse_pressure_loss);
+    assert!(reverse_trickle.value < 0.0);
+    assert!(reverse_trickle.value.abs() < 1e-3 * reverse_base_flow.value.abs());
+
+    // and the forward direction inverts properly
+    let forward_pressure_loss = 
+        check_valve.get_pressure_loss_immutable(forward_flow);
+    assert_relative_eq!(
+        check_valve.get_mass_flowrate_from_pressure_loss_immutable(
+            forward_pressure_loss).value,
+        0.2, max_relative = 1e-6);
+
+    // zero flow gives zero loss, and the loss is continuous at Re -> 0
+    let zero_flow = MassRate::new::<kilogram_per_second>(0.0);
+    assert_eq!(check_valve.get_pressure_loss_immutable(zero_flow).value, 0.0);
+
+    let tiny_flow = MassRate::new::<kilogram_per_second>(1.0e-15);
+    assert!(check_valve.get_pressure_loss_immutable(tiny_flow).value.abs() < 1e-6);
+    assert!(check_valve.get_pressure_loss_immutable(-tiny_flow).value.abs() < 1e-6);
+}
+
+#[test]
+pub fn when_orifice_beta_approaches_one_expect_k_approaches_zero(){
+
+    use uom::si::length::inch;
+    use fluid_mechanics_rust::fluid_component_calculation::OrificePlate;
+
+    let pipe_diameter = Length::new::<inch>(1.0);
+
+    let mut previous_k = f64::MAX;
+
+    for orifice_diameter_inch in [0.3, 0.5, 0.7, 0.9, 0.99] {
+        let orifice = OrificePlate::new(
+            pipe_diameter,
+            Length::new::<inch>(orifice_diameter_inch),
+            0.61);
+
+        let k = orifice.form_loss_k();
+        assert!(k < previous_k);
+        previous_k = k;
+    }
+
+    assert!(previous_k < 1e-2);
+}
+
+#[test]
+#[should_panic(expected = "orifice_diameter >= pipe_diameter")]
+pub fn when_orifice_beta_equals_one_expect_panic(){
+
+    use uom::si::length::inch;
+    use fluid_mechanics_rust::fluid_component_calculation::OrificePlate;
+
+    // at beta = 1 there is no form loss and no friction, so the 
+    // mass flowrate from pressure loss has no root
+    let pipe_diameter = Length::new::<inch>(1.0);
+
+    OrificePlate::new(pipe_diameter, pipe_diameter, 0.61);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+pub fn when_pressure_change_evaluated_in_parallel_expect_same_as_serial(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // a section with quadratic resistance and a hydrostatic head
+    struct ResistanceSection {
+        resistance: f64,
+        hydrostatic_pressure: f64,
+    }
+
+    impl FluidComponentCollectionMethods for ResistanceSection {
+        fn get_pressure_change(
+            &self, 
+            fluid_mass_flowrate: MassRate) -> Pressure {
+            let mass_flow_value = fluid_mass_flowrate.value;
+            return Pressure::new::<pascal>(
+                -self.resistance * mass_flow_value * mass_flow_value.abs()
+                + self.hydrostatic_pressure);
+        }
+
+        fn get_mass_flowrate_from_pressure_change(
+            &self,
+            pressure_change: Pressure) -> MassRate {
+            let pressure_value = pressure_change.value 
+                - self.hydrostatic_pressure;
+            return MassRate::new::<kilogram_per_second>(
+                -pressure_value.signum() 
+                * (pressure_value.abs()/self.resistance).sqrt());
+        }
+    }
+
+    struct LongLoop {}
+    impl FluidComponentSuperCollectionSeriesAssociatedFunctions for LongLoop {}
+
+    let sections: Vec<ResistanceSection> = (0..100).map(|index| {
+        ResistanceSection { 
+            resistance: 1.0e3 * (1.0 + index as f64 * 0.37),
+            hydrostatic_pressure: 10.0 * (index as f64 * 0.91).sin(),
+        }
+    }).collect();
+
+    let serial_vector: Vec<&dyn FluidComponentCollectionMethods> = 
+        sections.iter().map(|section| section as &dyn FluidComponentCollectionMethods)
+        .collect();
+
+    let parallel_vector: Vec<&(dyn FluidComponentCollectionMethods + Sync)> = 
+        sections.iter()
+        .map(|section| section as &(dyn FluidComponentCollectionMethods + Sync))
+        .collect();
+
+    for mass_flowrate_value in [-2.5, 0.0, 0.013, 1.7] {
+        let mass_flowrate = MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        let serial_pressure_change = LongLoop::
+            calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, &serial_vector);
+
+        let parallel_pressure_change = LongLoop::
+            calculate_pressure_change_from_mass_flowrate_parallel(
+                mass_flowrate, &parallel_vector);
+
+        assert_eq!(serial_pressure_change, parallel_pressure_change);
+    }
+}
+
+#[test]
+pub fn when_ten_one_meter_pipes_in_series_expect_ten_meters_and_ten_pipe_volumes(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::volume::cubic_meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+
+    let pipes: Vec<TestWaterPipe> = (0..10).map(|_| TestWaterPipe::new(1.0))
+        .collect();
+
+    let pipe_series = TestSeriesCollection {
+        fluid_component_vector: pipes.iter()
+            .map(|pipe| pipe as &dyn FluidComponent).collect(),
+    };
+
+    assert_relative_eq!(pipe_series.total_component_length().get::<meter>(), 
+                        10.0, max_relative = 1e-12);
+
+    let single_pipe_volume = pipes[0].get_cross_sectional_area_immutable() 
+        * Length::new::<meter>(1.0);
+
+    assert_relative_eq!(pipe_series.total_fluid_volume().get::<cubic_meter>(), 
+                        10.0 * single_pipe_volume.get::<cubic_meter>(), 
+                        max_relative = 1e-12);
+
+    // the collection still behaves as a normal series collection
+    let pressure_change = pipe_series.get_pressure_change(
+        MassRate::new::<kilogram_per_second>(0.1));
+    assert!(pressure_change.value < 0.0);
+}
+
+#[test]
+pub fn when_dowtherm_components_displayed_expect_name_and_diameter_in_mm(){
+
+    use fluid_mechanics_rust::therminol_component::factory;
+
+    let pipe_6a = factory::Pipe6a::get();
+    let pipe_6a_summary = format!("{}", pipe_6a);
+
+    assert!(pipe_6a_summary.contains("static_mixer_pipe_6a"));
+    assert!(pipe_6a_summary.contains("27.900 mm"));
+    assert!(pipe_6a_summary.contains("form loss K 5.05"));
+
+    let static_mixer_41 = factory::StaticMixer41::get();
+    let static_mixer_41_summary = static_mixer_41.to_string();
+
+    assert!(static_mixer_41_summary.contains("static_mixer_41"));
+    assert!(static_mixer_41_summary.contains("27.900 mm"));
+    assert!(static_mixer_41_summary.contains("custom fn(Re)"));
+}
+
+#[test]
+pub fn when_smooth_two_inch_pipe_expect_relative_roughness_about_3_9e_minus_5(){
+
+    use uom::si::length::{inch, millimeter};
+    use fluid_mechanics_rust::dimensionalisation::relative_roughness;
+
+    let roughness_ratio = relative_roughness(
+        Length::new::<millimeter>(0.002),
+        Length::new::<inch>(2.0));
+
+    assert_relative_eq!(roughness_ratio, 3.937e-5, max_relative = 1e-3);
+}
+
+#[test]
+#[should_panic(expected = "hydraulic Diameter <= 0.0")]
+pub fn when_relative_roughness_with_zero_diameter_expect_panic(){
+
+    use uom::si::length::{meter, millimeter};
+    use fluid_mechanics_rust::dimensionalisation::relative_roughness;
+
+    relative_roughness(
+        Length::new::<millimeter>(0.002),
+        Length::new::<meter>(0.0));
+}
+
+#[test]
+pub fn when_linear_pump_curve_in_series_with_laminar_pipe_expect_hand_calculated_operating_point(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // H = H0 - a m
+    let shutoff_head_pascals = 20.0;
+    let head_slope_pascals_per_kg_per_s = 200.0;
+
+    let head_curve = |mass_flowrate: MassRate| -> Pressure {
+        return Pressure::new::<pascal>(
+            shutoff_head_pascals - head_slope_pascals_per_kg_per_s 
+            * mass_flowrate.get::<kilogram_per_second>());
+    };
+
+    let pipe = TestWaterPipe::new(10.0);
+
+    let pump = CentrifugalPump::new(
+        &head_curve,
+        pipe.hydraulic_diameter,
+        pipe.get_cross_sectional_area_immutable(),
+        Length::new::<meter>(0.3),
+        Angle::new::<degree>(0.0),
+        pipe.density,
+        pipe.dynamic_viscosity);
+
+    // in laminar flow, the pipe is a linear resistance:
+    // pressure_loss = 128 mu L m / (pi rho D^4)
+    let diameter_meters = pipe.hydraulic_diameter.get::<meter>();
+    let pipe_resistance = 128.0 * pipe.dynamic_viscosity.value * 10.0 
+        / (std::f64::consts::PI * pipe.density.value 
+           * diameter_meters.powf(4.0));
+
+    // H0 - a m = R m
+    let hand_calculated_mass_flowrate = shutoff_head_pascals 
+        / (head_slope_pascals_per_kg_per_s + pipe_resistance);
+
+    struct PumpLoop {}
+    impl FluidComponentCollectionSeriesAssociatedFunctions for PumpLoop {}
+
+    let pump_loop: Vec<&dyn FluidComponent> = vec![&pump, &pipe];
+
+    // a closed loop has zero pressure change from start to end
+    let operating_mass_flowrate = PumpLoop::
+        calculate_mass_flowrate_from_pressure_change(
+            Pressure::new::<pascal>(0.0), &pump_loop);
+
+    assert_relative_eq!(operating_mass_flowrate.get::<kilogram_per_second>(),
+                        hand_calculated_mass_flowrate,
+                        max_relative = 0.01);
+
+    // the internal pressure source follows the current flowrate
+    let mut pump = pump;
+    pump.set_mass_flowrate(operating_mass_flowrate);
+    assert_relative_eq!(pump.get_internal_pressure_source().get::<pascal>(),
+                        head_curve(operating_mass_flowrate).get::<pascal>());
+
+    // the pump head only shows up in the pressure change, which 
+    // is inverted consistently, the pump itself is lossless
+    let pump_pressure_change = 
+        pump.get_pressure_change_immutable(operating_mass_flowrate);
+    assert_eq!(pump.get_pressure_loss_immutable(
+            operating_mass_flowrate).get::<pascal>(), 0.0);
+    assert_relative_eq!(
+        pump.get_mass_flowrate_from_pressure_change_immutable(
+            pump_pressure_change).get::<kilogram_per_second>(),
+        operating_mass_flowrate.get::<kilogram_per_second>(),
+        max_relative = 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "CentrifugalPump is lossless")]
+pub fn when_centrifugal_pump_mass_flowrate_from_pressure_loss_expect_panic(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let head_curve = |_mass_flowrate: MassRate| -> Pressure {
+        return Pressure::new::<pascal>(1000.0);
+    };
+
+    let pump = CentrifugalPump::new(
+        &head_curve,
+        Length::new::<meter>(2.79e-2),
+        Area::new::<square_meter>(6.11e-4),
+        Length::new::<meter>(0.3),
+        Angle::new::<degree>(0.0),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    // zero loss at every flowrate, so there is nothing to invert
+    pump.get_mass_flowrate_from_pressure_loss_immutable(
+        Pressure::new::<pascal>(0.0));
+}
+
+#[test]
+pub fn when_reynolds_from_pressure_expect_consistent_with_pipe_to_mass_rate(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::dimensionalisation::{CalcBejan, CalcReynolds};
+    use fluid_mechanics_rust::fluid_component_calculation::
+        standard_pipe_calc::CalcPressureLoss;
+
+    let pipe = TestWaterPipe::new(2.0);
+    let cross_sectional_area = pipe.hydraulic_diameter 
+        * pipe.hydraulic_diameter * std::f64::consts::PI / 4.0;
+    let form_loss_k = 3.5;
+
+    for pressure_loss_pascals in [-500.0, 5.0, 120.0, 3.0e4] {
+        let pressure_loss = Pressure::new::<pascal>(pressure_loss_pascals);
+
+        let reynolds_number = CalcBejan::reynolds_from_pressure(
+            pressure_loss,
+            pipe.hydraulic_diameter,
+            pipe.pipe_length,
+            form_loss_k,
+            pipe.absolute_roughness,
+            pipe.density,
+            pipe.dynamic_viscosity);
+
+        let mass_rate = CalcPressureLoss::to_mass_rate(
+            pressure_loss,
+            cross_sectional_area,
+            pipe.hydraulic_diameter,
+            pipe.dynamic_viscosity,
+            pipe.density,
+            pipe.pipe_length,
+            pipe.absolute_roughness,
+            form_loss_k);
+
+        let reynolds_number_from_mass_rate = CalcReynolds::from_mass_rate(
+            mass_rate, cross_sectional_area, 
+            pipe.hydraulic_diameter, pipe.dynamic_viscosity);
+
+        assert_relative_eq!(reynolds_number, reynolds_number_from_mass_rate,
+                            max_relative = 1e-9);
+    }
+}
+
+#[test]
+pub fn when_ten_meter_vertical_water_column_expect_minus_98_kpa(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::kilopascal;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let water_column = ElevationSegment::new(
+        Length::new::<meter>(10.0),
+        Angle::new::<degree>(90.0),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        DynamicViscosity::new::<millipascal_second>(1.0),
+        Length::new::<meter>(2.79e-2));
+
+    for mass_flowrate_value in [-1.0, 0.0, 3.0] {
+        let pressure_change = water_column.get_pressure_change_immutable(
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value));
+
+        assert_relative_eq!(pressure_change.get::<kilopascal>(), -98.1,
+                            max_relative = 1e-9);
+
+        assert_eq!(water_column.get_pressure_loss_immutable(
+                MassRate::new::<kilogram_per_second>(mass_flowrate_value)).value,
+                0.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "ElevationSegment is frictionless")]
+pub fn when_elevation_segment_mass_flowrate_from_pressure_loss_expect_panic(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::angle::degree;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let water_column = ElevationSegment::new(
+        Length::new::<meter>(10.0),
+        Angle::new::<degree>(90.0),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        DynamicViscosity::new::<millipascal_second>(1.0),
+        Length::new::<meter>(2.79e-2));
+
+    water_column.get_mass_flowrate_from_pressure_loss_immutable(
+        Pressure::new::<pascal>(100.0));
+}
+
+#[test]
+pub fn when_darcy_f32_compared_to_darcy_expect_agreement_within_1e_minus_4(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::{darcy, darcy_f32};
+
+    assert_relative_eq!(darcy_f32(1.0e5, 0.0015), 
+                        darcy(1.0e5, 0.0015) as f32,
+                        max_relative = 1e-4);
+
+    // the f32 version should not overflow across the usual range
+    for reynolds_number in [10.0, 1800.0, 3000.0, 1.0e5, 1.0e8] {
+        for roughness_ratio in [0.0, 1.0e-5, 0.05] {
+            assert_relative_eq!(
+                darcy_f32(reynolds_number as f32, roughness_ratio as f32), 
+                darcy(reynolds_number, roughness_ratio) as f32,
+                max_relative = 1e-4);
+        }
+    }
+}
+
+#[test]
+pub fn when_reynolds_number_negative_or_zero_expect_correct_direction_and_regime(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::dimensionalisation::{ReynoldsNumber, CalcReynolds};
+    use fluid_mechanics_rust::churchill_friction_factor::FlowRegime;
+
+    let reverse_flow = ReynoldsNumber::from(-3000.0);
+    assert!(reverse_flow.is_reverse_flow());
+    assert_eq!(reverse_flow.magnitude(), 3000.0);
+    assert_eq!(reverse_flow.regime(), FlowRegime::Transitional);
+
+    let zero_flow = ReynoldsNumber::from(0.0);
+    assert!(!zero_flow.is_reverse_flow());
+    assert_eq!(zero_flow.magnitude(), 0.0);
+    assert_eq!(zero_flow.regime(), FlowRegime::Laminar);
+
+    let forward_flow: ReynoldsNumber = 5.0e4.into();
+    assert!(!forward_flow.is_reverse_flow());
+    assert_eq!(forward_flow.regime(), FlowRegime::Turbulent);
+    assert_eq!(f64::from(forward_flow), 5.0e4);
+
+    // the typed constructor agrees with the f64 one
+    let pipe = TestWaterPipe::new(1.0);
+    let cross_sectional_area = pipe.hydraulic_diameter 
+        * pipe.hydraulic_diameter * std::f64::consts::PI / 4.0;
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(-0.2);
+
+    let reynolds_number = CalcReynolds::from_mass_rate_typed(
+        mass_flowrate, cross_sectional_area, 
+        pipe.hydraulic_diameter, pipe.dynamic_viscosity);
+
+    assert_eq!(reynolds_number.0, CalcReynolds::from_mass_rate(
+            mass_flowrate, cross_sectional_area, 
+            pipe.hydraulic_diameter, pipe.dynamic_viscosity));
+    assert!(reynolds_number.is_reverse_flow());
+}
+
+/// golden (regression) value for the whole CTAH branch,
+/// 0.18 kg/s of dowtherm A at 21C going from pipe 6a to branch 17
+/// with the CTAH pump switched off
+///
+/// at zero flow, the branch gives 39062.93 Pa of hydrostatic 
+/// pressure change (the branch goes downwards overall),
+/// at 0.18 kg/s, the frictional and form losses take away
+/// about 10.32 kPa of that, leaving 28740.47 Pa
+///
+/// these values were generated by this library, not measured,
+/// so they only guard against regressions. The branch goes 
+/// through a series FluidComponentCollection so the collection
+/// solver is covered as well
+#[test]
+pub fn when_ctah_branch_at_0_18_kg_per_s_expect_golden_pressure_change(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::fluid_component_calculation::{
+        FluidComponent, TemperatureDependentFluidComponent};
+    use fluid_mechanics_rust::fluid_component_collection::
+        FluidComponentCollectionMethods;
+
+    let mut ctah_branch = factory::ctah_branch();
+    assert_eq!(ctah_branch.len(), 17);
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+
+    for component in ctah_branch.iter_mut() {
+        match component {
+            DowthermAComponent::Pipe(pipe) => 
+                pipe.set_fluid_temp(fluid_temp),
+            DowthermAComponent::CustomComponent(custom_component) => 
+                custom_component.set_fluid_temp(fluid_temp),
+        }
+    }
+
+    let fluid_component_vector: Vec<&dyn FluidComponent> = 
+        ctah_branch.iter().map(|component| -> &dyn FluidComponent {
+            match component {
+                DowthermAComponent::Pipe(pipe) => pipe,
+                DowthermAComponent::CustomComponent(custom_component) => 
+                    custom_component,
+            }
+        }).collect();
+
+    let ctah_branch_series = TestSeriesCollection {
+        fluid_component_vector,
+    };
+
+    let hydrostatic_pressure_change = ctah_branch_series.get_pressure_change(
+        MassRate::new::<kilogram_per_second>(0.0));
+
+    assert_relative_eq!(hydrostatic_pressure_change.get::<pascal>(),
+                        39062.93, max_relative = 1e-5);
+
+    let pressure_change = ctah_branch_series.get_pressure_change(
+        MassRate::new::<kilogram_per_second>(0.18));
+
+    assert_relative_eq!(pressure_change.get::<pascal>(),
+                        28740.47, max_relative = 1e-5);
+
+    // the series collection solver should also get back 0.18 kg/s
+    let mass_flowrate = ctah_branch_series.get_mass_flowrate_from_pressure_change(
+        pressure_change);
+
+    assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(),
+                        0.18, max_relative = 1e-6);
+}
+
+/// a simple series fluid component collection used by the tests, 
+/// it holds references to any FluidComponent
+pub struct TestSeriesCollection<'collection_lifetime> {
+    pub fluid_component_vector: Vec<&'collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_calculation::FluidComponent>,
+}
+
+impl<'collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollection<'collection_lifetime> 
+for TestSeriesCollection<'collection_lifetime> {
+
+    fn get_immutable_fluid_component_vector(&self) 
+        -> &Vec<&'collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_calculation::FluidComponent> {
+        return &self.fluid_component_vector;
+    }
+
+    fn set_fluid_component_vector(
+        &mut self,
+        fluid_component_vector: Vec<&'collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_calculation::FluidComponent>){
+        self.fluid_component_vector = fluid_component_vector;
+    }
+}
+
+impl<'collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollectionMethods 
+for TestSeriesCollection<'collection_lifetime> {
+
+    fn get_pressure_change(
+        &self, 
+        fluid_mass_flowrate: uom::si::f64::MassRate) -> uom::si::f64::Pressure {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentCollectionSeriesAssociatedFunctions;
+
+        return <Self as FluidComponentCollectionSeriesAssociatedFunctions>::
+            calculate_pressure_change_from_mass_flowrate(
+                fluid_mass_flowrate, &self.fluid_component_vector);
+    }
+
+    fn get_mass_flowrate_from_pressure_change(
+        &self,
+        pressure_change: uom::si::f64::Pressure) -> uom::si::f64::MassRate {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentCollectionSeriesAssociatedFunctions;
+
+        return <Self as FluidComponentCollectionSeriesAssociatedFunctions>::
+            calculate_mass_flowrate_from_pressure_change(
+                pressure_change, &self.fluid_component_vector);
+    }
+}
+
+impl<'collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollectionSeriesAssociatedFunctions 
+for TestSeriesCollection<'collection_lifetime> {}
+
+#[test]
+pub fn when_heater_branch_assembled_as_collection_expect_same_as_calc_pressure_change(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+    use fluid_mechanics_rust::therminol_component::factory;
+    use fluid_mechanics_rust::therminol_component::CalcPressureChange;
+
+    // pipes and custom components mixed in one collection
+    let branch_5 = factory::Branch5::get();
+    let pipe_4 = factory::Pipe4::get();
+    let pipe_3 = factory::Pipe3::get();
+    let static_mixer_10 = factory::StaticMixer10::get();
+    let pipe_2a = factory::Pipe2a::get();
+    let heater_top_head_1a = factory::HeaterTopHead1a::get();
+    let ciet_heater = factory::CietHeaterVersion1::get();
+    let heater_bottom_head_1b = factory::HeaterBottomHead1b::get();
+    let pipe_18 = factory::Pipe18::get();
+
+    let heater_branch = TestSeriesCollection {
+        fluid_component_vector: vec![
+            &branch_5, &pipe_4, &pipe_3, &static_mixer_10, &pipe_2a,
+            &heater_top_head_1a, &ciet_heater, &heater_bottom_head_1b,
+            &pipe_18],
+    };
+
+    // factory components default to 21C
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+    assert_eq!(ciet_heater.fluid_temp, fluid_temp);
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+
+    let collection_pressure_change = 
+        heater_branch.get_pressure_change(mass_flowrate);
+
+    let expected_pressure_change = 
+        branch_5.from_mass_rate(mass_flowrate, fluid_temp) +
+        pipe_4.from_mass_rate(mass_flowrate, fluid_temp) +
+        pipe_3.from_mass_rate(mass_flowrate, fluid_temp) +
+        static_mixer_10.from_mass_rate(mass_flowrate, fluid_temp) +
+        pipe_2a.from_mass_rate(mass_flowrate, fluid_temp) +
+        heater_top_head_1a.from_mass_rate(mass_flowrate, fluid_temp) +
+        ciet_heater.from_mass_rate(mass_flowrate, fluid_temp) +
+        heater_bottom_head_1b.from_mass_rate(mass_flowrate, fluid_temp) +
+        pipe_18.from_mass_rate(mass_flowrate, fluid_temp);
+
+    assert_relative_eq!(collection_pressure_change.get::<pascal>(),
+                        expected_pressure_change.get::<pascal>(),
+                        max_relative = 1e-12);
+
+    // the pressure loss is consistent with the pressure change
+    let pressure_loss = pipe_4.get_pressure_loss_immutable(mass_flowrate);
+    assert_relative_eq!(
+        pipe_4.get_mass_flowrate_from_pressure_loss_immutable(
+            pressure_loss).get::<kilogram_per_second>(),
+        0.18, max_relative = 1e-6);
+
+    // and the collection can be solved for mass flowrate
+    let mass_flowrate_from_collection = heater_branch.
+        get_mass_flowrate_from_pressure_change(collection_pressure_change);
+
+    assert_relative_eq!(
+        mass_flowrate_from_collection.get::<kilogram_per_second>(),
+        0.18, max_relative = 1e-3);
+}
+
+#[test]
+pub fn when_dowtherm_pipes_heated_expect_lower_pressure_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+    use fluid_mechanics_rust::therminol_component::factory;
+
+    struct HorizontalBranch {}
+    impl FluidComponentSuperCollectionSeriesAssociatedFunctions for HorizontalBranch {}
+
+    // both are horizontal, so there is no hydrostatic pressure change
+    let mut branch_17 = factory::Branch17::get();
+    let mut branch_5 = factory::Branch5::get();
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.18);
+
+    let pressure_loss_at = |temperature_degree_celsius: f64,
+    branch_17: &mut dyn TemperatureDependentFluidComponent,
+    branch_5: &mut dyn TemperatureDependentFluidComponent| -> f64 {
+
+        let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(
+            temperature_degree_celsius);
+
+        let mut components: Vec<&mut dyn TemperatureDependentFluidComponent> 
+            = vec![branch_17, branch_5];
+
+        let pressure_change = HorizontalBranch::
+            calculate_pressure_change_with_temperatures(
+                mass_flowrate, &mut components, 
+                &[fluid_temp, fluid_temp]);
+
+        return -pressure_change.value;
+    };
+
+    let pressure_loss_cold = pressure_loss_at(30.0, &mut branch_17, &mut branch_5);
+    assert_eq!(branch_17.fluid_temp.get::<degree_celsius>(), 30.0);
+
+    let viscosity_cold = branch_17.get_fluid_viscosity_immutable();
+
+    let pressure_loss_hot = pressure_loss_at(150.0, &mut branch_17, &mut branch_5);
+    assert_relative_eq!(branch_5.fluid_temp.get::<degree_celsius>(), 150.0);
+
+    let viscosity_hot = branch_17.get_fluid_viscosity_immutable();
+
+    assert!(viscosity_hot < viscosity_cold);
+    assert!(pressure_loss_cold > 0.0);
+    assert!(pressure_loss_hot < pressure_loss_cold);
+
+    // solving the hot branch for mass flowrate at the hot 
+    // pressure loss recovers the original mass flowrate, even 
+    // though the branches were last set to the cold temperature
+    pressure_loss_at(30.0, &mut branch_17, &mut branch_5);
+
+    let hot_fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(150.0);
+    let mut components: Vec<&mut dyn TemperatureDependentFluidComponent> 
+        = vec![&mut branch_17, &mut branch_5];
+
+    let mass_flowrate_hot = HorizontalBranch::
+        calculate_mass_flowrate_with_temperatures(
+            -Pressure::new::<uom::si::pressure::pascal>(pressure_loss_hot),
+            &mut components,
+            &[hot_fluid_temp, hot_fluid_temp]);
+
+    assert_relative_eq!(mass_flowrate_hot.get::<kilogram_per_second>(),
+                        0.18, max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_blasius_compared_to_churchill_for_smooth_pipe_expect_within_5_percent(){
+
+    let blasius_friction_factor = fluid_mechanics_rust::blasius(5.0e4);
+    let churchill_friction_factor = fluid_mechanics_rust::darcy(5.0e4, 0.0);
+
+    assert_relative_eq!(blasius_friction_factor, churchill_friction_factor,
+                        max_relative = 0.05);
+
+    // laminar fallback
+    assert_relative_eq!(fluid_mechanics_rust::blasius(1000.0), 0.064);
+}
+
+#[test]
+#[should_panic(expected = "Re > 1e5")]
+pub fn when_blasius_above_1e5_expect_panic(){
+    fluid_mechanics_rust::blasius(2.0e5);
+}
+
+#[test]
+pub fn when_pressure_loss_and_change_converted_expect_consistent_signs(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::angle::degree;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let hydrostatic_pressure = Pressure::new::<pascal>(-2000.0);
+    let internal_pressure = Pressure::new::<pascal>(300.0);
+
+    // forward flow, positive loss, pressure change more negative
+    let pressure_loss = PressureLoss(Pressure::new::<pascal>(150.0));
+    let pressure_change = pressure_loss.to_change(
+        hydrostatic_pressure, internal_pressure);
+
+    assert_eq!(pressure_change, PressureChange(
+            Pressure::new::<pascal>(-150.0 - 2000.0 + 300.0)));
+    assert_eq!(PressureLoss::from_change_and_sources(
+            pressure_change, hydrostatic_pressure, internal_pressure),
+            pressure_loss);
+
+    // no sources, the change is just minus the loss
+    let zero_pressure = Pressure::new::<pascal>(0.0);
+    let pressure_change = PressureChange::from_loss_and_sources(
+        pressure_loss, zero_pressure, zero_pressure);
+    assert_eq!(Pressure::from(pressure_change), -Pressure::from(pressure_loss));
+
+    // the typed pressure change of a component agrees with the untyped one
+    let mut pipe = TestWaterPipe::new(3.0);
+    pipe.incline_angle = Angle::new::<degree>(30.0);
+    pipe.internal_pressure_source = internal_pressure;
+    pipe.set_mass_flowrate(MassRate::new::<kilogram_per_second>(0.4));
+
+    let typed_pressure_change = pipe.get_pressure_change_typed();
+    let pressure_change = pipe.get_pressure_change();
+
+    assert_relative_eq!(typed_pressure_change.0.get::<pascal>(),
+                        pressure_change.get::<pascal>(),
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_ten_identical_pipes_collapsed_expect_ten_times_single_pipe_fldk(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe = TestWaterPipe::new(2.0);
+
+    let mut fluid_component_vector: Vec<&dyn FluidComponent> = vec![];
+    for _ in 0..10 {
+        fluid_component_vector.push(&pipe);
+    }
+
+    let series_collection = TestSeriesCollection {
+        fluid_component_vector,
+    };
+
+    let reference_reynolds = 8000.0;
+    let roughness_ratio = (pipe.absolute_roughness / pipe.hydraulic_diameter).value;
+    let length_to_diameter = (pipe.pipe_length / pipe.hydraulic_diameter).value;
+
+    let single_pipe_fldk = fluid_mechanics_rust::fldk(
+        reference_reynolds, roughness_ratio, length_to_diameter, 0.0);
+
+    // a zero length equivalent pipe puts all the resistance into K
+    let equivalent_k = series_collection.equivalent_form_loss_k(
+        reference_reynolds,
+        pipe.hydraulic_diameter,
+        Length::new::<meter>(0.0),
+        pipe.absolute_roughness);
+
+    assert_relative_eq!(equivalent_k, 10.0 * single_pipe_fldk,
+                        max_relative = 1e-6);
+
+    // an equivalent pipe the length of all ten pipes needs no K 
+    let equivalent_k = series_collection.equivalent_form_loss_k(
+        reference_reynolds,
+        pipe.hydraulic_diameter,
+        series_collection.total_component_length(),
+        pipe.absolute_roughness);
+
+    assert_abs_diff_eq!(equivalent_k, 0.0, epsilon = 1e-6 * single_pipe_fldk);
+}
+
+#[test]
+pub fn when_two_node_three_edge_network_solved_expect_hagen_poiseuille_flows(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::flow_network::*;
+
+    // a pumped pipe A goes from node 0 to node 1, 
+    // pipes B and C return in parallel from node 1 to node 0
+    //
+    // node 0 is the reference pressure for the closed loop
+    //
+    // flows are laminar, so by hand, each pipe has 
+    // pressure loss = R * L * m, with R = 128 mu / (pi D^4 rho)
+    let pump_pressure = 3.0;
+    let reference_pressure = 101325.0;
+
+    let mut pipe_a = TestWaterPipe::new(1.0);
+    pipe_a.internal_pressure_source = Pressure::new::<pascal>(pump_pressure);
+    let pipe_b = TestWaterPipe::new(2.0);
+    let pipe_c = TestWaterPipe::new(6.0);
+
+    let mut flow_network = FlowNetwork::new();
+    let node_0 = flow_network.add_node(
+        Node::fixed(Pressure::new::<pascal>(reference_pressure)));
+    let node_1 = flow_network.add_node(Node::internal());
+
+    flow_network.add_edge(node_0, node_1, &pipe_a);
+    flow_network.add_edge(node_1, node_0, &pipe_b);
+    flow_network.add_edge(node_1, node_0, &pipe_c);
+
+    let solution = flow_network.solve();
+
+    // hand calculation
+    let viscosity = 0.001;
+    let density = 1000.0;
+    let diameter: f64 = 2.79e-2;
+    let resistance_per_length = 128.0 * viscosity / 
+        (std::f64::consts::PI * diameter.powf(4.0) * density);
+
+    let parallel_length = 2.0 * 6.0 / (2.0 + 6.0);
+    let mass_flowrate_a = pump_pressure / 
+        (resistance_per_length * (1.0 + parallel_length));
+    // flow splits inversely with length
+    let mass_flowrate_b = mass_flowrate_a * 6.0 / (2.0 + 6.0);
+    let mass_flowrate_c = mass_flowrate_a * 2.0 / (2.0 + 6.0);
+    let node_1_pressure = reference_pressure + pump_pressure 
+        - resistance_per_length * 1.0 * mass_flowrate_a;
+
+    // churchill is not exactly 64/Re in the laminar region
+    let flows = &solution.edge_mass_flowrates;
+    assert_relative_eq!(flows[0].get::<kilogram_per_second>(),
+                        mass_flowrate_a, max_relative = 0.02);
+    assert_relative_eq!(flows[1].get::<kilogram_per_second>(),
+                        mass_flowrate_b, max_relative = 0.02);
+    assert_relative_eq!(flows[2].get::<kilogram_per_second>(),
+                        mass_flowrate_c, max_relative = 0.02);
+    assert_relative_eq!(solution.node_pressures[node_1].get::<pascal>(),
+                        node_1_pressure, max_relative = 1e-5);
+
+    // mass is conserved at node 1 to solver tolerance
+    assert_relative_eq!(flows[0].get::<kilogram_per_second>(),
+                        (flows[1] + flows[2]).get::<kilogram_per_second>(),
+                        max_relative = 1e-8);
+
+    // splitting pipe A in half with an extra internal node 
+    // gives the same flows, this time with two unknown pressures
+    let mut half_pipe_a = TestWaterPipe::new(0.5);
+    half_pipe_a.internal_pressure_source = 
+        Pressure::new::<pascal>(pump_pressure);
+    let other_half_pipe_a = TestWaterPipe::new(0.5);
+
+    let mut flow_network = FlowNetwork::new();
+    let node_0 = flow_network.add_node(
+        Node::fixed(Pressure::new::<pascal>(reference_pressure)));
+    let node_1 = flow_network.add_node(Node::internal());
+    let node_2 = flow_network.add_node(Node::internal());
+
+    flow_network.add_edge(node_0, node_2, &half_pipe_a);
+    flow_network.add_edge(node_2, node_1, &other_half_pipe_a);
+    flow_network.add_edge(node_1, node_0, &pipe_b);
+    flow_network.add_edge(node_1, node_0, &pipe_c);
+
+    let split_solution = flow_network.solve();
+
+    assert_relative_eq!(
+        split_solution.edge_mass_flowrates[1].get::<kilogram_per_second>(),
+        flows[0].get::<kilogram_per_second>(),
+        max_relative = 1e-6);
+    assert_relative_eq!(
+        split_solution.edge_mass_flowrates[3].get::<kilogram_per_second>(),
+        flows[2].get::<kilogram_per_second>(),
+        max_relative = 1e-6);
+}
+
+/// a bare bones fluid which only carries a temperature and
+/// fluid properties, so the associated functions of 
+/// ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions
+/// can be called in the tests below
+pub struct TestFluid<'fluid_lifetime> {
+    pub fluid_temp: uom::si::f64::ThermodynamicTemperature,
+    pub fluid_properties: &'fluid_lifetime dyn 
+        fluid_mechanics_rust::fluid_thermophysical_properties::FluidProperties,
+}
+
+impl<'fluid_lifetime> fluid_mechanics_rust::fluid_thermophysical_properties::
+ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions<'fluid_lifetime>
+for TestFluid<'fluid_lifetime> {
+
+    fn get_fluid_temp(&self) -> uom::si::f64::ThermodynamicTemperature {
+        return self.fluid_temp;
+    }
+
+    fn set_fluid_temp(&mut self, 
+                      fluid_temp: uom::si::f64::ThermodynamicTemperature) {
+        self.fluid_temp = fluid_temp;
+    }
+
+    fn get_fluid_properties(&self) -> &'fluid_lifetime dyn 
+        fluid_mechanics_rust::fluid_thermophysical_properties::FluidProperties {
+        return self.fluid_properties;
+    }
+
+    fn set_fluid_properties(
+        &mut self,
+        fluid_properties: &'fluid_lifetime dyn 
+        fluid_mechanics_rust::fluid_thermophysical_properties::FluidProperties) {
+        self.fluid_properties = fluid_properties;
+    }
+}
+
+#[test]
+pub fn when_dowtherm_a_at_50c_expect_kinematic_viscosity_and_diffusivity_by_hand(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::diffusion_coefficient::square_meter_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+    use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let dowtherm_props = DowthermAProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+
+    let density = dowtherm_props.density(fluid_temp)
+        .get::<kilogram_per_cubic_meter>();
+    let viscosity = dowtherm_props.viscosity(fluid_temp)
+        .get::<pascal_second>();
+    let thermal_conductivity = dowtherm_props.thermal_conductivity(fluid_temp)
+        .get::<watt_per_meter_kelvin>();
+    let specific_heat_capacity = dowtherm_props.specific_heat_capacity(fluid_temp)
+        .get::<joule_per_kilogram_kelvin>();
+
+    let kinematic_viscosity = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::kinematic_viscosity(fluid_temp, &dowtherm_props);
+
+    let thermal_diffusivity = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::thermal_diffusivity(fluid_temp, &dowtherm_props);
+
+    // density at 50C is 1078 - 0.85*50 = 1035.5 kg/m3
+    assert_relative_eq!(density, 1035.5, max_relative = 1e-12);
+
+    assert_relative_eq!(
+        kinematic_viscosity.get::<square_meter_per_second>(),
+        viscosity/density,
+        max_relative = 1e-12);
+
+    assert_relative_eq!(
+        thermal_diffusivity.get::<square_meter_per_second>(),
+        thermal_conductivity/(density * specific_heat_capacity),
+        max_relative = 1e-12);
+
+    // sanity check, Pr = nu/alpha
+    let prandtl_number = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::prandtl_number(fluid_temp, &dowtherm_props);
+
+    assert_relative_eq!(
+        (kinematic_viscosity.value)/(thermal_diffusivity.value),
+        prandtl_number,
+        max_relative = 1e-10);
+}
+
+#[test]
+pub fn when_dowtherm_a_natural_convection_expect_grashof_and_rayleigh_by_hand(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use uom::si::temperature_interval::kelvin;
+    use uom::si::temperature_coefficient::per_kelvin;
+    use uom::si::length::meter;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    use fluid_mechanics_rust::dimensionalisation::*;
+
+    // a DHX-like branch about 1 m tall with dowtherm A at 50C 
+    // and a 10 K temperature difference
+    let dowtherm_props = DowthermAProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+
+    let density = dowtherm_props.density(fluid_temp);
+    let viscosity = dowtherm_props.viscosity(fluid_temp);
+    let thermal_expansion = 
+        dowtherm_props.thermal_expansion_coefficient(fluid_temp);
+
+    // beta = 0.85/1035.5 per K
+    assert_relative_eq!(thermal_expansion.get::<per_kelvin>(),
+                        0.85/1035.5, max_relative = 1e-12);
+
+    // water uses the default central difference of density,
+    // which should be about 2.1e-4 per K at 20C
+    let water_props = WaterLiquidProperties::new();
+    let water_expansion = water_props.thermal_expansion_coefficient(
+        ThermodynamicTemperature::new::<degree_celsius>(20.0));
+    assert_relative_eq!(water_expansion.get::<per_kelvin>(), 2.1e-4,
+                        max_relative = 0.2);
+
+    let grashof = grashof_number(
+        density,
+        thermal_expansion,
+        TemperatureInterval::new::<kelvin>(10.0),
+        Length::new::<meter>(1.0),
+        viscosity);
+
+    let hand_grashof = 9.81 * (0.85/1035.5) * 10.0 * 1.0 
+        * 1035.5_f64.powf(2.0) / viscosity.value.powf(2.0);
+
+    assert_relative_eq!(grashof, hand_grashof, max_relative = 1e-10);
+
+    let prandtl = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::prandtl_number(fluid_temp, &dowtherm_props);
+
+    let rayleigh = rayleigh_number(grashof, prandtl);
+
+    assert_relative_eq!(rayleigh, hand_grashof * prandtl, max_relative = 1e-10);
+
+    // natural convection over a 1 m height is well into 
+    // the turbulent range, Ra > 1e9
+    assert!(rayleigh > 1.0e9);
+}
+
+#[test]
+pub fn when_custom_component_pressure_loss_from_mass_rate_expect_same_as_custom_component_calc(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::length::{meter, millimeter};
+    use fluid_mechanics_rust::fluid_component_calculation::
+        custom_component_calc;
+
+    fn custom_k(reynolds_number: f64) -> f64 {
+        if reynolds_number < 0.0 {
+            return -(18.0 + 93000.0/reynolds_number.abs().powf(1.35));
+        }
+        return 18.0 + 93000.0/reynolds_number.powf(1.35);
+    }
+
+    fn custom_f(reynolds_number: f64, roughness_ratio: f64) -> f64 {
+        if reynolds_number < 0.0 {
+            return -fluid_mechanics_rust::darcy(
+                reynolds_number.abs(), roughness_ratio);
+        }
+        return fluid_mechanics_rust::darcy(reynolds_number, roughness_ratio);
+    }
+
+    let pipe_diameter = Length::new::<meter>(2.79e-2);
+    let pipe_xs_area = pipe_diameter*pipe_diameter*std::f64::consts::PI/4.0;
+    let fluid_viscosity = DynamicViscosity::new::<pascal_second>(0.005);
+    let fluid_density = MassDensity::new::<kilogram_per_cubic_meter>(1060.0);
+    let component_length = Length::new::<meter>(0.33);
+    let absolute_roughness = Length::new::<millimeter>(0.015);
+
+    for mass_rate_value in [-0.3, -0.05, 0.0, 0.02, 0.18, 0.5] {
+
+        let mass_rate = MassRate::new::<kilogram_per_second>(mass_rate_value);
+
+        let pressure_loss = 
+            fluid_mechanics_rust::CustomComponent::pressure_loss_from_mass_rate(
+                &custom_f,
+                &custom_k,
+                mass_rate,
+                pipe_xs_area,
+                pipe_diameter,
+                fluid_viscosity,
+                fluid_density,
+                component_length,
+                absolute_roughness);
+
+        let reference_pressure_loss = 
+            custom_component_calc::CalcPressureLoss::from_mass_rate(
+                mass_rate,
+                pipe_xs_area,
+                pipe_diameter,
+                fluid_viscosity,
+                fluid_density,
+                component_length,
+                absolute_roughness,
+                &custom_f,
+                &custom_k);
+
+        assert_relative_eq!(pressure_loss.value, 
+                            reference_pressure_loss.value,
+                            max_relative = 1e-12);
+    }
+}
+
+#[test]
+pub fn when_darcy_roughness_sensitivity_expect_near_zero_laminar_and_positive_turbulent(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let roughness_ratio = 1.0e-3;
+
+    // laminar, f = 64/Re regardless of roughness
+    let laminar_sensitivity = 
+        darcy_roughness_sensitivity(500.0, roughness_ratio);
+    let turbulent_sensitivity = 
+        darcy_roughness_sensitivity(1.0e5, roughness_ratio);
+
+    // compare relative to the friction factor itself,
+    // ie. d(ln f)/d(roughness_ratio)
+    let laminar_relative_sensitivity = 
+        laminar_sensitivity/darcy(500.0, roughness_ratio);
+    let turbulent_relative_sensitivity = 
+        turbulent_sensitivity/darcy(1.0e5, roughness_ratio);
+
+    assert!(laminar_relative_sensitivity.abs() < 1.0e-3);
+    assert!(turbulent_relative_sensitivity > 10.0);
+
+    // smooth pipes use a forward difference and 
+    // should still work
+    assert!(darcy_roughness_sensitivity(1.0e5, 0.0) > 0.0);
+
+    // the sweep should increase monotonically in the turbulent region
+    let roughness_ratios = [0.0, 1.0e-5, 1.0e-4, 1.0e-3, 1.0e-2];
+    let friction_factors = darcy_roughness_sweep(1.0e5, &roughness_ratios);
+
+    for index in 1..friction_factors.len() {
+        assert!(friction_factors[index] > friction_factors[index - 1]);
+        assert_eq!(friction_factors[index], 
+                   darcy(1.0e5, roughness_ratios[index]));
+    }
+}
+
+#[test]
+pub fn when_dowtherm_a_out_of_range_expect_range_error(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let dowtherm_props = DowthermAProperties::new();
+
+    let too_cold = ThermodynamicTemperature::new::<degree_celsius>(19.0);
+    let too_hot = ThermodynamicTemperature::new::<degree_celsius>(181.0);
+    let just_right = ThermodynamicTemperature::new::<degree_celsius>(100.0);
+
+    for fluid_temp in [too_cold, too_hot] {
+        let expected_error = DowthermRangeError {
+            fluid_temp,
+            min_temp: ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            max_temp: ThermodynamicTemperature::new::<degree_celsius>(180.0),
+        };
+
+        assert_eq!(dowtherm_props.try_density(fluid_temp), 
+                   Err(expected_error));
+        assert_eq!(dowtherm_props.try_viscosity(fluid_temp), 
+                   Err(expected_error));
+        assert_eq!(dowtherm_props.try_enthalpy(fluid_temp), 
+                   Err(expected_error));
+        assert_eq!(dowtherm_props.try_specific_heat_capacity(fluid_temp), 
+                   Err(expected_error));
+        assert_eq!(dowtherm_props.try_thermal_conductivity(fluid_temp), 
+                   Err(expected_error));
+        assert_eq!(dowtherm_props.try_thermal_expansion_coefficient(fluid_temp), 
+                   Err(expected_error));
+    }
+
+    assert_eq!(dowtherm_props.try_density(just_right), 
+               Ok(dowtherm_props.density(just_right)));
+    assert_eq!(dowtherm_props.try_viscosity(just_right), 
+               Ok(dowtherm_props.viscosity(just_right)));
+    assert_eq!(dowtherm_props.try_enthalpy(just_right), 
+               Ok(dowtherm_props.enthalpy(just_right)));
+    assert_eq!(dowtherm_props.try_specific_heat_capacity(just_right), 
+               Ok(dowtherm_props.specific_heat_capacity(just_right)));
+    assert_eq!(dowtherm_props.try_thermal_conductivity(just_right), 
+               Ok(dowtherm_props.thermal_conductivity(just_right)));
+    assert_eq!(dowtherm_props.try_thermal_expansion_coefficient(just_right), 
+               Ok(dowtherm_props.thermal_expansion_coefficient(just_right)));
+
+    let error_message = dowtherm_props.try_density(too_hot)
+        .unwrap_err().to_string();
+    assert!(error_message.contains("181"));
+}
+
+#[test]
+pub fn when_dowtherm_a_viscosity_at_80c_inverted_expect_80c(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let dowtherm_props = DowthermAProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+
+    let viscosity = dowtherm_props.viscosity(fluid_temp);
+    let solved_temp = dowtherm_props.temperature_from_viscosity(viscosity)
+        .unwrap();
+
+    assert_abs_diff_eq!(solved_temp.get::<degree_celsius>(), 80.0,
+                        epsilon = 0.5);
+
+    let density = dowtherm_props.density(fluid_temp);
+    let solved_temp = dowtherm_props.temperature_from_density(density)
+        .unwrap();
+
+    assert_abs_diff_eq!(solved_temp.get::<degree_celsius>(), 80.0,
+                        epsilon = 0.5);
+
+    // viscosity at 10C is not reachable in the 20C to 180C range
+    let too_viscous = DynamicViscosity::new::<
+        uom::si::dynamic_viscosity::pascal_second>(0.130/10.0_f64.powf(1.072));
+
+    assert!(dowtherm_props.temperature_from_viscosity(too_viscous).is_err());
+}
+
+#[test]
+pub fn when_ten_pipe_series_solved_with_guess_expect_same_as_cold_start(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::angle::degree;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // ten pipes, some inclined so there is a hydrostatic term too
+    let mut pipes: Vec<TestWaterPipe> = vec![];
+    for index in 0..10 {
+        let mut pipe = TestWaterPipe::new(1.0 + 0.1 * index as f64);
+        pipe.incline_angle = Angle::new::<degree>(10.0 * index as f64);
+        pipes.push(pipe);
+    }
+
+    let fluid_component_vector: Vec<&dyn FluidComponent> = 
+        pipes.iter().map(|pipe| pipe as &dyn FluidComponent).collect();
+
+    // a transient where the mass flowrate changes a little each timestep
+    let mut previous_mass_flowrate = 
+        MassRate::new::<kilogram_per_second>(0.3);
+
+    for timestep in 0..5 {
+
+        let mass_flowrate = MassRate::new::<kilogram_per_second>(
+            0.3 + 0.01 * timestep as f64);
+
+        let pressure_change = 
+            <TestSeriesCollection as FluidComponentCollectionSeriesAssociatedFunctions>
+            ::calculate_pressure_change_from_mass_flowrate(
+                mass_flowrate, &fluid_component_vector);
+
+        let cold_start_mass_flowrate = 
+            <TestSeriesCollection as FluidComponentCollectionSeriesAssociatedFunctions>
+            ::calculate_mass_flowrate_from_pressure_change(
+                pressure_change, &fluid_component_vector);
+
+        let warm_start_mass_flowrate = 
+            <TestSeriesCollection as FluidComponentCollectionSeriesAssociatedFunctions>
+            ::calculate_mass_flowrate_from_pressure_change_with_guess(
+                pressure_change, &fluid_component_vector, 
+                previous_mass_flowrate);
+
+        assert_relative_eq!(
+            warm_start_mass_flowrate.get::<kilogram_per_second>(),
+            cold_start_mass_flowrate.get::<kilogram_per_second>(),
+            max_relative = 1e-9);
+
+        assert_relative_eq!(
+            warm_start_mass_flowrate.get::<kilogram_per_second>(),
+            mass_flowrate.get::<kilogram_per_second>(),
+            max_relative = 1e-9);
+
+        previous_mass_flowrate = warm_start_mass_flowrate;
+    }
+
+    // a bad guess in the wrong direction falls back to the cold start
+    let pressure_change = 
+        <TestSeriesCollection as FluidComponentCollectionSeriesAssociatedFunctions>
+        ::calculate_pressure_change_from_mass_flowrate(
+            MassRate::new::<kilogram_per_second>(-2.0), 
+            &fluid_component_vector);
+
+    let warm_start_mass_flowrate = 
+        <TestSeriesCollection as FluidComponentCollectionSeriesAssociatedFunctions>
+        ::calculate_mass_flowrate_from_pressure_change_with_guess(
+            pressure_change, &fluid_component_vector, 
+            MassRate::new::<kilogram_per_second>(0.001));
+
+    assert_relative_eq!(warm_start_mass_flowrate.get::<kilogram_per_second>(),
+                        -2.0, max_relative = 1e-9);
+}
+
+#[test]
+pub fn when_temperature_aware_custom_component_at_30c_and_150c_expect_different_losses(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_component_calculation::{FluidComponent,
+        TemperatureDependentFluidComponent};
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::therminol_component::
+        custom_therminol_component::DowthermACustomComponent;
+
+    fn custom_darcy(_reynolds_number: f64, _roughness_ratio: f64) -> f64 {
+        return 0.0;
+    }
+
+    fn flowmeter_k(reynolds_number: f64) -> f64 {
+        if reynolds_number == 0.0 {
+            return 0.0;
+        }
+        return (18.0 + 93000.0/reynolds_number.abs().powf(1.35)) 
+            * reynolds_number.signum();
+    }
+
+    // same as the flowmeter, but ignores temperature
+    fn temperature_independent_k(reynolds_number: f64, 
+                                 _fluid_temp: ThermodynamicTemperature) -> f64 {
+        return flowmeter_k(reynolds_number);
+    }
+
+    // a viscosity ratio type correction, 
+    // (mu(21C)/mu(fluid temp))^0.14
+    fn temperature_dependent_k(reynolds_number: f64, 
+                               fluid_temp: ThermodynamicTemperature) -> f64 {
+        let viscosity_ratio = 
+            (fluid_temp.get::<degree_celsius>()/21.0).powf(1.072);
+        return viscosity_ratio.powf(0.14) * flowmeter_k(reynolds_number);
+    }
+
+    let mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+
+    // with a temperature independent K, this is the same 
+    // as the existing custom component
+    let mut flowmeter_reference: DowthermACustomComponent = 
+        StandardCustomComponentProperties::new(
+            "flowmeter".to_string(), 2.79e-2, 6.11e-4, 0.36, 0.015, 0.0,
+            &custom_darcy, &flowmeter_k);
+
+    let mut flowmeter_independent = TemperatureAwareCustomComponent::new(
+        "flowmeter".to_string(), 2.79e-2, 6.11e-4, 0.36, 0.015, 0.0,
+        &custom_darcy, &temperature_independent_k);
+
+    let mut flowmeter_dependent = TemperatureAwareCustomComponent::new(
+        "flowmeter".to_string(), 2.79e-2, 6.11e-4, 0.36, 0.015, 0.0,
+        &custom_darcy, &temperature_dependent_k);
+
+    let mut pressure_losses: Vec<f64> = vec![];
+
+    for fluid_temp_degrees_c in [30.0, 150.0] {
+
+        let fluid_temp = 
+            ThermodynamicTemperature::new::<degree_celsius>(fluid_temp_degrees_c);
+
+        flowmeter_reference.set_fluid_temp(fluid_temp);
+        flowmeter_independent.set_fluid_temp(fluid_temp);
+        flowmeter_dependent.set_fluid_temp(fluid_temp);
+
+        assert_relative_eq!(
+            flowmeter_independent.get_pressure_loss_immutable(mass_rate).value,
+            flowmeter_reference.get_pressure_loss_immutable(mass_rate).value,
+            max_relative = 1e-12);
+
+        let pressure_loss = 
+            flowmeter_dependent.get_pressure_loss_immutable(mass_rate);
+
+        // the temperature correction should be applied on top of
+        // the temperature independent loss
+        let expected_ratio = 
+            (fluid_temp_degrees_c/21.0_f64).powf(1.072).powf(0.14);
+        assert_relative_eq!(
+            pressure_loss.value,
+            expected_ratio * 
+            flowmeter_independent.get_pressure_loss_immutable(mass_rate).value,
+            max_relative = 1e-12);
+
+        // and the inverse gives back the mass flowrate
+        assert_relative_eq!(
+            flowmeter_dependent.get_mass_flowrate_from_pressure_loss_immutable(
+                pressure_loss).value,
+            mass_rate.value,
+            max_relative = 1e-6);
+
+        pressure_losses.push(pressure_loss.value);
+    }
+
+    assert!((pressure_losses[0] - pressure_losses[1]).abs() 
+            > 0.01 * pressure_losses[0]);
+}
+
+#[test]
+pub fn when_pipes_exported_and_imported_from_csv_expect_same_lengths_and_k(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::therminol_component::PipeProperties;
+    use fluid_mechanics_rust::io::*;
+
+    let make_pipe = |name: &str, length_meters: f64, angle_degrees: f64,
+                     form_loss_k: f64| -> PipeProperties {
+        return PipeProperties {
+            _name: name.to_string(),
+            hydraulic_diameter: Length::new::<meter>(2.79e-2),
+            component_length: Length::new::<meter>(length_meters),
+            absolute_roughness: Length::new::<millimeter>(0.015),
+            incline_angle: Angle::new::<degree>(angle_degrees),
+            form_loss_k,
+            internal_pressure: Pressure::new::<pascal>(0.0),
+        };
+    };
+
+    let pipes = vec![
+        make_pipe("pipe_6a", 0.1526, 51.526384, 5.15),
+        make_pipe("pipe_9", 0.7112, -40.0, 0.0),
+        make_pipe("pipe_10", 2.4511, -90.0, 0.45),
+    ];
+
+    let csv_path = std::env::temp_dir().join(
+        format!("fluid_mechanics_rust_pipes_{}.csv", std::process::id()));
+
+    export_pipes_to_csv(&csv_path, &pipes).unwrap();
+    let imported_pipes = import_pipes_from_csv(&csv_path).unwrap();
+    std::fs::remove_file(&csv_path).unwrap();
+
+    assert_eq!(imported_pipes.len(), pipes.len());
+
+    for (pipe, imported_pipe) in pipes.iter().zip(imported_pipes.iter()) {
+        assert_eq!(imported_pipe._name, pipe._name);
+        assert_relative_eq!(imported_pipe.component_length.value,
+                            pipe.component_length.value, 
+                            max_relative = 1e-12);
+        assert_relative_eq!(imported_pipe.absolute_roughness.value,
+                            pipe.absolute_roughness.value, 
+                            max_relative = 1e-12);
+        assert_relative_eq!(imported_pipe.incline_angle.value,
+                            pipe.incline_angle.value, 
+                            max_relative = 1e-12);
+        assert_eq!(imported_pipe.form_loss_k, pipe.form_loss_k);
+    }
+
+    // a bad number is an InvalidData error
+    let bad_csv_path = std::env::temp_dir().join(
+        format!("fluid_mechanics_rust_bad_pipes_{}.csv", std::process::id()));
+    std::fs::write(&bad_csv_path, 
+                   format!("{}\npipe_1,0.0279,one,0.015,0.0,0.0\n", 
+                           PIPE_CSV_HEADER)).unwrap();
+
+    let import_error = import_pipes_from_csv(&bad_csv_path).err().unwrap();
+    std::fs::remove_file(&bad_csv_path).unwrap();
+
+    assert_eq!(import_error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+pub fn when_dowtherm_a_prandtl_from_properties_at_60c_expect_same_as_convenience_version(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let dowtherm_props = DowthermAProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+
+    // fetch each property once
+    let fluid_viscosity = dowtherm_props.viscosity(fluid_temp);
+    let fluid_specific_heat_capacity = 
+        dowtherm_props.specific_heat_capacity(fluid_temp);
+    let fluid_thermal_conductivity = 
+        dowtherm_props.thermal_conductivity(fluid_temp);
+
+    let prandtl_from_properties = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::prandtl_number_from_properties(
+            fluid_viscosity,
+            fluid_specific_heat_capacity,
+            fluid_thermal_conductivity);
+
+    let prandtl = 
+        <TestFluid as ConstantCompositionSinglePhaseFluidPropertiesAssociatedFunctions>
+        ::prandtl_number(fluid_temp, &dowtherm_props);
+
+    assert_relative_eq!(prandtl_from_properties, prandtl, max_relative = 1e-14);
+
+    // hand calculation, mu cp/k
+    let hand_prandtl = (0.130/60.0_f64.powf(1.072)) 
+        * (1518.0 + 2.82 * 60.0) / (0.142 - 0.00016 * 60.0);
+    assert_relative_eq!(prandtl, hand_prandtl, max_relative = 1e-10);
+}
+
+#[test]
+pub fn when_area_ratio_approaches_one_or_zero_expect_limiting_form_loss_k(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+    let small_area = Area::new::<square_meter>(1.0e-4);
+
+    // nearly no area change, K goes to zero both ways
+    let nearly_equal_area = Area::new::<square_meter>(1.0001e-4);
+
+    let expansion = SuddenExpansion::new(
+        small_area, nearly_equal_area, density, viscosity);
+    let contraction = SuddenContraction::new(
+        nearly_equal_area, small_area, density, viscosity);
+
+    assert!(expansion.form_loss_k() < 1.0e-7);
+    assert!(contraction.form_loss_k() < 1.0e-6);
+    assert!(expansion.reverse_form_loss_k() < 1.0e-6);
+
+    // expansion into a large reservoir, K goes to 1
+    let reservoir_area = Area::new::<square_meter>(1.0e2);
+    let expansion_into_reservoir = SuddenExpansion::new(
+        small_area, reservoir_area, density, viscosity);
+
+    assert_relative_eq!(expansion_into_reservoir.form_loss_k(), 1.0,
+                        max_relative = 1e-5);
+
+    // contraction from a large reservoir, Cc = 0.62
+    let contraction_from_reservoir = SuddenContraction::new(
+        reservoir_area, small_area, density, viscosity);
+    assert_relative_eq!(contraction_from_reservoir.form_loss_k(), 
+                        (1.0/0.62_f64 - 1.0).powf(2.0),
+                        max_relative = 1e-5);
+
+    // the loss is K rho u^2/2, u = 1 m/s at 0.1 kg/s in 1 cm^2
+    let mass_rate = MassRate::new::<kilogram_per_second>(0.1);
+    let pressure_loss = 
+        expansion_into_reservoir.get_pressure_loss_immutable(mass_rate);
+
+    assert_relative_eq!(pressure_loss.value, 
+                        expansion_into_reservoir.form_loss_k() * 500.0,
+                        max_relative = 1e-6);
+
+    // reverse flow through an expansion is a contraction
+    let reverse_pressure_loss = 
+        expansion_into_reservoir.get_pressure_loss_immutable(-mass_rate);
+
+    assert_relative_eq!(reverse_pressure_loss.value, 
+                        -contraction_from_reservoir.form_loss_k() * 500.0,
+                        max_relative = 1e-6);
+
+    // and the inverse gives back the mass flowrate
+    assert_relative_eq!(
+        expansion_into_reservoir.get_mass_flowrate_from_pressure_loss_immutable(
+            reverse_pressure_loss).value,
+        -0.1, max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_component_pressure_changes_summed_expect_total_pressure_change(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // a mixed series: a pumped pipe, an inclined pipe 
+    // and a sudden expansion into a larger pipe
+    let mut pumped_pipe = TestWaterPipe::new(1.0);
+    pumped_pipe.internal_pressure_source = Pressure::new::<pascal>(500.0);
+
+    let mut inclined_pipe = TestWaterPipe::new(2.0);
+    inclined_pipe.incline_angle = Angle::new::<degree>(30.0);
+
+    let pipe_area = Area::new::<square_meter>(
+        std::f64::consts::PI / 4.0 * 2.79e-2_f64.powf(2.0));
+    let expansion = SuddenExpansion::new(
+        pipe_area, 
+        4.0 * pipe_area,
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    let series_collection = TestSeriesCollection {
+        fluid_component_vector: vec![&pumped_pipe, 
+        &inclined_pipe, &expansion],
+    };
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+
+    let component_pressure_changes = 
+        series_collection.component_pressure_changes(mass_flowrate);
+
+    assert_eq!(component_pressure_changes.len(), 3);
+
+    let mut summed_pressure_change = Pressure::new::<pascal>(0.0);
+    for (index, (component_index, pressure_change)) in 
+        component_pressure_changes.iter().enumerate() {
+            assert_eq!(index, *component_index);
+            summed_pressure_change += *pressure_change;
+        }
+
+    let total_pressure_change = 
+        series_collection.get_pressure_change(mass_flowrate);
+
+    assert_relative_eq!(summed_pressure_change.value, 
+                        total_pressure_change.value,
+                        max_relative = 1e-12);
+
+    // each entry is that component's own pressure change
+    assert_eq!(component_pressure_changes[1].1, 
+               inclined_pipe.get_pressure_change_immutable(mass_flowrate));
+}
+
+#[test]
+pub fn when_pump_efficiency_halved_expect_pumping_power_doubled(){
+
+    use uom::si::f64::*;
+    use uom::si::power::watt;
+    use uom::si::pressure::kilopascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::power::*;
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.0);
+    let pressure_rise = Pressure::new::<kilopascal>(100.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+
+    let hydraulic_power = pumping_power(mass_flowrate, 
+                                        pressure_rise, 
+                                        density);
+
+    assert_relative_eq!(hydraulic_power.get::<watt>(), 100.0,
+                        max_relative = 1e-12);
+
+    let shaft_power = pumping_power_with_efficiency(mass_flowrate,
+                                                    pressure_rise,
+                                                    density,
+                                                    0.5);
+
+    assert_relative_eq!(shaft_power.get::<watt>(), 200.0,
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_two_phase_pipe_quality_zero_expect_single_phase_pressure_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_calculation::two_phase::*;
+
+    // same geometry and liquid properties as the test water pipe
+    let single_phase_pipe = TestWaterPipe::new(2.0);
+
+    let mut two_phase_pipe = TwoPhasePipe::new(
+        "two_phase_water_pipe",
+        2.79e-2,
+        2.0,
+        0.001,
+        0.0,
+        0.0,
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        MassDensity::new::<kilogram_per_cubic_meter>(0.6),
+        DynamicViscosity::new::<millipascal_second>(1.0),
+        DynamicViscosity::new::<millipascal_second>(0.012));
+
+    for mass_flowrate_value in [-0.5, 0.0, 0.01, 0.5, 2.0] {
+
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        assert_eq!(
+            two_phase_pipe.get_pressure_loss_immutable(mass_flowrate),
+            single_phase_pipe.get_pressure_loss_immutable(mass_flowrate));
+    }
+
+    // with some vapor, the loss goes up by the HEM multiplier
+    two_phase_pipe.vapor_quality = 0.02;
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+
+    let expected_multiplier = 
+        homogeneous_two_phase_multiplier_with_viscosity(
+            0.02, 1000.0/0.6, 1.0/0.012);
+
+    assert!(expected_multiplier > 1.0);
+
+    let two_phase_loss = 
+        two_phase_pipe.get_pressure_loss_immutable(mass_flowrate);
+
+    assert_relative_eq!(
+        two_phase_loss.value,
+        expected_multiplier * 
+        single_phase_pipe.get_pressure_loss_immutable(mass_flowrate).value,
+        max_relative = 1e-12);
+
+    // and the mass flowrate is recovered from the pressure loss
+    assert_relative_eq!(
+        two_phase_pipe.get_mass_flowrate_from_pressure_loss_immutable(
+            two_phase_loss).value,
+        0.5, max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_turbulent_water_pipe_expect_wall_shear_stress_near_hand_calculation(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::dimensionalisation::wall_shear_stress;
+
+    let pipe = TestWaterPipe::new(1.0);
+    let area = pipe.hydraulic_diameter * pipe.hydraulic_diameter
+        * std::f64::consts::PI / 4.0;
+
+    // by hand: 
+    // v = 0.5/(1000 * 6.1136e-4) = 0.81785 m/s
+    // Re = 1000 * 0.81785 * 0.0279 / 0.001 = 22818
+    // e/D = 0.001 mm / 27.9 mm = 3.584e-5
+    // Colebrook f = 0.025150
+    // tau_w = 0.025150 * 1000 * 0.81785^2 / 8 = 2.1028 Pa
+    let tau_w = wall_shear_stress(
+        MassRate::new::<kilogram_per_second>(0.5),
+        area,
+        pipe.hydraulic_diameter,
+        pipe.density,
+        pipe.dynamic_viscosity,
+        pipe.absolute_roughness);
+
+    assert_relative_eq!(tau_w.get::<pascal>(), 2.1028, max_relative = 0.01);
+
+    // reverse flow gives the opposite shear
+    let reverse_tau_w = wall_shear_stress(
+        MassRate::new::<kilogram_per_second>(-0.5),
+        area,
+        pipe.hydraulic_diameter,
+        pipe.density,
+        pipe.dynamic_viscosity,
+        pipe.absolute_roughness);
+
+    assert_eq!(reverse_tau_w, -tau_w);
+}
+
+#[test]
+pub fn when_mixed_enum_components_summed_expect_same_as_individual_components(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::*;
+
+    // pipes and custom components in one vector, 
+    // including the pump with some pressure
+    let heterogeneous_loop: Vec<DowthermAComponent> = vec![
+        factory::Pipe6a::get_enum(),
+        factory::StaticMixer41::get_enum(),
+        factory::CTAHVertical::get_enum(),
+        factory::CTAHPump::get_enum(5000.0),
+        factory::Flowmeter40::get_enum(),
+        factory::Pipe15::get_enum(),
+    ];
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(25.0);
+    let mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+
+    let mut series_pressure_change = Pressure::new::<pascal>(0.0);
+    for component in heterogeneous_loop.iter() {
+        series_pressure_change += 
+            component.pressure_change_from_mass_rate(mass_rate, fluid_temp);
+    }
+
+    let expected_series_pressure_change = 
+        factory::Pipe6a::get().from_mass_rate(mass_rate, fluid_temp)
+        + factory::StaticMixer41::get().from_mass_rate(mass_rate, fluid_temp)
+        + factory::CTAHVertical::get().from_mass_rate(mass_rate, fluid_temp)
+        + factory::CTAHPump::get(5000.0).from_mass_rate(mass_rate, fluid_temp)
+        + factory::Flowmeter40::get().from_mass_rate(mass_rate, fluid_temp)
+        + factory::Pipe15::get().from_mass_rate(mass_rate, fluid_temp);
+
+    assert_eq!(series_pressure_change, expected_series_pressure_change);
+
+    // each component can be inverted on its own too
+    let pipe_pressure_change = heterogeneous_loop[0]
+        .pressure_change_from_mass_rate(mass_rate, fluid_temp);
+
+    assert_relative_eq!(
+        heterogeneous_loop[0].mass_rate_from_pressure_change(
+            pipe_pressure_change, fluid_temp).value,
+        0.18, max_relative = 1e-5);
+}
+
+#[test]
+pub fn when_friction_mode_forced_expect_laminar_or_turbulent_only_darcy(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let roughness_ratio = 0.001/27.9;
+
+    // forced laminar gives 64/Re even within the transition band
+    assert_eq!(darcy_with_mode(3000.0, roughness_ratio, 
+                               FrictionMode::ForceLaminar),
+               64.0/3000.0);
+
+    // auto reproduces the current behaviour
+    for reynolds_number in [100.0, 2300.0, 3000.0, 4000.0, 1.0e5] {
+        assert_eq!(darcy_with_mode(reynolds_number, roughness_ratio,
+                                   FrictionMode::Auto),
+                   darcy(reynolds_number, roughness_ratio));
+    }
+
+    // in the transition band the laminar term still matters, 
+    // so forcing turbulent flow should change the friction factor
+    let turbulent_darcy = darcy_with_mode(3000.0, roughness_ratio,
+                                          FrictionMode::ForceTurbulent);
+    assert!(turbulent_darcy != darcy(3000.0, roughness_ratio));
+
+    // but well within turbulent flow, the laminar term is negligible
+    assert_relative_eq!(darcy_with_mode(1.0e5, roughness_ratio,
+                                        FrictionMode::ForceTurbulent),
+                        darcy(1.0e5, roughness_ratio),
+                        max_relative = 1e-9);
+
+    // and in laminar flow, turbulent only is far below 64/Re
+    assert!(darcy_with_mode(500.0, roughness_ratio, 
+                            FrictionMode::ForceTurbulent) < 64.0/500.0);
+}
+
+#[test]
+pub fn when_solver_abs_tol_tightened_expect_more_accurate_mass_flowrate(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::pressure::pascal;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    // a sudden expansion has a constant K, so the mass flowrate 
+    // is known analytically:
+    // m = A sqrt(2 rho dp / K)
+    let upstream_area = Area::new::<square_meter>(1.0e-4);
+    let expansion = SuddenExpansion::new(
+        upstream_area,
+        Area::new::<square_meter>(4.0e-4),
+        MassDensity::new::<kilogram_per_cubic_meter>(1000.0),
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    let pressure_loss_pascals = 1000.0;
+    let analytical_mass_flowrate = upstream_area.value * 
+        (2.0 * 1000.0 * pressure_loss_pascals / expansion.form_loss_k()).sqrt();
+
+    let fluid_component_vector: Vec<&dyn FluidComponent> = vec![&expansion];
+    let pressure_change = Pressure::new::<pascal>(-pressure_loss_pascals);
+
+    let loose_config = SolverConfig { abs_tol: 1.0e-2, max_iter: 30 };
+    let tight_config = SolverConfig { abs_tol: 1.0e-12, max_iter: 30 };
+
+    let loose_mass_flowrate = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_mass_flowrate_from_pressure_change_with_config(
+            pressure_change, &fluid_component_vector, &loose_config);
+
+    let tight_mass_flowrate = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_mass_flowrate_from_pressure_change_with_config(
+            pressure_change, &fluid_component_vector, &tight_config);
+
+    let loose_error = 
+        (loose_mass_flowrate.value - analytical_mass_flowrate).abs();
+    let tight_error = 
+        (tight_mass_flowrate.value - analytical_mass_flowrate).abs();
+
+    assert!(tight_error < loose_error);
+    assert_relative_eq!(tight_mass_flowrate.value, analytical_mass_flowrate,
+                        max_relative = 1e-10);
+}
+
+/// a simple parallel collection for testing,
+/// it holds references to any FluidComponent
+pub struct TestParallelCollection<'collection_lifetime> {
+    pub fluid_component_vector: Vec<&'collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_calculation::FluidComponent>,
+}
+
+impl<'collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollectionMethods 
+for TestParallelCollection<'collection_lifetime> {
+
+    fn get_pressure_change(
+        &self, 
+        fluid_mass_flowrate: uom::si::f64::MassRate) -> uom::si::f64::Pressure {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentCollectionParallelAssociatedFunctions;
+
+        return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            calculate_pressure_change_from_mass_flowrate(
+                fluid_mass_flowrate, &self.fluid_component_vector);
+    }
+
+    fn get_mass_flowrate_from_pressure_change(
+        &self,
+        pressure_change: uom::si::f64::Pressure) -> uom::si::f64::MassRate {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentCollectionParallelAssociatedFunctions;
+
+        return <Self as FluidComponentCollectionParallelAssociatedFunctions>::
+            calculate_mass_flowrate_from_pressure_change(
+                pressure_change, &self.fluid_component_vector);
+    }
+}
+
+impl<'collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollectionParallelAssociatedFunctions 
+for TestParallelCollection<'collection_lifetime> {}
+
+#[test]
+pub fn when_pressure_step_simulated_expect_steady_parallel_flowrate(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::transient::simulate_flow;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(3.0);
+
+    let parallel_collection = TestParallelCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2],
+    };
+
+    // step change from 0 to -1000 Pa at t = 1 s
+    let pressure_schedule = vec![
+        (0.0, Pressure::new::<pascal>(0.0)),
+        (0.5, Pressure::new::<pascal>(0.0)),
+        (1.0, Pressure::new::<pascal>(-1000.0)),
+        (1.5, Pressure::new::<pascal>(-1000.0)),
+    ];
+
+    let flow_history = simulate_flow(&parallel_collection, 
+                                     &pressure_schedule);
+
+    assert_eq!(flow_history.len(), 4);
+
+    // no flow before the step
+    assert_eq!(flow_history[0].1.value, 0.0);
+    assert_eq!(flow_history[1].1.value, 0.0);
+
+    // after the step, each parallel pipe sees the full -1000 Pa
+    let steady_mass_flowrate = 
+        pipe_1.get_mass_flowrate_from_pressure_change_immutable(
+            Pressure::new::<pascal>(-1000.0)) +
+        pipe_2.get_mass_flowrate_from_pressure_change_immutable(
+            Pressure::new::<pascal>(-1000.0));
+
+    for (time, mass_flowrate) in flow_history[2..].iter() {
+        assert!(*time >= 1.0);
+        assert_relative_eq!(mass_flowrate.value, steady_mass_flowrate.value,
+                            max_relative = 1e-12);
+    }
+
+    assert!(steady_mass_flowrate.value > 0.0);
+}
+
+#[test]
+pub fn when_bejan_from_mass_rate_inverted_expect_input_reynolds_number(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::dimensionalisation::{CalcBejan, CalcReynolds};
+
+    let pipe = TestWaterPipe::new(2.0);
+    let area = pipe.hydraulic_diameter * pipe.hydraulic_diameter
+        * std::f64::consts::PI / 4.0;
+    let form_loss_k = 5.0;
+    let roughness_ratio = (pipe.absolute_roughness / 
+                           pipe.hydraulic_diameter).value;
+    let length_to_diameter = (pipe.pipe_length / 
+                              pipe.hydraulic_diameter).value;
+
+    // laminar, transitional and turbulent flows
+    for mass_flowrate_value in [0.01, 0.06, 0.5, 2.0] {
+
+        let mass_rate = MassRate::new::<kilogram_per_second>(
+            mass_flowrate_value);
+
+        let input_reynolds_number = CalcReynolds::from_mass_rate(
+            mass_rate, area, pipe.hydraulic_diameter, 
+            pipe.dynamic_viscosity);
+
+        let bejan_number = CalcBejan::from_mass_rate(
+            mass_rate,
+            area,
+            pipe.hydraulic_diameter,
+            pipe.pipe_length,
+            pipe.absolute_roughness,
+            pipe.density,
+            pipe.dynamic_viscosity,
+            form_loss_k);
+
+        let recovered_reynolds_number = 
+            fluid_mechanics_rust::get_reynolds_number(
+                bejan_number, roughness_ratio, 
+                length_to_diameter, form_loss_k);
+
+        assert_relative_eq!(recovered_reynolds_number, input_reynolds_number,
+                            max_relative = 1e-6);
+    }
+
+    // and it matches going through the pressure loss
+    let mass_rate = MassRate::new::<kilogram_per_second>(0.5);
+    let mut pipe_with_k = TestWaterPipe::new(2.0);
+    pipe_with_k.form_loss_k = form_loss_k;
+
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    let bejan_from_pressure = CalcBejan::from_pressure(
+        pipe_with_k.get_pressure_loss_immutable(mass_rate),
+        pipe.hydraulic_diameter, pipe.density, pipe.dynamic_viscosity);
+
+    assert_relative_eq!(
+        CalcBejan::from_mass_rate(mass_rate, area, pipe.hydraulic_diameter,
+                                  Length::new::<meter>(2.0),
+                                  pipe.absolute_roughness, pipe.density,
+                                  pipe.dynamic_viscosity, form_loss_k),
+        bejan_from_pressure, max_relative = 1e-9);
+}
+
+#[test]
+pub fn when_factory_custom_darcy_given_negative_roughness_expect_err_not_panic(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+    use fluid_mechanics_rust::therminol_component::factory;
+
+    // negative roughness is an error rather than a panic
+    assert_eq!(darcy_checked(5000.0, -1.0e-4), 
+               Err(FrictionFactorError::NegativeRoughness));
+    assert_eq!(darcy_checked(0.0, 1.0e-4), 
+               Err(FrictionFactorError::ZeroReynolds));
+
+    assert_eq!(factory::CTAHVertical::try_custom_darcy(5000.0, -1.0e-4),
+               Err(FrictionFactorError::NegativeRoughness));
+    assert_eq!(factory::DHXShellSideHeatExchanger::try_custom_darcy(
+            -5000.0, -1.0e-4),
+               Err(FrictionFactorError::NegativeRoughness));
+
+    // valid inputs are unaffected, including reverse flow
+    for reynolds_number in [100.0, 3000.0, 1.0e5] {
+        let roughness_ratio = 1.0e-4;
+        let expected_darcy = darcy(reynolds_number, roughness_ratio);
+
+        assert_eq!(darcy_checked(reynolds_number, roughness_ratio), 
+                   Ok(expected_darcy));
+        assert_eq!(factory::CTAHVertical::custom_darcy(
+                reynolds_number, roughness_ratio), expected_darcy);
+        assert_eq!(factory::HeaterTopHead1a::custom_darcy(
+                -reynolds_number, roughness_ratio), -expected_darcy);
+        assert_eq!(factory::CietHeaterVersion1::try_custom_darcy(
+                reynolds_number, roughness_ratio), Ok(expected_darcy));
+        assert_eq!(factory::HeaterBottomHead1b::try_custom_darcy(
+                -reynolds_number, roughness_ratio), Ok(-expected_darcy));
+    }
+}
+
+#[test]
+pub fn when_dhx_branch_natural_circulation_estimated_expect_pressure_balance(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::therminol_component::dowtherm_a_properties::*;
+    use roots::{find_root_brent, SimpleConvergency};
+
+    // hot leg at 80C and cold leg at 40C, with the thermal centres 
+    // of the heat source and sink 1 m apart (assumed)
+    let hot_temp = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+    let cold_temp = ThermodynamicTemperature::new::<degree_celsius>(40.0);
+    let average_temp = ThermodynamicTemperature::new::<degree_celsius>(60.0);
+
+    let driving_pressure = natural_circulation_driving_pressure(
+        getDowthermADensity(hot_temp),
+        getDowthermADensity(cold_temp),
+        Length::new::<meter>(1.0));
+
+    assert!(driving_pressure.value > 0.0);
+
+    // loop closure assumption: 
+    // the whole loop's resistance is taken as the DHX branch alone, 
+    // at the average temperature. The hydrostatic pressure of the 
+    // branch is already in the driving pressure, so only the 
+    // frictional and form losses balance it
+    let dhx_branch: Vec<DowthermAComponent> = vec![
+        factory::Pipe26::get_enum(),
+        factory::StaticMixer21::get_enum(),
+        factory::Pipe25a::get_enum(),
+        factory::DHXShellSideHeatExchanger::get_enum(),
+        factory::StaticMixer20::get_enum(),
+        factory::Pipe23a::get_enum(),
+        factory::Pipe22::get_enum(),
+        factory::Flowmeter20::get_enum(),
+        factory::Pipe21::get_enum(),
+        factory::Pipe20::get_enum(),
+        factory::Pipe19::get_enum(),
+    ];
+
+    let branch_pressure_loss = |mass_flowrate_kg_per_s: f64| -> f64 {
+
+        let mut pressure_loss_pascals = 0.0;
+
+        for component in dhx_branch.iter() {
+            let pressure_change = component.pressure_change_from_mass_rate(
+                MassRate::new::<kilogram_per_second>(mass_flowrate_kg_per_s),
+                average_temp);
+            let zero_flow_pressure_change = 
+                component.pressure_change_from_mass_rate(
+                    MassRate::new::<kilogram_per_second>(0.0),
+                    average_temp);
+
+            pressure_loss_pascals -= 
+                (pressure_change - zero_flow_pressure_change).value;
+        }
+
+        return pressure_loss_pascals;
+    };
+
+    let pressure_balance = |mass_flowrate_kg_per_s: f64| -> f64 {
+        return branch_pressure_loss(mass_flowrate_kg_per_s) 
+            - driving_pressure.get::<pascal>();
+    };
+
+    let mut convergency = SimpleConvergency { eps: 1e-12_f64, max_iter: 60 };
+    let natural_circulation_flowrate = find_root_brent(
+        1.0e-6, 1.0, &pressure_balance, &mut convergency).unwrap();
+
+    // the flow should be positive and small, 
+    // CIET natural circulation flows are within a few tens of g/s
+    assert!(natural_circulation_flowrate > 0.0);
+    assert!(natural_circulation_flowrate < 0.2);
+
+    assert_relative_eq!(branch_pressure_loss(natural_circulation_flowrate),
+                        driving_pressure.get::<pascal>(),
+                        max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_pipe_6a_built_with_typed_constructor_expect_same_pipe_properties(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::angle::degree;
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::therminol_component::factory;
+    use fluid_mechanics_rust::therminol_component::therminol_pipe::DowthermAPipe;
+    use fluid_mechanics_rust::therminol_component::custom_therminol_component::
+        DowthermACustomComponent;
+
+    // pipe 6a's numbers, with the units explicit
+    let typed_pipe_6a: DowthermAPipe = StandardPipeProperties::new_typed(
+        "static_mixer_pipe_6a".to_string(),
+        Length::new::<meter>(2.79e-2),
+        Length::new::<meter>(0.1526),
+        Length::new::<millimeter>(0.015),
+        Angle::new::<degree>(51.526384),
+        5.05);
+
+    let untyped_pipe_6a = factory::Pipe6a::get();
+
+    assert_eq!(typed_pipe_6a.dowtherm_pipe_properties,
+               untyped_pipe_6a.dowtherm_pipe_properties);
+
+    // likewise for the custom components, eg. the CTAH pump
+    let typed_pump: DowthermACustomComponent = 
+        StandardCustomComponentProperties::new_typed(
+            "ctah_pump".to_string(),
+            Length::new::<meter>(2.79e-2),
+            Area::new::<square_meter>(6.11e-4),
+            Length::new::<meter>(0.36),
+            Length::new::<millimeter>(0.015),
+            Angle::new::<degree>(0.0),
+            &factory::CTAHPump::custom_darcy,
+            &factory::CTAHPump::custom_k);
+
+    let untyped_pump = factory::CTAHPump::get(0.0);
+
+    let typed_properties = &typed_pump.dowtherm_custom_component_properties;
+    let untyped_properties = &untyped_pump.dowtherm_custom_component_properties;
+
+    assert_eq!(typed_properties._name, untyped_properties._name);
+    assert_eq!(typed_properties.hydraulic_diameter, 
+               untyped_properties.hydraulic_diameter);
+    assert_eq!(typed_properties.xs_area, untyped_properties.xs_area);
+    assert_eq!(typed_properties.component_length, 
+               untyped_properties.component_length);
+    assert_eq!(typed_properties.absolute_roughness, 
+               untyped_properties.absolute_roughness);
+    assert_eq!(typed_properties.incline_angle, 
+               untyped_properties.incline_angle);
+    assert_eq!(typed_properties.internal_pressure, 
+               untyped_properties.internal_pressure);
+}
+
+#[test]
+pub fn when_two_series_collections_concatenated_expect_summed_pressure_change(){
+
+    use uom::si::f64::*;
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let mut pipe_2 = TestWaterPipe::new(2.0);
+    pipe_2.incline_angle = Angle::new::<degree>(45.0);
+    let mut pipe_3 = TestWaterPipe::new(0.5);
+    pipe_3.internal_pressure_source = Pressure::new::<pascal>(300.0);
+
+    let collection_a = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2],
+    };
+
+    let collection_b = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_3],
+    };
+
+    let merged_collection = TestSeriesCollection {
+        fluid_component_vector: concat_series(&collection_a, &collection_b),
+    };
+
+    assert_eq!(merged_collection.fluid_component_vector.len(), 3);
+
+    for mass_flowrate_value in [-0.3, 0.0, 0.2, 1.0] {
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        let summed_pressure_change = 
+            collection_a.get_pressure_change(mass_flowrate) + 
+            collection_b.get_pressure_change(mass_flowrate);
+
+        assert_relative_eq!(
+            merged_collection.get_pressure_change(mass_flowrate).value,
+            summed_pressure_change.value,
+            max_relative = 1e-12);
+    }
+}
+
+#[test]
+pub fn when_gravity_halved_expect_hydrostatic_pressure_change_halved(){
+
+    use uom::si::f64::*;
+    use uom::si::angle::degree;
+    use uom::si::length::meter;
+    use uom::si::acceleration::meter_per_second_squared;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::physical_constants::PhysicalConstants;
+    use fluid_mechanics_rust::fluid_component_calculation::standard_pipe_calc::
+        FluidPipeCalcPressureChange;
+
+    let pipe_length = Length::new::<meter>(2.0);
+    let vertical = Angle::new::<degree>(90.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+
+    // default constants are the same as the default path
+    let default_pressure_change = <TestWaterPipe as 
+        FluidPipeCalcPressureChange>::get_hydrostatic_pressure_change(
+            pipe_length, vertical, density);
+
+    let default_constants_pressure_change = <TestWaterPipe as 
+        FluidPipeCalcPressureChange>::get_hydrostatic_pressure_change_with_constants(
+            pipe_length, vertical, density, &PhysicalConstants::default());
+
+    assert_eq!(default_pressure_change, default_constants_pressure_change);
+    assert_relative_eq!(default_pressure_change.value, -9.81 * 1000.0 * 2.0,
+                        max_relative = 1e-12);
+
+    // halving gravity halves the hydrostatic pressure change
+    let half_gravity = PhysicalConstants {
+        gravity: Acceleration::new::<meter_per_second_squared>(9.81/2.0),
+    };
+
+    let half_gravity_pressure_change = <TestWaterPipe as 
+        FluidPipeCalcPressureChange>::get_hydrostatic_pressure_change_with_constants(
+            pipe_length, vertical, density, &half_gravity);
+
+    assert_relative_eq!(half_gravity_pressure_change.value,
+                        0.5 * default_pressure_change.value,
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_velocity_times_area_times_density_expect_mass_rate_recovered(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::velocity::meter_per_second;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::mass_flux::kilogram_per_square_meter_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::dimensionalisation::*;
+
+    let mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+    let area = Area::new::<square_meter>(6.11e-4);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1060.0);
+
+    // these are typed, so the units are checked at compile time
+    let velocity: Velocity = velocity_from_mass_rate(mass_rate, area, density);
+    let mass_flux: MassFlux = mass_flux(mass_rate, area);
+
+    let recovered_mass_rate: MassRate = velocity * area * density;
+
+    assert_relative_eq!(recovered_mass_rate.get::<kilogram_per_second>(), 0.18,
+                        max_relative = 1e-12);
+
+    // G = rho U
+    assert_relative_eq!(mass_flux.get::<kilogram_per_square_meter_second>(),
+                        density.value * velocity.get::<meter_per_second>(),
+                        max_relative = 1e-12);
+
+    assert_relative_eq!(mass_flux.get::<kilogram_per_square_meter_second>(),
+                        0.18/6.11e-4, max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_scaled_component_factor_two_expect_double_pressure_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+
+    let test_pipe = TestWaterPipe::new(1.0);
+    let mut scaled_pipe = ScaledComponent::new(&test_pipe, 2.0);
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+
+    let pipe_pressure_loss = 
+        test_pipe.get_pressure_loss_immutable(mass_flowrate);
+    let scaled_pressure_loss = 
+        scaled_pipe.get_pressure_loss_immutable(mass_flowrate);
+
+    assert_relative_eq!(scaled_pressure_loss.value,
+                        2.0 * pipe_pressure_loss.value,
+                        max_relative = 1e-12);
+
+    // mass flowrate from the scaled pressure loss 
+    // should recover the original mass flowrate
+    let recovered_mass_flowrate = scaled_pipe.
+        get_mass_flowrate_from_pressure_loss_immutable(
+            scaled_pressure_loss);
+
+    assert_relative_eq!(recovered_mass_flowrate.get::<kilogram_per_second>(),
+                        0.5, max_relative = 1e-6);
+
+    // and the mutable methods should agree
+    scaled_pipe.set_pressure_loss(scaled_pressure_loss);
+    assert_relative_eq!(scaled_pipe.get_mass_flowrate().
+                        get::<kilogram_per_second>(),
+                        0.5, max_relative = 1e-6);
+
+    // a horizontal pipe has pressure change = -pressure loss
+    let pressure_change = 
+        scaled_pipe.get_pressure_change_immutable(mass_flowrate);
+    assert_relative_eq!(pressure_change.get::<pascal>(),
+                        -scaled_pressure_loss.get::<pascal>(),
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_dowtherm_a_property_table_expect_density_decreasing_with_temp(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::
+        dowtherm_a_properties::property_table;
+
+    // 20C to 180C in steps of 10C
+    let temps: Vec<ThermodynamicTemperature> = (0..17).map(
+        |i| ThermodynamicTemperature::new::<degree_celsius>(
+            20.0 + 10.0 * i as f64)).collect();
+
+    let table = property_table(&temps);
+
+    assert_eq!(table.len(), 17);
+
+    for pair in table.windows(2) {
+        assert!(pair[1].density < pair[0].density);
+        assert!(pair[1].temperature > pair[0].temperature);
+    }
+
+    // enthalpy reference is 0 J/kg at 20C
+    assert_relative_eq!(table[0].enthalpy.value, 0.0, epsilon = 1e-6);
+}
+
+#[test]
+pub fn when_air_at_300k_and_1atm_expect_ideal_gas_density_1_18(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::pressure::pascal;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let air_properties = IdealGasProperties::air();
+
+    let fluid_temp = ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    // used as a trait object just like dowtherm A
+    let fluid_properties: &dyn FluidProperties = &air_properties;
+
+    assert_relative_eq!(
+        fluid_properties.density(fluid_temp).get::<kilogram_per_cubic_meter>(),
+        1.18, max_relative = 0.01);
+
+    // Sutherland's law gives about 1.846e-5 Pa s for air at 300 K
+    assert_relative_eq!(
+        fluid_properties.viscosity(fluid_temp).get::<pascal_second>(),
+        1.846e-5, max_relative = 0.005);
+
+    // doubling the pressure doubles the density
+    assert_relative_eq!(
+        air_properties.density_at_pressure(
+            Pressure::new::<pascal>(2.0*101325.0), fluid_temp).value,
+        2.0 * fluid_properties.density(fluid_temp).value,
+        max_relative = 1e-12);
+
+    // enthalpy inverts back to temperature
+    let enthalpy = fluid_properties.enthalpy(fluid_temp);
+    assert_relative_eq!(
+        fluid_properties.get_temperature_from_enthalpy(enthalpy)
+        .get::<kelvin>(), 300.0, max_relative = 1e-12);
+
+    // 0 K is not valid, the range check would panic there
+    assert!(!fluid_properties.is_temp_valid(
+            ThermodynamicTemperature::new::<kelvin>(0.0)));
+    assert!(fluid_properties.is_temp_valid(fluid_temp));
+}
+
+#[test]
+pub fn when_helium_ideal_gas_constructed_expect_its_own_viscosity_and_cp(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use uom::si::pressure::pascal;
+    use uom::si::molar_mass::gram_per_mole;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::pascal_second;
+    use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    // helium, Sutherland coefficients from White's Viscous Fluid Flow
+    let helium_properties = IdealGasProperties::new(
+        MolarMass::new::<gram_per_mole>(4.0026),
+        Pressure::new::<pascal>(101325.0),
+        SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(5193.0),
+        0.66,
+        DynamicViscosity::new::<pascal_second>(1.87e-5),
+        ThermodynamicTemperature::new::<kelvin>(273.0),
+        79.4);
+
+    let fluid_temp = ThermodynamicTemperature::new::<kelvin>(300.0);
+
+    assert_relative_eq!(
+        helium_properties.density(fluid_temp).get::<kilogram_per_cubic_meter>(),
+        0.1625, max_relative = 0.01);
+
+    // about 2.0e-5 Pa s at 300 K, not air's 1.846e-5 Pa s
+    assert_relative_eq!(
+        helium_properties.viscosity(fluid_temp).get::<pascal_second>(),
+        2.0e-5, max_relative = 0.02);
+
+    assert_relative_eq!(
+        helium_properties.specific_heat_capacity(fluid_temp)
+        .get::<joule_per_kilogram_kelvin>(),
+        5193.0, max_relative = 1e-12);
+}
+
+/// a simple parallel super collection for testing,
+/// it holds references to any FluidComponentCollectionMethods
+pub struct TestParallelSuperCollection<'super_collection_lifetime> {
+    pub super_collection_vector: Vec<&'super_collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_collection::
+        FluidComponentCollectionMethods>,
+}
+
+impl<'super_collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentSuperCollection<'super_collection_lifetime> 
+for TestParallelSuperCollection<'super_collection_lifetime> {
+
+    fn get_immutable_vector(&self) 
+        -> &Vec<&'super_collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_collection::
+        FluidComponentCollectionMethods> {
+        return &self.super_collection_vector;
+    }
+
+    fn set_vector(
+        &mut self,
+        fluid_component_super_vector: Vec<&'super_collection_lifetime dyn 
+        fluid_mechanics_rust::fluid_component_collection::
+        FluidComponentCollectionMethods>){
+        self.super_collection_vector = fluid_component_super_vector;
+    }
+}
+
+impl<'super_collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentCollectionMethods 
+for TestParallelSuperCollection<'super_collection_lifetime> {
+
+    fn get_pressure_change(
+        &self, 
+        fluid_mass_flowrate: uom::si::f64::MassRate) -> uom::si::f64::Pressure {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentSuperCollectionParallelAssociatedFunctions;
+
+        return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            calculate_pressure_change_from_mass_flowrate(
+                fluid_mass_flowrate, &self.super_collection_vector);
+    }
+
+    fn get_mass_flowrate_from_pressure_change(
+        &self,
+        pressure_change: uom::si::f64::Pressure) -> uom::si::f64::MassRate {
+        use fluid_mechanics_rust::fluid_component_collection::
+            FluidComponentSuperCollectionParallelAssociatedFunctions;
+
+        return <Self as FluidComponentSuperCollectionParallelAssociatedFunctions>::
+            calculate_mass_flowrate_from_pressure_change(
+                pressure_change, &self.super_collection_vector);
+    }
+}
+
+impl<'super_collection_lifetime> fluid_mechanics_rust::fluid_component_collection::
+FluidComponentSuperCollectionParallelAssociatedFunctions 
+for TestParallelSuperCollection<'super_collection_lifetime> {}
+
+#[test]
+pub fn when_pumped_branch_drives_parallel_loop_expect_other_branch_reversed(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let plain_pipe = TestWaterPipe::new(1.0);
+    let mut pumped_pipe = TestWaterPipe::new(2.0);
+    pumped_pipe.internal_pressure_source = Pressure::new::<pascal>(100.0);
+
+    // single component branches
+    let plain_branch = TestParallelCollection {
+        fluid_component_vector: vec![&plain_pipe],
+    };
+    let pumped_branch = TestParallelCollection {
+        fluid_component_vector: vec![&pumped_pipe],
+    };
+
+    let parallel_super_collection = TestParallelSuperCollection {
+        super_collection_vector: vec![&plain_branch, &pumped_branch],
+    };
+
+    // with no net flow, the pump circulates fluid forward through 
+    // its own branch and backward through the plain branch
+    let flow_directions = parallel_super_collection.branch_flow_directions(
+        MassRate::new::<kilogram_per_second>(0.0));
+
+    assert_eq!(flow_directions, 
+               vec![FlowDirection::Reverse, FlowDirection::Forward]);
+
+    // with a large enough net flow, both branches flow forward
+    let flow_directions = parallel_super_collection.branch_flow_directions(
+        MassRate::new::<kilogram_per_second>(1.0));
+
+    assert_eq!(flow_directions, 
+               vec![FlowDirection::Forward, FlowDirection::Forward]);
+}
+
+#[test]
+pub fn when_90_degree_long_radius_bend_at_high_re_expect_k_between_0_2_and_0_4(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{inch, millimeter};
+    use uom::si::angle::degree;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::PipeBend;
+
+    // 2 inch commercial steel, long radius (r/D = 1.5) elbow
+    let bend = PipeBend::new(
+        Length::new::<inch>(2.0),
+        Angle::new::<degree>(90.0),
+        1.5,
+        Length::new::<millimeter>(0.045));
+
+    let high_re_k = bend.form_loss_k(1.0e6);
+
+    assert!(high_re_k > 0.2 && high_re_k < 0.4);
+
+    // K is larger in laminar flow, and symmetric in reverse flow
+    assert!(bend.form_loss_k(1000.0) > high_re_k);
+    assert_eq!(bend.form_loss_k(-1.0e6), high_re_k);
+
+    // a 45 degree bend has half the bend coefficient
+    let half_bend = PipeBend::new(
+        Length::new::<inch>(2.0),
+        Angle::new::<degree>(45.0),
+        1.5,
+        Length::new::<millimeter>(0.045));
+
+    assert_relative_eq!(2.0 * half_bend.equivalent_length_ratio(),
+                        bend.equivalent_length_ratio(),
+                        max_relative = 1e-12);
+
+    // the pressure loss should be K rho u^2/2
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let mass_rate = MassRate::new::<kilogram_per_second>(5.0);
+
+    let velocity: Velocity = mass_rate / density / 
+        bend.pipe_cross_sectional_area();
+    let diameter_value = bend.diameter.value;
+    let reynolds_number = density.value * velocity.value * diameter_value
+        / viscosity.value;
+
+    let pressure_loss = bend.pressure_loss_from_mass_rate(
+        mass_rate, viscosity, density);
+
+    assert_relative_eq!(pressure_loss.get::<pascal>(),
+                        bend.form_loss_k(reynolds_number) * 0.5 
+                        * density.value * velocity.value.powf(2.0),
+                        max_relative = 1e-6);
+
+    // and reverse flow gives the same loss in the opposite direction
+    let reverse_pressure_loss = bend.pressure_loss_from_mass_rate(
+        -mass_rate, viscosity, density);
+
+    assert_relative_eq!(reverse_pressure_loss.value, -pressure_loss.value,
+                        max_relative = 1e-12);
+
+    // zero flow, zero loss
+    let zero_pressure_loss = bend.pressure_loss_from_mass_rate(
+        MassRate::new::<kilogram_per_second>(0.0), viscosity, density);
+
+    assert_eq!(zero_pressure_loss.value, 0.0);
+}
+
+#[test]
+pub fn when_iterating_collection_components_expect_count_and_total_length(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(2.5);
+    let pipe_3 = TestWaterPipe::new(0.5);
+
+    let series_collection = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2, &pipe_3],
+    };
+
+    assert_eq!(series_collection.len(), 3);
+    assert!(!series_collection.is_empty());
+    assert_eq!(series_collection.components().count(), 3);
+
+    let summed_length: Length = series_collection.components()
+        .map(|fluid_component| 
+             fluid_component.get_component_length_immutable())
+        .fold(Length::new::<meter>(0.0), |total, length| total + length);
+
+    assert_relative_eq!(summed_length.get::<meter>(), 4.0, 
+                        max_relative = 1e-12);
+    assert_eq!(summed_length, series_collection.total_component_length());
+
+    let empty_collection = TestSeriesCollection {
+        fluid_component_vector: vec![],
+    };
+
+    assert_eq!(empty_collection.len(), 0);
+    assert!(empty_collection.is_empty());
+}
+
+#[test]
+pub fn when_prototype_parameters_used_as_model_expect_prototype_mass_rate(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::centipoise;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::dimensionalisation::CalcReynolds;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+    use fluid_mechanics_rust::scaling::matching_mass_rate;
+
+    // prototype: dowtherm A at 80C in the test pipe geometry
+    let prototype_pipe = TestWaterPipe::new(1.0);
+    let dowtherm_properties = DowthermAProperties::new();
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+
+    let prototype_density = dowtherm_properties.density(fluid_temp);
+    let prototype_viscosity = dowtherm_properties.viscosity(fluid_temp);
+    let prototype_diameter = prototype_pipe.hydraulic_diameter;
+    let prototype_area = prototype_diameter * prototype_diameter 
+        * std::f64::consts::PI / 4.0;
+    let prototype_mass_rate = MassRate::new::<kilogram_per_second>(0.18);
+
+    let prototype_re = CalcReynolds::from_mass_rate(
+        prototype_mass_rate,
+        prototype_area,
+        prototype_diameter,
+        prototype_viscosity);
+
+    // feeding the prototype's own parameters gives back its mass rate
+    let matched_mass_rate = matching_mass_rate(
+        prototype_re,
+        prototype_diameter,
+        prototype_density,
+        prototype_viscosity,
+        prototype_area);
+
+    assert_relative_eq!(matched_mass_rate.get::<kilogram_per_second>(),
+                        0.18, max_relative = 1e-12);
+
+    // a model with a more viscous fluid needs proportionally more flow
+    let model_mass_rate = matching_mass_rate(
+        prototype_re,
+        prototype_diameter,
+        prototype_density,
+        DynamicViscosity::new::<centipoise>(
+            2.0 * prototype_viscosity.get::<centipoise>()),
+        prototype_area);
+
+    assert_relative_eq!(model_mass_rate.get::<kilogram_per_second>(),
+                        0.36, max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_inlet_and_outlet_density_equal_expect_zero_momentum_term(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_calculation::momentum::*;
+
+    let test_pipe = TestWaterPipe::new(1.0);
+    let area = test_pipe.get_cross_sectional_area_immutable();
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+
+    let momentum_term = momentum_pressure_change(
+        mass_flowrate, area, density, density);
+
+    assert_eq!(momentum_term.get::<pascal>(), 0.0);
+
+    // with no density change, the heated pipe is just the test pipe
+    let isothermal_pipe = HeatedPipe::new(
+        "isothermal", 2.79e-2, 1.0, 0.001, 0.0, 0.0,
+        density, density,
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    assert_relative_eq!(
+        isothermal_pipe.get_pressure_loss_immutable(mass_flowrate).value,
+        test_pipe.get_pressure_loss_immutable(mass_flowrate).value,
+        max_relative = 1e-9);
+
+    // heating the fluid adds the momentum term on top of
+    // the friction at mean density
+    let heated_pipe = HeatedPipe::new(
+        "heated", 2.79e-2, 1.0, 0.001, 0.0, 0.0,
+        density, MassDensity::new::<kilogram_per_cubic_meter>(900.0),
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    let mean_density_pipe = HeatedPipe::new(
+        "mean", 2.79e-2, 1.0, 0.001, 0.0, 0.0,
+        heated_pipe.mean_density(), heated_pipe.mean_density(),
+        DynamicViscosity::new::<millipascal_second>(1.0));
+
+    let expected_momentum_term = momentum_pressure_change(
+        mass_flowrate, area, density, 
+        MassDensity::new::<kilogram_per_cubic_meter>(900.0));
+
+    assert!(expected_momentum_term.value > 0.0);
+
+    assert_relative_eq!(
+        heated_pipe.get_pressure_loss_immutable(mass_flowrate).value,
+        mean_density_pipe.get_pressure_loss_immutable(mass_flowrate).value
+        + expected_momentum_term.value,
+        max_relative = 1e-9);
+}
+
+/// a fake fluid which counts how many times its density 
+/// correlation is called
+pub struct CountingFluidProperties {
+    pub density_calls: std::cell::Cell<usize>,
+}
+
+impl fluid_mechanics_rust::fluid_thermophysical_properties::FluidProperties 
+for CountingFluidProperties {
+
+    fn density(&self, _fluid_temp: uom::si::f64::ThermodynamicTemperature) 
+        -> uom::si::f64::MassDensity {
+        use uom::si::mass_density::kilogram_per_cubic_meter;
+        self.density_calls.set(self.density_calls.get() + 1);
+        return uom::si::f64::MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    }
+
+    fn viscosity(&self, _fluid_temp: uom::si::f64::ThermodynamicTemperature) 
+        -> uom::si::f64::DynamicViscosity {
+        use uom::si::dynamic_viscosity::millipascal_second;
+        return uom::si::f64::DynamicViscosity::new::<millipascal_second>(1.0);
+    }
+
+    fn enthalpy(&self, _fluid_temp: uom::si::f64::ThermodynamicTemperature) 
+        -> uom::si::f64::AvailableEnergy {
+        use uom::si::available_energy::joule_per_kilogram;
+        return uom::si::f64::AvailableEnergy::new::<joule_per_kilogram>(0.0);
+    }
+
+    fn specific_heat_capacity(
+        &self, _fluid_temp: uom::si::f64::ThermodynamicTemperature) 
+        -> uom::si::f64::SpecificHeatCapacity {
+        use uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+        return uom::si::f64::SpecificHeatCapacity::
+            new::<joule_per_kilogram_kelvin>(4180.0);
+    }
+
+    fn thermal_conductivity(
+        &self, _fluid_temp: uom::si::f64::ThermodynamicTemperature) 
+        -> uom::si::f64::ThermalConductivity {
+        use uom::si::thermal_conductivity::watt_per_meter_kelvin;
+        return uom::si::f64::ThermalConductivity::
+            new::<watt_per_meter_kelvin>(0.6);
+    }
+
+    fn get_temperature_from_enthalpy(
+        &self, _fluid_enthalpy: uom::si::f64::AvailableEnergy) 
+        -> uom::si::f64::ThermodynamicTemperature {
+        use uom::si::thermodynamic_temperature::degree_celsius;
+        return uom::si::f64::ThermodynamicTemperature::
+            new::<degree_celsius>(20.0);
+    }
+}
+
+#[test]
+pub fn when_same_temperature_requested_twice_expect_cache_hit(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let counting_props = CountingFluidProperties {
+        density_calls: std::cell::Cell::new(0),
+    };
+    let cached_props = CachedFluidProperties::new(&counting_props);
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(50.0);
+
+    let density_1 = cached_props.density(fluid_temp);
+    let density_2 = cached_props.density(fluid_temp);
+
+    assert_eq!(density_1, density_2);
+    assert_eq!(counting_props.density_calls.get(), 1);
+
+    // a different temperature is a cache miss
+    cached_props.density(
+        ThermodynamicTemperature::new::<degree_celsius>(60.0));
+    assert_eq!(counting_props.density_calls.get(), 2);
+
+    // and clearing the cache forces a recalculation
+    cached_props.clear();
+    cached_props.density(fluid_temp);
+    assert_eq!(counting_props.density_calls.get(), 3);
+}
+
+#[test]
+pub fn when_bejan_with_friction_expect_darcy_matches_direct_call(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let roughness_ratio = 0.0015;
+    let length_to_diameter = 35.8;
+    let form_loss_k = 5.0;
+
+    for reynolds_number in [800.0, 3000.0, 1.0e5, -1.0e5] {
+
+        let (bejan_number, darcy_friction_factor) = 
+            get_bejan_d_with_friction(reynolds_number, roughness_ratio, 
+                                      length_to_diameter, form_loss_k);
+
+        assert_eq!(darcy_friction_factor, 
+                   darcy(f64::abs(reynolds_number), roughness_ratio));
+
+        assert_relative_eq!(bejan_number, 
+                            getBe(reynolds_number, roughness_ratio,
+                                  length_to_diameter, form_loss_k),
+                            max_relative = 1e-14);
+    }
+}
+
+#[test]
+pub fn when_converting_friction_factors_and_bejan_expect_crate_definitions(){
+
+    use fluid_mechanics_rust::churchill_friction_factor;
+    use fluid_mechanics_rust::conversions::*;
+
+    let reynolds_number = 20000.0;
+    let roughness_ratio = 0.001;
+
+    // darcy = 4 fanning, and the conversions are inverses
+    let darcy_friction_factor = 
+        churchill_friction_factor::darcy(reynolds_number, roughness_ratio);
+    let fanning_friction_factor = darcy_to_fanning(darcy_friction_factor);
+
+    assert_relative_eq!(4.0 * fanning_friction_factor, darcy_friction_factor,
+                        max_relative = 1e-15);
+    assert_relative_eq!(fanning_to_darcy(fanning_friction_factor), 
+                        darcy_friction_factor, max_relative = 1e-15);
+
+    // Be = 0.5 Re^2 fldk, so Eu = Be/Re^2 = 0.5 fldk
+    let length_to_diameter = 20.0;
+    let form_loss_k = 1.5;
+    let bejan_number = churchill_friction_factor::getBe(
+        reynolds_number, roughness_ratio, length_to_diameter, form_loss_k);
+
+    let fldk = churchill_friction_factor::fLDK(
+        reynolds_number, roughness_ratio, length_to_diameter, form_loss_k);
+
+    let euler_number = bejan_to_euler(bejan_number, reynolds_number);
+
+    assert_relative_eq!(euler_number, 0.5 * fldk, max_relative = 1e-12);
+    assert_relative_eq!(euler_to_bejan(euler_number, reynolds_number),
+                        bejan_number, max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_laminar_constant_64_expect_darcy_and_96_expect_1_5_times(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let roughness_ratio = 0.001;
+
+    for reynolds_number in [10.0, 100.0, 500.0, 1500.0] {
+
+        // C = 64 is the circular pipe
+        assert_relative_eq!(
+            darcy_noncircular(reynolds_number, roughness_ratio, 64.0),
+            darcy(reynolds_number, roughness_ratio),
+            max_relative = 1e-14);
+
+        assert_eq!(darcy_laminar_constant(reynolds_number, 64.0),
+                   64.0/reynolds_number);
+    }
+
+    // at low Re, parallel plates (C = 96) have 1.5 times the 
+    // friction factor of a circular pipe
+    let reynolds_number = 100.0;
+    assert_relative_eq!(
+        darcy_noncircular(reynolds_number, roughness_ratio, 96.0),
+        1.5 * darcy(reynolds_number, roughness_ratio),
+        max_relative = 1e-6);
+
+    assert_relative_eq!(
+        darcy_noncircular(reynolds_number, roughness_ratio, 96.0),
+        darcy_laminar_constant(reynolds_number, 96.0),
+        max_relative = 1e-6);
+
+    // in fully turbulent flow, the laminar constant hardly matters
+    assert_relative_eq!(
+        darcy_noncircular(1.0e6, roughness_ratio, 96.0),
+        darcy(1.0e6, roughness_ratio),
+        max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_generic_resistor_k_includes_pipe_friction_expect_custom_k_pipe_bejan(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::dimensionalisation::{CalcReynolds, CalcBejan};
+    use fluid_mechanics_rust::churchill_friction_factor;
+
+    // the test pipe geometry, with a fixed K of 5 on top of friction
+    let test_pipe = TestWaterPipe::new(1.0);
+    let hydraulic_diameter = test_pipe.hydraulic_diameter;
+    let pipe_length = Length::new::<meter>(1.0);
+    let area = hydraulic_diameter * hydraulic_diameter 
+        * std::f64::consts::PI / 4.0;
+    let roughness_ratio = (test_pipe.absolute_roughness 
+                           / hydraulic_diameter).value;
+    let length_to_diameter = (pipe_length / hydraulic_diameter).value;
+
+    let fixed_k = |reynolds_number: f64| -> f64 {
+        if reynolds_number < 0.0 {
+            return -5.0;
+        }
+        return 5.0;
+    };
+
+    // the resistor has no friction factor, so the pipe friction
+    // goes into its K(Re) instead
+    let pipe_k = |reynolds_number: f64| -> f64 {
+        if reynolds_number == 0.0 {
+            return 0.0;
+        }
+        let darcy = churchill_friction_factor::darcy(
+            reynolds_number.abs(), roughness_ratio);
+        return (darcy * length_to_diameter + 5.0) * reynolds_number.signum();
+    };
+
+    let resistor = GenericResistor::new(
+        hydraulic_diameter,
+        pipe_length,
+        area,
+        test_pipe.density,
+        test_pipe.dynamic_viscosity,
+        &pipe_k);
+
+    // get_bejan_custom_k_pipe only takes forward flow
+    for mass_flowrate_value in [0.05, 0.5, 2.0] {
+
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        let reynolds_number = CalcReynolds::from_mass_rate(
+            mass_flowrate, area, hydraulic_diameter, 
+            test_pipe.dynamic_viscosity);
+
+        let expected_bejan_number = 
+            fluid_mechanics_rust::CustomComponent::get_bejan_custom_k_pipe(
+                reynolds_number, roughness_ratio, length_to_diameter,
+                &fixed_k);
+
+        let resistor_bejan_number = CalcBejan::from_pressure(
+            resistor.get_pressure_loss_immutable(mass_flowrate),
+            hydraulic_diameter,
+            test_pipe.density,
+            test_pipe.dynamic_viscosity);
+
+        assert_relative_eq!(resistor_bejan_number, expected_bejan_number,
+                            max_relative = 1e-9);
+
+        // reverse flow gives the same loss in the opposite direction
+        let reverse_pressure_loss = resistor.get_pressure_loss_immutable(
+            -mass_flowrate);
+        assert_relative_eq!(
+            reverse_pressure_loss.value,
+            -resistor.get_pressure_loss_immutable(mass_flowrate).value,
+            max_relative = 1e-9);
+    }
+}
+
+#[test]
+pub fn when_linear_pump_drives_quadratic_resistance_expect_analytic_operating_point(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::*;
+    use fluid_mechanics_rust::system_curve::operating_point;
+
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+    let area = Area::new::<square_meter>(6.11e-4);
+
+    // H = a - b m
+    let pump_shutoff_head = 5000.0;
+    let pump_slope = 1000.0;
+    let head_curve = |mass_flowrate: MassRate| -> Pressure {
+        return Pressure::new::<pascal>(
+            pump_shutoff_head - pump_slope * 
+            mass_flowrate.get::<kilogram_per_second>());
+    };
+
+    let pump = CentrifugalPump::new(
+        &head_curve,
+        Length::new::<meter>(2.79e-2),
+        area,
+        Length::new::<meter>(0.36),
+        Angle::new::<degree>(0.0),
+        density,
+        viscosity);
+
+    // a constant K resistor, so that 
+    // pressure_loss = c m^2, c = K/(2 rho A^2)
+    let form_loss_k = 10.0;
+    let constant_k = |reynolds_number: f64| -> f64 {
+        return form_loss_k * reynolds_number.signum();
+    };
+
+    let resistor = GenericResistor::new(
+        Length::new::<meter>(2.79e-2),
+        Length::new::<meter>(1.0),
+        area,
+        density,
+        viscosity,
+        &constant_k);
+
+    let system = TestSeriesCollection {
+        fluid_component_vector: vec![&resistor],
+    };
+
+    let (mass_flowrate, pump_pressure) = operating_point(&pump, &system);
+
+    // c m^2 + b m - a = 0
+    let c = form_loss_k / (2.0 * density.value * area.value * area.value);
+    let expected_mass_flowrate = (-pump_slope + 
+        (pump_slope * pump_slope + 4.0 * c * pump_shutoff_head).sqrt())
+        / (2.0 * c);
+
+    assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(),
+                        expected_mass_flowrate, max_relative = 1e-6);
+
+    assert_relative_eq!(pump_pressure.get::<pascal>(),
+                        c * expected_mass_flowrate * expected_mass_flowrate,
+                        max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_frictional_series_system_curve_expect_pressure_magnitude_increasing(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::system_curve::system_resistance_curve;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(2.5);
+
+    let system = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2],
+    };
+
+    let mass_rates: Vec<MassRate> = (0..20)
+        .map(|i| MassRate::new::<kilogram_per_second>(0.1 * i as f64))
+        .collect();
+
+    let system_curve = system_resistance_curve(&system, &mass_rates);
+
+    assert_eq!(system_curve.len(), mass_rates.len());
+
+    for i in 1..system_curve.len() {
+        assert_eq!(system_curve[i].0, mass_rates[i]);
+        assert!(system_curve[i].1.value.abs() > 
+                system_curve[i-1].1.value.abs());
+    }
+}
+
+#[test]
+pub fn when_gauge_pressure_converted_to_absolute_and_back_expect_round_trip(){
+
+    use uom::si::f64::*;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::pressure_reference::*;
+
+    let atmospheric = PressureContext::default();
+    assert_eq!(atmospheric.reference.get::<pascal>(), 101325.0);
+
+    let pressurised = PressureContext {
+        reference: Pressure::new::<pascal>(3.0e5),
+    };
+
+    for context in [atmospheric, pressurised] {
+        for gauge_pressure_value in [-5000.0, 0.0, 1234.5] {
+
+            let gauge_pressure = Pressure::new::<pascal>(gauge_pressure_value);
+            let absolute_pressure = to_absolute(gauge_pressure, &context);
+
+            // gauge + reference = absolute
+            assert_relative_eq!(absolute_pressure.get::<pascal>(),
+                                gauge_pressure_value + 
+                                context.reference.get::<pascal>());
+
+            assert_relative_eq!(
+                to_gauge(absolute_pressure, &context).get::<pascal>(),
+                gauge_pressure_value, epsilon = 1e-9);
+        }
+    }
+}
+
+#[test]
+pub fn when_three_identical_branches_share_flow_expect_equal_split(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(1.0);
+    let pipe_3 = TestWaterPipe::new(1.0);
+
+    let branch_1 = TestParallelCollection {
+        fluid_component_vector: vec![&pipe_1],
+    };
+    let branch_2 = TestParallelCollection {
+        fluid_component_vector: vec![&pipe_2],
+    };
+    let branch_3 = TestParallelCollection {
+        fluid_component_vector: vec![&pipe_3],
+    };
+
+    let parallel_super_collection = TestParallelSuperCollection {
+        super_collection_vector: vec![&branch_1, &branch_2, &branch_3],
+    };
+
+    let total_mass_flowrate = MassRate::new::<kilogram_per_second>(0.3);
+
+    let branch_mass_flowrates = parallel_super_collection.
+        branch_mass_flowrates(total_mass_flowrate);
+
+    assert_eq!(branch_mass_flowrates.len(), 3);
+
+    let mut sum_of_branch_flowrates = 
+        MassRate::new::<kilogram_per_second>(0.0);
+
+    for branch_mass_flowrate in branch_mass_flowrates.iter() {
+        assert_relative_eq!(branch_mass_flowrate.get::<kilogram_per_second>(),
+                            0.1, max_relative = 1e-6);
+        sum_of_branch_flowrates += *branch_mass_flowrate;
+    }
+
+    assert_relative_eq!(sum_of_branch_flowrates.get::<kilogram_per_second>(),
+                        0.3, max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_darcy_dre_compared_to_finite_difference_expect_within_one_percent(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    for reynolds_number in [1.0e4, 1.0e5] {
+        for roughness_ratio in [0.0, 1.0e-4, 1.0e-3] {
+
+            let step = 1.0e-4 * reynolds_number;
+            let finite_difference_derivative = 
+                (darcy(reynolds_number + step, roughness_ratio) 
+                 - darcy(reynolds_number - step, roughness_ratio))
+                / (2.0 * step);
+
+            let analytic_derivative = 
+                darcy_dRe(reynolds_number, roughness_ratio);
+
+            // friction factor decreases with Re here
+            assert!(analytic_derivative < 0.0);
+
+            assert_relative_eq!(analytic_derivative, 
+                                finite_difference_derivative,
+                                max_relative = 0.01);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn when_network_result_serialised_to_json_expect_round_trip(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(2.0);
+
+    let series_collection = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2],
+    };
+
+    let network_result = series_collection.network_result(
+        MassRate::new::<kilogram_per_second>(0.5));
+
+    assert_eq!(network_result.per_component.len(), 2);
+    assert_eq!(network_result.per_component[1].name, "component_1");
+    assert_eq!(network_result.mass_flowrate_kg_per_s, 0.5);
+
+    // the component pressure changes sum to the total for 
+    // a series collection
+    let sum_of_pressure_changes: f64 = network_result.per_component.iter()
+        .map(|component_result| component_result.pressure_change_pa)
+        .sum();
+    assert_relative_eq!(sum_of_pressure_changes, 
+                        network_result.total_pressure_change_pa,
+                        max_relative = 1e-9);
+
+    let network_result_json = serde_json::to_string(&network_result).unwrap();
+
+    assert!(network_result_json.contains("total_pressure_change_pa"));
+
+    let network_result_from_json: NetworkResult = 
+        serde_json::from_str(&network_result_json).unwrap();
+
+    // serde_json may be off in the last bit when parsing floats
+    assert_relative_eq!(network_result.total_pressure_change_pa,
+                        network_result_from_json.total_pressure_change_pa,
+                        max_relative = 1e-15);
+    assert_eq!(network_result.mass_flowrate_kg_per_s,
+               network_result_from_json.mass_flowrate_kg_per_s);
+
+    for (component_result, component_result_from_json) in 
+        network_result.per_component.iter().zip(
+            network_result_from_json.per_component.iter()) {
+
+        assert_eq!(component_result.name, component_result_from_json.name);
+        assert_relative_eq!(component_result.pressure_change_pa,
+                            component_result_from_json.pressure_change_pa,
+                            max_relative = 1e-15);
+        assert_relative_eq!(component_result.reynolds,
+                            component_result_from_json.reynolds,
+                            max_relative = 1e-15);
+    }
+}
+
+#[test]
+pub fn when_creeping_flow_in_series_expect_linear_solver_matches_brent(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::dimensionalisation::CalcReynolds;
+
+    // long pipes so that the laminar pressure loss is well 
+    // above the 9 Pa cutoff of the brent solver
+    let pipe_1 = TestWaterPipe::new(50.0);
+    let pipe_2 = TestWaterPipe::new(100.0);
+
+    let fluid_component_vector: Vec<&dyn FluidComponent> = 
+        vec![&pipe_1, &pipe_2];
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.01);
+
+    let reynolds_number = CalcReynolds::from_mass_rate(
+        mass_flowrate,
+        pipe_1.get_cross_sectional_area_immutable(),
+        pipe_1.get_hydraulic_diameter_immutable(),
+        pipe_1.get_fluid_viscosity_immutable());
+    assert!(reynolds_number < LINEAR_SOLVER_MAX_REYNOLDS);
+
+    let pressure_change = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_pressure_change_from_mass_flowrate(
+            mass_flowrate, &fluid_component_vector);
+
+    let linear_mass_flowrate = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_mass_flowrate_from_pressure_change_linear(
+            pressure_change, &fluid_component_vector);
+
+    let brent_mass_flowrate = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_mass_flowrate_from_pressure_change(
+            pressure_change, &fluid_component_vector);
+
+    assert_relative_eq!(linear_mass_flowrate.get::<kilogram_per_second>(),
+                        brent_mass_flowrate.get::<kilogram_per_second>(),
+                        max_relative = 1e-6);
+
+    assert_relative_eq!(linear_mass_flowrate.get::<kilogram_per_second>(),
+                        0.01, max_relative = 1e-6);
+
+    // and the laminar fast path was actually taken, 
+    // rather than the brent fallback
+    let fast_path_mass_flowrate = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        try_linear_mass_flowrate_from_pressure_change(
+            pressure_change, &fluid_component_vector).
+        expect("laminar flow should take the linear fast path");
+
+    assert_eq!(fast_path_mass_flowrate, linear_mass_flowrate);
+
+    // turbulent flows are handed over to the brent solver
+    let turbulent_mass_flowrate = MassRate::new::<kilogram_per_second>(1.0);
+
+    let turbulent_pressure_change = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_pressure_change_from_mass_flowrate(
+            turbulent_mass_flowrate, &fluid_component_vector);
+
+    let mass_flowrate_from_linear_solver = <TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        calculate_mass_flowrate_from_pressure_change_linear(
+            turbulent_pressure_change, &fluid_component_vector);
+
+    assert_relative_eq!(
+        mass_flowrate_from_linear_solver.get::<kilogram_per_second>(),
+        1.0, max_relative = 1e-6);
+
+    assert!(<TestSeriesCollection as 
+        FluidComponentCollectionSeriesAssociatedFunctions>::
+        try_linear_mass_flowrate_from_pressure_change(
+            turbulent_pressure_change, &fluid_component_vector).is_none());
+}
+
+#[test]
+pub fn when_laminar_re_1000_in_0_1_m_pipe_expect_5_m_entrance_length(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use fluid_mechanics_rust::developing_flow::*;
+
+    let diameter = Length::new::<meter>(0.1);
+
+    let laminar_entrance_length = entrance_length(1000.0, diameter);
+
+    assert_relative_eq!(laminar_entrance_length.get::<meter>(), 5.0,
+                        max_relative = 1e-12);
+
+    // reverse flow develops over the same length
+    assert_eq!(entrance_length(-1000.0, diameter), laminar_entrance_length);
+
+    // a 1 m pipe is too short for this, a 10 m pipe is long enough
+    assert!(!is_fully_developed(Length::new::<meter>(1.0), 
+                                laminar_entrance_length));
+    assert!(is_fully_developed(Length::new::<meter>(10.0), 
+                               laminar_entrance_length));
+}
+
+#[test]
+pub fn when_reverse_flow_k_multiplier_is_3_expect_reverse_form_loss_tripled(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_calculation::
+        standard_pipe_calc::FluidPipeCalcPressureLoss;
+
+    let mut test_pipe = TestWaterPipe::new(1.0);
+    test_pipe.form_loss_k = 5.0;
+
+    let area = Area::new::<square_meter>(6.11e-4);
+
+    let pressure_loss = |mass_flowrate_value: f64, form_loss_k: f64,
+                         reverse_flow_k_multiplier: f64| -> Pressure {
+        return <TestWaterPipe as FluidPipeCalcPressureLoss>::
+            pipe_calc_pressure_loss_asymmetric(
+                MassRate::new::<kilogram_per_second>(mass_flowrate_value),
+                area,
+                test_pipe.hydraulic_diameter,
+                test_pipe.dynamic_viscosity,
+                test_pipe.density,
+                test_pipe.pipe_length,
+                test_pipe.absolute_roughness,
+                form_loss_k,
+                reverse_flow_k_multiplier);
+    };
+
+    // a multiplier of 1 is the usual symmetric pipe
+    let symmetric_reverse_loss = pressure_loss(-0.5, 5.0, 1.0);
+    let symmetric_reverse_loss_reference = 
+        <TestWaterPipe as FluidPipeCalcPressureLoss>::pipe_calc_pressure_loss(
+            MassRate::new::<kilogram_per_second>(-0.5),
+            area,
+            test_pipe.hydraulic_diameter,
+            test_pipe.dynamic_viscosity,
+            test_pipe.density,
+            test_pipe.pipe_length,
+            test_pipe.absolute_roughness,
+            5.0);
+    assert_eq!(symmetric_reverse_loss, symmetric_reverse_loss_reference);
+    assert_relative_eq!(symmetric_reverse_loss.value, 
+                        -pressure_loss(0.5, 5.0, 1.0).value,
+                        max_relative = 1e-12);
+
+    // the form loss part of the reverse pressure loss triples,
+    // friction is unchanged
+    let friction_only_reverse_loss = pressure_loss(-0.5, 0.0, 1.0);
+    let asymmetric_reverse_loss = pressure_loss(-0.5, 5.0, 3.0);
+
+    assert_relative_eq!(
+        (asymmetric_reverse_loss - friction_only_reverse_loss).value,
+        3.0 * (symmetric_reverse_loss - friction_only_reverse_loss).value,
+        max_relative = 1e-9);
+
+    // forward flow is unaffected
+    assert_eq!(pressure_loss(0.5, 5.0, 3.0), pressure_loss(0.5, 5.0, 1.0));
+
+    // and the mass flowrate is recovered from the reverse pressure loss
+    let mass_flowrate = <TestWaterPipe as FluidPipeCalcPressureLoss>::
+        pipe_calc_mass_flowrate_asymmetric(
+            asymmetric_reverse_loss,
+            area,
+            test_pipe.hydraulic_diameter,
+            test_pipe.dynamic_viscosity,
+            test_pipe.density,
+            test_pipe.pipe_length,
+            test_pipe.absolute_roughness,
+            5.0,
+            3.0);
+
+    assert_relative_eq!(mass_flowrate.get::<kilogram_per_second>(), -0.5,
+                        max_relative = 1e-6);
+}
+
+#[test]
+pub fn when_ten_identical_pipes_in_series_expect_ten_times_single_pipe_bejan(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+
+    let test_pipe = TestWaterPipe::new(1.0);
+
+    let single_pipe_collection = TestSeriesCollection {
+        fluid_component_vector: vec![&test_pipe],
+    };
+
+    let ten_pipe_vector: Vec<&dyn FluidComponent> = vec![&test_pipe; 10];
+    let ten_pipe_collection = TestSeriesCollection {
+        fluid_component_vector: ten_pipe_vector,
+    };
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+
+    let single_pipe_bejan_number = single_pipe_collection.total_bejan_number(
+        mass_flowrate, test_pipe.density, test_pipe.dynamic_viscosity,
+        test_pipe.hydraulic_diameter);
+
+    let ten_pipe_bejan_number = ten_pipe_collection.total_bejan_number(
+        mass_flowrate, test_pipe.density, test_pipe.dynamic_viscosity,
+        test_pipe.hydraulic_diameter);
+
+    assert!(single_pipe_bejan_number > 0.0);
+    assert_relative_eq!(ten_pipe_bejan_number, 10.0 * single_pipe_bejan_number,
+                        max_relative = 1e-12);
+
+    // doubling the reference diameter quadruples the Bejan number
+    let doubled_diameter_bejan_number = ten_pipe_collection.total_bejan_number(
+        mass_flowrate, test_pipe.density, test_pipe.dynamic_viscosity,
+        2.0 * test_pipe.hydraulic_diameter);
+
+    assert_relative_eq!(doubled_diameter_bejan_number, 
+                        4.0 * ten_pipe_bejan_number,
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_flibe_at_873_kelvin_expect_literature_density_and_viscosity(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::{kelvin, degree_celsius};
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let flibe_properties = FluidKind::Flibe.properties();
+
+    // Romatoski and Hu (2017), about 1987 kg/m3 and 8.6 mPa s at 873 K
+    let fluid_temp = ThermodynamicTemperature::new::<kelvin>(873.0);
+
+    assert_relative_eq!(
+        flibe_properties.density(fluid_temp).get::<kilogram_per_cubic_meter>(),
+        1987.0, max_relative = 0.001);
+
+    assert_relative_eq!(
+        flibe_properties.viscosity(fluid_temp).get::<millipascal_second>(),
+        8.56, max_relative = 0.005);
+
+    // the commonly quoted 1940 kg/m3 is at 700 C
+    assert_relative_eq!(
+        flibe_properties.density(
+            ThermodynamicTemperature::new::<degree_celsius>(700.0)).
+        get::<kilogram_per_cubic_meter>(),
+        1940.0, max_relative = 0.002);
+
+    // enthalpy round trip
+    let enthalpy = flibe_properties.enthalpy(fluid_temp);
+    assert_relative_eq!(
+        flibe_properties.get_temperature_from_enthalpy(enthalpy).get::<kelvin>(),
+        873.0, max_relative = 1e-12);
+
+    // the correlations only cover the liquid range
+    assert!(!flibe_properties.is_temp_valid(
+        ThermodynamicTemperature::new::<kelvin>(700.0)));
+}
+
+#[test]
+#[should_panic]
+pub fn when_flibe_below_melting_point_expect_panic(){
+
+    use uom::si::f64::*;
+    use uom::si::thermodynamic_temperature::kelvin;
+    use fluid_mechanics_rust::fluid_thermophysical_properties::*;
+
+    let flibe_properties = FlibeProperties::new();
+
+    flibe_properties.density(ThermodynamicTemperature::new::<kelvin>(700.0));
+}
+
+#[test]
+pub fn when_turbulent_pipe_euler_number_expect_bejan_over_reynolds_squared(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use fluid_mechanics_rust::dimensionalisation::*;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+
+    let test_pipe = TestWaterPipe::new(1.0);
+    let area = Area::new::<square_meter>(6.11e-4);
+
+    // Re is about 5e4 here
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(1.1);
+    let pressure_loss = test_pipe.get_pressure_loss_immutable(mass_flowrate);
+
+    let reynolds_number = CalcReynolds::from_mass_rate(
+        mass_flowrate, area, test_pipe.hydraulic_diameter, 
+        test_pipe.dynamic_viscosity);
+    assert!(reynolds_number > 4000.0);
+
+    let bejan_number = CalcBejan::from_pressure(
+        pressure_loss, test_pipe.hydraulic_diameter, 
+        test_pipe.density, test_pipe.dynamic_viscosity);
+
+    let euler = euler_number_from_mass_rate(
+        pressure_loss, test_pipe.density, mass_flowrate, area);
+
+    assert_relative_eq!(euler, bejan_number/reynolds_number.powi(2),
+                        max_relative = 1e-9);
+
+    // pressure coefficient is based on dynamic pressure
+    let velocity = velocity_from_mass_rate(mass_flowrate, area, 
+                                           test_pipe.density);
+    assert_relative_eq!(
+        pressure_coefficient(pressure_loss, test_pipe.density, velocity),
+        2.0 * euler, max_relative = 1e-12);
+    assert_relative_eq!(
+        pressure_coefficient_from_mass_rate(pressure_loss, test_pipe.density,
+                                            mass_flowrate, area),
+        2.0 * euler, max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_try_fldk_pipe_at_zero_or_negative_re_expect_err(){
+
+    use fluid_mechanics_rust::CustomComponent;
+
+    let custom_k = |reynolds_number: f64| -> f64 {
+        return 400.0 + 52000.0/reynolds_number;
+    };
+
+    let stagnant_fldk = CustomComponent::try_fldk_pipe(
+        0.0, 0.00014, 10.0, &custom_k);
+    assert!(stagnant_fldk.is_err());
+
+    let reverse_fldk = CustomComponent::try_fldk_pipe(
+        -100.0, 0.00014, 10.0, &custom_k);
+    assert!(reverse_fldk.is_err());
+
+    // the error message should say what went wrong
+    assert!(reverse_fldk.unwrap_err().contains("Re < 0.0"));
+
+    let forward_fldk = CustomComponent::try_fldk_pipe(
+        1000.0, 0.00014, 10.0, &custom_k);
+    assert_eq!(forward_fldk, Ok(CustomComponent::fldk_pipe(
+        1000.0, 0.00014, 10.0, &custom_k)));
+}
+
+#[test]
+pub fn when_direction_along_or_across_gravity_expect_vertical_or_horizontal_incline(){
+
+    use uom::si::angle::degree;
+    use fluid_mechanics_rust::geometry::effective_incline_angle;
+
+    // gravity not along a coordinate axis, to check the vector maths
+    let gravity = [0.0, -9.81 * 0.6, -9.81 * 0.8];
+
+    // aligned with gravity is vertical, flowing downwards
+    let downward_incline = effective_incline_angle([0.0, -0.6, -0.8], gravity);
+    assert_relative_eq!(downward_incline.get::<degree>(), -90.0,
+                        max_relative = 1e-6);
+
+    // and flowing upwards against gravity
+    let upward_incline = effective_incline_angle([0.0, 1.2, 1.6], gravity);
+    assert_relative_eq!(upward_incline.get::<degree>(), 90.0,
+                        max_relative = 1e-6);
+
+    // perpendicular to gravity is horizontal
+    for direction in [[1.0, 0.0, 0.0], [0.0, 0.8, -0.6]] {
+        let horizontal_incline = effective_incline_angle(direction, gravity);
+        assert_relative_eq!(horizontal_incline.get::<degree>(), 0.0,
+                            epsilon = 1e-9);
+    }
+}
+
+#[test]
+pub fn when_transition_uncertainty_peak_expect_re_near_branch_crossing(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    let relative_difference_at = |reynolds_number: f64, 
+                                  roughness_ratio: f64| -> f64 {
+        let laminar_darcy = darcy_with_mode(
+            reynolds_number, roughness_ratio, FrictionMode::ForceLaminar);
+        let turbulent_darcy = darcy_with_mode(
+            reynolds_number, roughness_ratio, FrictionMode::ForceTurbulent);
+        let blended_darcy = darcy(reynolds_number, roughness_ratio);
+
+        return (blended_darcy - laminar_darcy).abs()
+            .min((blended_darcy - turbulent_darcy).abs())
+            / blended_darcy;
+    };
+
+    for roughness_ratio in [0.0, 1.0e-6, 1.0e-4, 1.0e-3, 1.0e-2, 0.05] {
+
+        let (peak_reynolds, peak_relative_difference) = 
+            transition_uncertainty_peak(roughness_ratio);
+
+        // the peak is inside the band, where the laminar and 
+        // turbulent branches cross (Re about 2240), 
+        // not at either edge of the scan
+        assert!((peak_reynolds - 2240.0).abs() <= 20.0,
+                "peak at Re = {} for roughness ratio {}", 
+                peak_reynolds, roughness_ratio);
+        assert!(peak_reynolds > TRANSITION_SCAN_LOWER_REYNOLDS);
+        assert!(peak_reynolds < TRANSITION_SCAN_UPPER_REYNOLDS);
+
+        assert_relative_eq!(peak_relative_difference,
+                            relative_difference_at(peak_reynolds, 
+                                                   roughness_ratio),
+                            max_relative = 1e-12);
+        assert!(peak_relative_difference > 0.05);
+
+        // and no larger than anywhere else in the band,
+        // including the edges
+        for reynolds_number in [2000.0, 2200.0, 2300.0, 3000.0, 4000.0] {
+            assert!(relative_difference_at(reynolds_number, roughness_ratio)
+                    <= peak_relative_difference);
+        }
+        assert!(relative_difference_at(4000.0, roughness_ratio)
+                < 0.5 * peak_relative_difference);
+    }
+}
+
+#[test]
+pub fn when_custom_component_built_from_closures_expect_hand_calculated_pressure_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::area::square_meter;
+    use uom::si::angle::degree;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::therminol_component::
+        custom_therminol_component::DowthermACustomComponent;
+
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let area = Area::new::<square_meter>(6.11e-4);
+
+    // K = 20 + 500/Re, defined inline
+    let component = DowthermACustomComponent::from_closures(
+        "closure_component".to_string(),
+        hydraulic_diameter,
+        area,
+        Length::new::<meter>(0.5),
+        Length::new::<millimeter>(0.015),
+        Angle::new::<degree>(0.0),
+        |_reynolds_number: f64, _roughness_ratio: f64| 0.0,
+        |reynolds_number: f64| {
+            if reynolds_number == 0.0 {
+                return 0.0;
+            }
+            return (20.0 + 500.0/reynolds_number.abs()) 
+                * reynolds_number.signum();
+        });
+
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.15);
+
+    let pressure_change = CalcPressureChange::from_mass_rate(
+        &component, mass_flowrate, fluid_temp);
+
+    // hand calculation, pressure loss = K rho u^2/2
+    let density = dowtherm_a_properties::getDowthermADensity(fluid_temp);
+    let viscosity = dowtherm_a_properties::getDowthermAViscosity(fluid_temp);
+
+    let velocity = mass_flowrate.value / density.value / area.value;
+    let reynolds_number = mass_flowrate.value * hydraulic_diameter.value 
+        / area.value / viscosity.value;
+    let form_loss_k = 20.0 + 500.0/reynolds_number;
+    let expected_pressure_loss = form_loss_k * 0.5 * density.value 
+        * velocity * velocity;
+
+    // horizontal, so there is no hydrostatic pressure change
+    assert_relative_eq!(pressure_change.get::<pascal>(), 
+                        -expected_pressure_loss,
+                        max_relative = 1e-9);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+pub fn when_pressure_change_sweep_run_in_parallel_expect_same_as_serial(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+    use fluid_mechanics_rust::system_curve::*;
+
+    // owns its pipes so that it is Sync and can be shared
+    // across threads
+    struct PipeSeries {
+        pipes: Vec<TestWaterPipe>,
+    }
+
+    impl FluidComponentCollectionMethods for PipeSeries {
+        fn get_pressure_change(
+            &self, 
+            fluid_mass_flowrate: MassRate) -> Pressure {
+            let mut pressure_change = Pressure::new::<pascal>(0.0);
+            for pipe in self.pipes.iter() {
+                pressure_change += 
+                    pipe.get_pressure_change_immutable(fluid_mass_flowrate);
+            }
+            return pressure_change;
+        }
+
+        fn get_mass_flowrate_from_pressure_change(
+            &self,
+            _pressure_change: Pressure) -> MassRate {
+            unimplemented!();
+        }
+    }
+
+    let pipe_series = PipeSeries {
+        pipes: vec![
+            TestWaterPipe::new(1.0),
+            TestWaterPipe::new(2.5)],
+    };
+
+    // laminar, transition and turbulent flow in both directions
+    let mass_rates: Vec<MassRate> = (0..257).map(|index| {
+        MassRate::new::<kilogram_per_second>(-2.0 + index as f64 * 0.0157)
+    }).collect();
+
+    let serial_sweep = pressure_change_sweep(
+        &pipe_series, &mass_rates);
+    let parallel_sweep = pressure_change_sweep_parallel(
+        &pipe_series, &mass_rates);
+
+    assert_eq!(serial_sweep.len(), mass_rates.len());
+    for (serial, parallel) in serial_sweep.iter().zip(parallel_sweep.iter()) {
+        assert_eq!(serial.get::<pascal>(), parallel.get::<pascal>());
+    }
+}
+
+#[test]
+pub fn when_reynolds_from_mass_flux_expect_same_as_from_mass_rate(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use fluid_mechanics_rust::dimensionalisation::*;
+
+    // turbulent water flow in a 1 inch-ish pipe, Re about 36000
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.8);
+    let area = Area::new::<square_meter>(6.11e-4);
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+
+    let reynolds_from_mass_rate = CalcReynolds::from_mass_rate(
+        mass_flowrate, area, hydraulic_diameter, viscosity);
+
+    let reynolds_from_mass_flux = CalcReynolds::from_mass_flux(
+        mass_flux(mass_flowrate, area), hydraulic_diameter, viscosity);
+
+    assert!(reynolds_from_mass_flux > 4000.0);
+    assert_relative_eq!(reynolds_from_mass_flux, reynolds_from_mass_rate,
+                        max_relative = 1e-12);
+    // hand calc, Re = 0.8/6.11e-4 * 2.79e-2/1e-3
+    assert_relative_eq!(reynolds_from_mass_flux, 
+                        0.8/6.11e-4 * 2.79e-2/1e-3,
+                        max_relative = 1e-12);
+}
+
+#[test]
+pub fn when_transition_mass_flowrate_fed_back_expect_target_reynolds(){
+
+    use uom::si::f64::*;
+    use uom::si::length::meter;
+    use uom::si::area::square_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use fluid_mechanics_rust::dimensionalisation::*;
+
+    let area = Area::new::<square_meter>(6.11e-4);
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+
+    for target_reynolds in [LAMINAR_LIMIT_RE, TURBULENT_LIMIT_RE] {
+
+        let mass_flowrate = transition_mass_flowrate(
+            target_reynolds, area, hydraulic_diameter, viscosity);
+
+        let reynolds_number = CalcReynolds::from_mass_rate(
+            mass_flowrate, area, hydraulic_diameter, viscosity);
+
+        assert_relative_eq!(reynolds_number, target_reynolds,
+                            max_relative = 1e-12);
+    }
+}
+
+#[test]
+pub fn when_vertical_component_flows_downward_expect_hydrostatic_pressure_gain(){
+
+    use uom::si::f64::*;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::angle::degree;
+    use uom::si::pressure::pascal;
+    use uom::si::thermodynamic_temperature::degree_celsius;
+    use fluid_mechanics_rust::therminol_component::*;
+    use fluid_mechanics_rust::therminol_component::therminol_pipe::DowthermAPipe;
+
+    let build_vertical_pipe = |incline_angle: Angle| -> DowthermAPipe {
+        return StandardPipeProperties::new_typed(
+            "vertical_pipe".to_string(),
+            Length::new::<meter>(2.79e-2),
+            Length::new::<meter>(1.5),
+            Length::new::<millimeter>(0.015),
+            incline_angle,
+            0.0);
+    };
+
+    let base_angle = Angle::new::<degree>(90.0);
+    let fluid_temp = ThermodynamicTemperature::new::<degree_celsius>(21.0);
+
+    let upward_pipe = build_vertical_pipe(
+        signed_incline(base_angle, Orientation::FlowUpward));
+    let downward_pipe = build_vertical_pipe(
+        signed_incline(base_angle, Orientation::FlowDownward));
+    // the factory convention, adding 180 degrees by hand
+    let factory_downward_pipe = build_vertical_pipe(
+        Angle::new::<degree>(90.0 + 180.0));
+
+    let upward_hydrostatic = 
+        upward_pipe.get_hydrostatic_pressure_change(fluid_temp);
+    let downward_hydrostatic = 
+        downward_pipe.get_hydrostatic_pressure_change(fluid_temp);
+
+    // going up loses rho g L, going down gains it back
+    let density = dowtherm_a_properties::getDowthermADensity(fluid_temp);
+    let expected_pressure_gain = density.value * 9.81 * 1.5;
+
+    assert!(upward_hydrostatic.get::<pascal>() < 0.0);
+    assert_relative_eq!(downward_hydrostatic.get::<pascal>(),
+                        expected_pressure_gain,
+                        max_relative = 1e-9);
+    assert_relative_eq!(downward_hydrostatic.get::<pascal>(),
+                        -upward_hydrostatic.get::<pascal>(),
+                        max_relative = 1e-9);
+    assert_relative_eq!(downward_hydrostatic.get::<pascal>(),
+                        factory_downward_pipe
+                        .get_hydrostatic_pressure_change(fluid_temp)
+                        .get::<pascal>(),
+                        max_relative = 1e-9);
+}
+
+#[test]
+pub fn when_node_pressures_along_series_expect_pressure_drops_by_each_loss(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::pressure::{pascal, kilopascal};
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_collection::*;
+
+    let pipe_1 = TestWaterPipe::new(1.0);
+    let pipe_2 = TestWaterPipe::new(2.0);
+    let mut pipe_3 = TestWaterPipe::new(3.0);
+    pipe_3.form_loss_k = 5.0;
+
+    let series_collection = TestSeriesCollection {
+        fluid_component_vector: vec![&pipe_1, &pipe_2, &pipe_3],
+    };
+
+    let mass_flowrate = MassRate::new::<kilogram_per_second>(0.5);
+    let inlet_pressure = Pressure::new::<kilopascal>(200.0);
+
+    let node_pressures = series_collection.node_pressures(
+        mass_flowrate, inlet_pressure);
+
+    assert_eq!(node_pressures.len(), 4);
+    assert_eq!(node_pressures[0], inlet_pressure);
+
+    // horizontal pipes without pressure sources, so each node 
+    // is lower than the last by that pipe's pressure loss
+    let pipe_losses = [
+        pipe_1.get_pressure_loss_immutable(mass_flowrate),
+        pipe_2.get_pressure_loss_immutable(mass_flowrate),
+        pipe_3.get_pressure_loss_immutable(mass_flowrate),
+    ];
+
+    for (index, pipe_loss) in pipe_losses.iter().enumerate() {
+        assert!(node_pressures[index + 1] < node_pressures[index]);
+        assert_relative_eq!(
+            (node_pressures[index] - node_pressures[index + 1])
+            .get::<pascal>(),
+            pipe_loss.get::<pascal>(),
+            max_relative = 1e-9);
+    }
+
+    // and the outlet matches the total pressure change of the series
+    assert_relative_eq!(
+        node_pressures[3].get::<pascal>(),
+        (inlet_pressure + series_collection.get_pressure_change(
+                mass_flowrate)).get::<pascal>(),
+        max_relative = 1e-9);
+}
+
+#[test]
+pub fn when_pressure_loss_from_velocity_expect_same_as_from_mass_rate(){
+
+    use uom::si::f64::*;
+    use uom::si::velocity::meter_per_second;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::fluid_component_calculation::
+        standard_pipe_calc::{pressure_loss_from_velocity, CalcPressureLoss};
+
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let area = hydraulic_diameter * hydraulic_diameter 
+        * std::f64::consts::PI / 4.0;
+    let pipe_length = Length::new::<meter>(2.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+    let roughness = Length::new::<millimeter>(0.015);
+    let form_loss_k = 3.5;
+
+    // laminar, transition, turbulent, and reverse flow
+    for velocity_value in [0.05, 0.1, 1.5, -1.5] {
+
+        let velocity = Velocity::new::<meter_per_second>(velocity_value);
+
+        let pressure_loss = pressure_loss_from_velocity(
+            velocity, hydraulic_diameter, pipe_length, 
+            density, viscosity, roughness, form_loss_k);
+
+        let equivalent_mass_flowrate = density * velocity * area;
+
+        let reference_pressure_loss = CalcPressureLoss::from_mass_rate(
+            equivalent_mass_flowrate, area, hydraulic_diameter,
+            viscosity, density, pipe_length, roughness, form_loss_k);
+
+        assert_relative_eq!(pressure_loss.get::<pascal>(),
+                            reference_pressure_loss.get::<pascal>(),
+                            max_relative = 1e-9);
+    }
+}
+
+#[test]
+pub fn when_friction_inputs_not_finite_expect_non_finite_error(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+
+    assert_eq!(try_darcy(f64::NAN, 0.0015), 
+               Err(FrictionFactorError::NonFinite));
+    assert_eq!(try_darcy(f64::INFINITY, 0.0015), 
+               Err(FrictionFactorError::NonFinite));
+    assert_eq!(try_darcy(1800.0, f64::NAN), 
+               Err(FrictionFactorError::NonFinite));
+    // reverse flow goes through the same checks
+    assert_eq!(darcy_checked(f64::NEG_INFINITY, 0.0015), 
+               Err(FrictionFactorError::NonFinite));
+
+    // NaN and infinite inputs also fail the Re solve 
+    // instead of propagating
+    assert_eq!(fluid_mechanics_rust::try_get_reynolds_number(
+            f64::INFINITY, 0.00014, 10.0, 5.0),
+        Err(ReynoldsSolveError::NotANumber));
+    assert_eq!(fluid_mechanics_rust::try_get_reynolds_number(
+            1.0e5, 0.00014, f64::NAN, 5.0),
+        Err(ReynoldsSolveError::NotANumber));
+
+    fn custom_k(reynolds_number: f64) -> f64 {
+        return 400.0 + 52000.0/reynolds_number;
+    }
+
+    let fldk_error = fluid_mechanics_rust::CustomComponent::try_fldk_pipe(
+        f64::INFINITY, 0.00014, 10.0, &custom_k).unwrap_err();
+    assert!(fldk_error.contains("NaN or infinite"));
+}
+
+#[test]
+#[should_panic]
+pub fn when_darcy_given_nan_expect_panic(){
+
+    use fluid_mechanics_rust::churchill_friction_factor::darcy;
+
+    darcy(f64::NAN, 0.0015);
+}
+
+#[test]
+pub fn when_pipe_uses_haaland_expect_pressure_loss_close_to_churchill(){
+
+    use uom::si::f64::*;
+    use uom::si::area::square_meter;
+    use uom::si::length::{meter, millimeter};
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::pressure::pascal;
+    use fluid_mechanics_rust::churchill_friction_factor::*;
+    use fluid_mechanics_rust::fluid_component_calculation::
+        standard_pipe_calc::CalcPressureLoss;
+
+    let area = Area::new::<square_meter>(6.11e-4);
+    let hydraulic_diameter = Length::new::<meter>(2.79e-2);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+    let density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let pipe_length = Length::new::<meter>(3.0);
+    let roughness = Length::new::<millimeter>(0.015);
+    let form_loss_k = 0.0;
+
+    let pressure_loss_with = |mass_flowrate: MassRate, 
+        correlation: &dyn FrictionFactorCorrelation| -> f64 {
+        return CalcPressureLoss::from_mass_rate_with_correlation(
+            mass_flowrate, area, hydraulic_diameter, viscosity, 
+            density, pipe_length, roughness, form_loss_k, 
+            correlation).get::<pascal>();
+    };
+
+    // turbulent flow, Re about 45000, both ways
+    for mass_flowrate_value in [1.0, -1.0] {
+
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        let churchill_loss = pressure_loss_with(mass_flowrate, &Churchill);
+        let haaland_loss = pressure_loss_with(mass_flowrate, &Haaland);
+
+        // the default correlation is still churchill
+        assert_relative_eq!(churchill_loss, 
+            CalcPressureLoss::from_mass_rate(
+                mass_flowrate, area, hydraulic_diameter, viscosity, 
+                density, pipe_length, roughness, form_loss_k)
+            .get::<pascal>(),
+            max_relative = 1e-12);
+
+        // slightly different, but within a few percent
+        assert_ne!(haaland_loss, churchill_loss);
+        assert_relative_eq!(haaland_loss, churchill_loss, 
+                            max_relative = 0.03);
+    }
+}
+
+#[test]
+pub fn when_cooled_pipe_without_form_loss_expect_momentum_recovery_and_round_trip(){
+
+    use uom::si::f64::*;
+    use uom::si::mass_rate::kilogram_per_second;
+    use uom::si::dynamic_viscosity::millipascal_second;
+    use uom::si::mass_density::kilogram_per_cubic_meter;
+    use fluid_mechanics_rust::fluid_component_calculation::FluidComponent;
+    use fluid_mechanics_rust::fluid_component_calculation::momentum::*;
+
+    // cooling from 900 to 1000 kg/m3 with K = 0, the momentum 
+    // term is negative and larger in magnitude than K
+    let inlet_density = MassDensity::new::<kilogram_per_cubic_meter>(900.0);
+    let outlet_density = MassDensity::new::<kilogram_per_cubic_meter>(1000.0);
+    let viscosity = DynamicViscosity::new::<millipascal_second>(1.0);
+
+    let cooled_pipe = HeatedPipe::new(
+        "cooler", 2.79e-2, 1.0, 0.001, 0.0, 0.0,
+        inlet_density, outlet_density, viscosity);
+
+    let mean_density_pipe = HeatedPipe::new(
+        "mean", 2.79e-2, 1.0, 0.001, 0.0, 0.0,
+        cooled_pipe.mean_density(), cooled_pipe.mean_density(), viscosity);
+
+    for mass_flowrate_value in [0.5, -0.5, 0.01] {
+
+        let mass_flowrate = 
+            MassRate::new::<kilogram_per_second>(mass_flowrate_value);
+
+        let momentum_term = cooled_pipe.momentum_pressure_loss(mass_flowrate);
+
+        // pressure is recovered decelerating the fluid
+        assert!(momentum_term.value * mass_flowrate_value < 0.0);
+
+        let pressure_loss = 
+            cooled_pipe.get_pressure_loss_immutable(mass_flowrate);
+
+        assert_relative_eq!(
+            pressure_loss.value,
+            mean_density_pipe.get_pressure_loss_immutable(mass_flowrate).value
+            + momentum_term.value,
+            max_relative = 1e-9);
+
+        let mass_flowrate_test = cooled_pipe.
+            get_mass_flowrate_from_pressure_loss_immutable(pressure_loss);
+
+        assert_relative_eq!(mass_flowrate_test.value, mass_flowrate_value,
+                            max_relative = 1e-6);
+    }
+}